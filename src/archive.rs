@@ -0,0 +1,294 @@
+// src/archive.rs
+//
+// Passphrase-encrypted backup of the whole profile directory. The archive is a single JSON
+// document: a plaintext header (format, version, KDF, checksum of the ciphertext) and an
+// AES-256-GCM payload holding a manifest plus the contents of every managed file.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use bitcoin::hashes::{sha256, Hash};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use zeroize::Zeroizing;
+use crate::encrypt::encrypt_data;
+use crate::decrypt::decrypt_data;
+use crate::keystore::{self, KdfParams};
+use crate::utils::json_storage::{self, Profile};
+
+pub const ARCHIVE_FORMAT: &str = "dannesk-backup";
+pub const ARCHIVE_VERSION: u32 = 1;
+pub const ARCHIVE_EXTENSION: &str = "dnsk";
+
+// Replacing the unlock secret needs the same confirmation as replacing a wallet
+const PIN_FILE: &str = "pin.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveHeader {
+    pub format: String,
+    pub version: u32,
+    pub created_at: String,
+    pub kdf: KdfParams,
+    pub salt: String,
+    pub iv: String,
+    pub ciphertext: String,
+    /// Hex SHA-256 of `ciphertext`, checked before any key derivation.
+    pub checksum: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ManifestEntry {
+    pub name: String,
+    pub size: usize,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Manifest {
+    pub app_version: String,
+    pub created_at: String,
+    pub files: Vec<ManifestEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivePayload {
+    manifest: Manifest,
+    files: BTreeMap<String, String>,
+}
+
+/// A decrypted, verified archive waiting to be written back to disk.
+#[derive(Clone)]
+pub struct ArchiveContents {
+    pub manifest: Manifest,
+    files: BTreeMap<String, Zeroizing<String>>,
+}
+
+impl ArchiveContents {
+    /// Managed files in the archive that already exist locally and would be overwritten.
+    pub fn conflicts(&self) -> Vec<String> {
+        self.manifest.files.iter()
+            .filter(|entry| local_file_exists(&entry.name))
+            .map(|entry| entry.name.clone())
+            .collect()
+    }
+
+    /// True if restoring would replace a wallet, a key or the PIN currently on this device.
+    pub fn clobbers_wallet(&self) -> bool {
+        self.conflicts().iter().any(|name| json_storage::WALLET_FILES.contains(&name.as_str()) || name == PIN_FILE)
+    }
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    sha256::Hash::hash(data).to_string()
+}
+
+fn local_file_exists(filename: &str) -> bool {
+    json_storage::get_config_path(filename)
+        .map(|path| path.exists())
+        .unwrap_or(false)
+}
+
+/// Bundles every managed file that exists into an encrypted archive at `path`.
+/// Returns the manifest that was written.
+pub fn export_profile(
+    path: &Path,
+    passphrase: Zeroizing<String>,
+    app_version: &str,
+) -> Result<Manifest, String> {
+    let created_at = chrono::Utc::now().to_rfc3339();
+    let mut files = BTreeMap::new();
+    let mut entries = Vec::new();
+
+    for name in json_storage::MANAGED_FILES {
+        if !local_file_exists(name) {
+            continue;
+        }
+        let content = if json_storage::TEXT_FILES.contains(name) {
+            json_storage::read_text(name).map_err(|e| format!("Could not read {}: {}", name, e))?
+        } else {
+            let value = json_storage::read_json::<Value>(name)
+                .map_err(|e| format!("Could not read {}: {}", name, e))?;
+            serde_json::to_string(&value).map_err(|e| e.to_string())?
+        };
+
+        entries.push(ManifestEntry {
+            name: name.to_string(),
+            size: content.len(),
+            sha256: sha256_hex(content.as_bytes()),
+        });
+        files.insert(name.to_string(), content);
+    }
+
+    if entries.is_empty() {
+        return Err("Nothing to back up".to_string());
+    }
+
+    let manifest = Manifest {
+        app_version: app_version.to_string(),
+        created_at: created_at.clone(),
+        files: entries,
+    };
+
+    let payload = Zeroizing::new(
+        serde_json::to_string(&ArchivePayload { manifest: manifest.clone(), files })
+            .map_err(|e| e.to_string())?,
+    );
+
+    let kdf = keystore::calibrated_params();
+    let (ciphertext, salt, iv) = encrypt_data(&passphrase, payload.as_bytes(), &kdf)?;
+
+    let header = ArchiveHeader {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        created_at,
+        kdf,
+        salt,
+        iv,
+        checksum: sha256_hex(ciphertext.as_bytes()),
+        ciphertext,
+    };
+
+    let json = serde_json::to_string_pretty(&header).map_err(|e| e.to_string())?;
+    let mut file = json_storage::create_new_file(path, 0o600).map_err(|e| match e.kind() {
+        io::ErrorKind::AlreadyExists => format!("{} already exists. Choose another file name", path.display()),
+        _ => format!("Could not write archive: {}", e),
+    })?;
+    file.write_all(json.as_bytes())
+        .and_then(|_| file.sync_all())
+        .map_err(|e| format!("Could not write archive: {}", e))?;
+
+    Ok(manifest)
+}
+
+/// Reads, authenticates and decrypts the archive at `path` without touching the profile.
+pub fn inspect(path: &Path, passphrase: Zeroizing<String>) -> Result<ArchiveContents, String> {
+    let raw = fs::read_to_string(path).map_err(|e| format!("Could not read archive: {}", e))?;
+    let header: ArchiveHeader = serde_json::from_str(&raw)
+        .map_err(|_| "Not a Dannesk backup archive".to_string())?;
+
+    if header.format != ARCHIVE_FORMAT {
+        return Err("Not a Dannesk backup archive".to_string());
+    }
+    if header.version > ARCHIVE_VERSION {
+        return Err(format!("Archive version {} is newer than this app supports", header.version));
+    }
+    if sha256_hex(header.ciphertext.as_bytes()) != header.checksum {
+        return Err("Archive checksum mismatch: file is corrupted".to_string());
+    }
+
+    // AES-GCM authenticates the payload, so a wrong passphrase and tampering look the same here.
+    let plaintext = decrypt_data(&passphrase, &header.ciphertext, &header.salt, &header.iv, &header.kdf)
+        .map_err(|_| "Wrong passphrase or tampered archive".to_string())?;
+
+    let payload: ArchivePayload = serde_json::from_str(plaintext.as_str())
+        .map_err(|e| format!("Malformed archive payload: {}", e))?;
+
+    let mut files = BTreeMap::new();
+    for entry in &payload.manifest.files {
+        if !json_storage::MANAGED_FILES.contains(&entry.name.as_str()) {
+            return Err(format!("Archive contains unexpected file {}", entry.name));
+        }
+        let content = payload.files.get(&entry.name)
+            .ok_or_else(|| format!("Archive is missing {}", entry.name))?;
+        if content.len() != entry.size || sha256_hex(content.as_bytes()) != entry.sha256 {
+            return Err(format!("Checksum mismatch for {}", entry.name));
+        }
+        files.insert(entry.name.clone(), Zeroizing::new(content.clone()));
+    }
+
+    Ok(ArchiveContents { manifest: payload.manifest, files })
+}
+
+// Files shared by every profile stay as they are while the decoy is open, so restoring there
+// cannot replace the real PIN
+fn restorable(name: &str) -> bool {
+    json_storage::active_profile() == Profile::Primary || !json_storage::ROOT_FILES.contains(&name)
+}
+
+/// Writes the archive's files into the profile. Existing wallets and the PIN are only replaced
+/// when `overwrite` is set, which the caller must get from an explicit user confirmation.
+/// Every file is staged first and only moved into place once all of them were written, so a
+/// failure leaves the profile as it was.
+pub fn restore(contents: &ArchiveContents, overwrite: bool) -> Result<usize, String> {
+    if contents.clobbers_wallet() && !overwrite {
+        return Err("A wallet or PIN already exists on this device; confirm overwrite to restore".to_string());
+    }
+
+    let names: Vec<&str> = contents.files.keys().map(String::as_str).filter(|name| restorable(name)).collect();
+    let mut staged = Vec::new();
+    let result = names.iter().try_for_each(|&name| {
+        let content = &contents.files[name];
+        if !json_storage::TEXT_FILES.contains(&name) {
+            serde_json::from_str::<Value>(content.as_str())
+                .map_err(|e| format!("Malformed {}: {}", name, e))?;
+        }
+        json_storage::stage(name, content.as_str())
+            .map_err(|e| format!("Could not restore {}: {}", name, e))?;
+        staged.push(name);
+        Ok::<_, String>(())
+    });
+    if let Err(e) = result {
+        staged.iter().for_each(|name| json_storage::discard_staged(name));
+        return Err(e);
+    }
+
+    for (i, name) in staged.iter().enumerate() {
+        if let Err(e) = json_storage::install_staged(name) {
+            staged[i + 1..].iter().for_each(|name| json_storage::discard_staged(name));
+            return Err(format!("Could not restore {}: {}", name, e));
+        }
+    }
+
+    // Reported the same in both profiles, so the decoy does not give itself away
+    Ok(contents.files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents(files: &[(&str, &str)]) -> ArchiveContents {
+        ArchiveContents {
+            manifest: Manifest {
+                app_version: String::new(),
+                created_at: String::new(),
+                files: files.iter().map(|(name, content)| ManifestEntry {
+                    name: name.to_string(),
+                    size: content.len(),
+                    sha256: sha256_hex(content.as_bytes()),
+                }).collect(),
+            },
+            files: files.iter().map(|(name, content)| (name.to_string(), Zeroizing::new(content.to_string()))).collect(),
+        }
+    }
+
+    #[test]
+    fn pin_needs_confirmation_and_stays_shared_in_decoy() {
+        let root = json_storage::use_test_root();
+        json_storage::write_json("pin.json", &serde_json::json!({ "hash": "real" })).unwrap();
+        let archive = contents(&[("pin.json", r#"{"hash":"restored"}"#), ("xrp.json", r#"{"address":"r1"}"#)]);
+
+        assert!(archive.clobbers_wallet());
+        assert!(restore(&archive, false).is_err());
+
+        json_storage::in_profile(Profile::Decoy, || restore(&archive, true)).unwrap();
+        let pin: Value = json_storage::read_json("pin.json").unwrap();
+        assert_eq!(pin["hash"], "real");
+        assert!(root.join("alt").join("xrp.json").exists());
+        assert!(!root.join("alt").join("pin.json").exists());
+    }
+
+    #[test]
+    fn failed_restore_leaves_profile_untouched() {
+        let root = json_storage::use_test_root();
+        json_storage::write_json("btc.json", &serde_json::json!({ "address": "local" })).unwrap();
+        let archive = contents(&[("btc.json", r#"{"address":"restored"}"#), ("xrp.json", "not json")]);
+
+        assert!(restore(&archive, true).is_err());
+        let btc: Value = json_storage::read_json("btc.json").unwrap();
+        assert_eq!(btc["address"], "local");
+        assert!(!root.join("xrp.json").exists());
+        assert!(fs::read_dir(&root).unwrap().all(|entry| !entry.unwrap().file_name().to_string_lossy().ends_with(".staged")));
+    }
+}
@@ -0,0 +1,327 @@
+// src/audit.rs
+//
+// Append-only security log (audit.log, one JSON entry per line) for the active profile. Every
+// entry commits to the previous one through `prev`, and its own `hash` covers all of its fields:
+//
+//     hash = SHA256(JSON [seq, timestamp, event, detail, prev])
+//
+// Editing, removing or reordering a line breaks the chain at that point. audit_head.json keeps
+// the latest seq/hash so entries cut from the end are caught as well. Anyone who can rewrite the
+// whole file and the head can forge a consistent history; this makes tampering evident, not
+// impossible. Entries never contain secrets, only what happened and to which wallet.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::sync::Mutex;
+use bitcoin::hashes::{sha256, Hash};
+use serde::{Deserialize, Serialize};
+use crate::channel::{CHANNEL, ProgressState, WSCommand};
+use crate::utils::json_storage;
+
+pub const AUDIT_FILE: &str = "audit.log";
+const HEAD_FILE: &str = "audit_head.json";
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+// Appends read the head and write the next line; two at once would fork the chain
+static APPEND: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditEvent {
+    PinUnlocked,
+    PinFailed,
+    PinLockedOut,
+    KeysWiped,
+    PinChanged,
+    KeyPurged,
+    WalletImported,
+    TransactionSigned,
+    SigningSessionStarted,
+    SigningSessionEnded,
+    PsbtSigned,
+    PsbtFinalized,
+}
+
+impl AuditEvent {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AuditEvent::PinUnlocked => "PIN_UNLOCKED",
+            AuditEvent::PinFailed => "PIN_FAILED",
+            AuditEvent::PinLockedOut => "PIN_LOCKED_OUT",
+            AuditEvent::KeysWiped => "KEYS_WIPED",
+            AuditEvent::PinChanged => "PIN_CHANGED",
+            AuditEvent::KeyPurged => "KEY_PURGED",
+            AuditEvent::WalletImported => "WALLET_IMPORTED",
+            AuditEvent::TransactionSigned => "TRANSACTION_SIGNED",
+            AuditEvent::SigningSessionStarted => "SESSION_STARTED",
+            AuditEvent::SigningSessionEnded => "SESSION_ENDED",
+            AuditEvent::PsbtSigned => "PSBT_SIGNED",
+            AuditEvent::PsbtFinalized => "PSBT_FINALIZED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub timestamp: i64,
+    pub event: String,
+    pub detail: String,
+    pub prev: String,
+    pub hash: String,
+}
+
+impl AuditEntry {
+    fn compute_hash(&self) -> String {
+        let preimage = serde_json::to_string(&(self.seq, self.timestamp, &self.event, &self.detail, &self.prev))
+            .unwrap_or_default();
+        sha256::Hash::hash(preimage.as_bytes()).to_string()
+    }
+
+    /// UTC time of the event, for display.
+    pub fn time_label(&self) -> String {
+        chrono::DateTime::from_timestamp(self.timestamp, 0)
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| self.timestamp.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct AuditHead {
+    seq: u64,
+    hash: String,
+}
+
+/// Appends `event` to the log. Failures are shown in the progress bar and never block the
+/// action being audited.
+pub fn record(event: AuditEvent, detail: impl Into<String>) {
+    if let Err(e) = append(event, detail.into()) {
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 1.0,
+            message: format!("Error: Could not write audit entry {}: {}", event.code(), e),
+        }));
+    }
+}
+
+/// One-line summary of a dispatched command, built only from its non-secret fields.
+pub fn describe_command(cmd: &WSCommand) -> String {
+    let mut parts = vec![cmd.command.clone()];
+    parts.extend(cmd.tx_type.iter().map(|t| format!("type={}", t)));
+    parts.extend(cmd.wallet_type.iter().map(|a| format!("asset={}", a)));
+    parts.extend(cmd.amount.iter().map(|a| format!("amount={}", a)));
+    parts.extend(cmd.taker_gets.iter().map(|(value, currency)| format!("gets={} {}", value, currency)));
+    parts.extend(cmd.taker_pays.iter().map(|(value, currency)| format!("pays={} {}", value, currency)));
+    parts.extend(cmd.fee.iter().map(|f| format!("fee={}", f)));
+    parts.extend(cmd.recipient.iter().map(|r| format!("to={}", r)));
+    parts.extend(cmd.wallet.iter().map(|w| format!("from={}", w)));
+    parts.extend(cmd.tx_hash.iter().map(|h| format!("hash={}", h)));
+    parts.join(" ")
+}
+
+fn append(event: AuditEvent, detail: String) -> io::Result<()> {
+    let _guard = APPEND.lock().unwrap_or_else(|e| e.into_inner());
+
+    let (seq, prev) = match last_link()? {
+        Some(head) => (head.seq + 1, head.hash),
+        None => (0, GENESIS.to_string()),
+    };
+
+    let mut entry = AuditEntry {
+        seq,
+        timestamp: chrono::Utc::now().timestamp(),
+        event: event.code().to_string(),
+        detail,
+        prev,
+        hash: String::new(),
+    };
+    entry.hash = entry.compute_hash();
+
+    let path = json_storage::get_config_path(AUDIT_FILE)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    json_storage::restrict_permissions(&path, 0o600)?;
+    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+    file.sync_all()?;
+
+    json_storage::write_json(HEAD_FILE, &AuditHead { seq: entry.seq, hash: entry.hash })
+}
+
+// The head file is authoritative, so a truncated log keeps extending the original chain and
+// the cut stays visible. Without a head, fall back to the last line of the log.
+fn last_link() -> io::Result<Option<AuditHead>> {
+    match json_storage::read_json::<AuditHead>(HEAD_FILE) {
+        Ok(head) => return Ok(Some(head)),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        Err(_) => {}
+    }
+
+    Ok(read_lines()?
+        .last()
+        .and_then(|line| serde_json::from_str::<AuditEntry>(line).ok())
+        .map(|entry| AuditHead { seq: entry.seq, hash: entry.hash }))
+}
+
+fn read_lines() -> io::Result<Vec<String>> {
+    match fs::read_to_string(json_storage::get_config_path(AUDIT_FILE)?) {
+        Ok(content) => Ok(content.lines().filter(|l| !l.trim().is_empty()).map(str::to_string).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Integrity {
+    Intact,
+    /// Fields were changed after the entry was written.
+    Modified,
+    /// `prev` does not match the entry before it: something was inserted or replaced.
+    BrokenLink,
+    /// Sequence numbers jump, so entries are missing before this one.
+    Gap { missing: u64 },
+    /// The line is not a valid entry at all.
+    Unreadable,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedEntry {
+    pub line: usize,
+    pub entry: Option<AuditEntry>,
+    pub status: Integrity,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AuditReport {
+    pub entries: Vec<VerifiedEntry>,
+    /// Problem with the end of the log as a whole, e.g. entries cut off after the last line.
+    pub tail_issue: Option<String>,
+}
+
+impl AuditReport {
+    pub fn is_intact(&self) -> bool {
+        self.tail_issue.is_none() && self.entries.iter().all(|e| e.status == Integrity::Intact)
+    }
+
+    pub fn flagged(&self) -> usize {
+        self.entries.iter().filter(|e| e.status != Integrity::Intact).count()
+    }
+}
+
+/// Re-walks the whole chain. Each entry is checked against the one stored before it, so a
+/// single edit is flagged where it happened instead of marking everything after it.
+pub fn verify() -> io::Result<AuditReport> {
+    let mut report = AuditReport::default();
+    let mut expected_seq = 0u64;
+    let mut expected_prev = GENESIS.to_string();
+    let mut last: Option<AuditHead> = None;
+
+    for (i, line) in read_lines()?.iter().enumerate() {
+        let entry = match serde_json::from_str::<AuditEntry>(line) {
+            Ok(entry) => entry,
+            Err(_) => {
+                report.entries.push(VerifiedEntry { line: i + 1, entry: None, status: Integrity::Unreadable });
+                continue;
+            }
+        };
+
+        let status = if entry.compute_hash() != entry.hash {
+            Integrity::Modified
+        } else if entry.seq > expected_seq {
+            Integrity::Gap { missing: entry.seq - expected_seq }
+        } else if entry.seq < expected_seq || entry.prev != expected_prev {
+            Integrity::BrokenLink
+        } else {
+            Integrity::Intact
+        };
+
+        expected_seq = entry.seq + 1;
+        expected_prev = entry.hash.clone();
+        last = Some(AuditHead { seq: entry.seq, hash: entry.hash.clone() });
+        report.entries.push(VerifiedEntry { line: i + 1, entry: Some(entry), status });
+    }
+
+    report.tail_issue = match (json_storage::read_json::<AuditHead>(HEAD_FILE).ok(), last) {
+        (Some(head), Some(last)) if head == last => None,
+        (Some(head), Some(last)) if head.seq > last.seq => {
+            Some(format!("{} entries missing after #{}", head.seq - last.seq, last.seq))
+        }
+        (Some(_), Some(_)) => Some("Head record does not match the last entry".to_string()),
+        (Some(head), None) => Some(format!("Log is empty but {} entries were recorded", head.seq + 1)),
+        (None, Some(_)) => Some("Head record is missing".to_string()),
+        (None, None) => None,
+    };
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh log of `count` entries, returned as its lines
+    fn log_of(count: usize) -> Vec<String> {
+        json_storage::use_test_root();
+        for i in 0..count {
+            record(AuditEvent::PinUnlocked, format!("entry {}", i));
+        }
+        let lines = read_lines().unwrap();
+        assert_eq!(lines.len(), count);
+        assert!(verify().unwrap().is_intact());
+        lines
+    }
+
+    fn rewrite(lines: &[String]) {
+        let content: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+        fs::write(json_storage::get_config_path(AUDIT_FILE).unwrap(), content).unwrap();
+    }
+
+    fn statuses(report: &AuditReport) -> Vec<Integrity> {
+        report.entries.iter().map(|e| e.status.clone()).collect()
+    }
+
+    #[test]
+    fn edited_entry_is_modified() {
+        let mut lines = log_of(3);
+        let mut entry: AuditEntry = serde_json::from_str(&lines[1]).unwrap();
+        entry.detail = "something else".to_string();
+        lines[1] = serde_json::to_string(&entry).unwrap();
+        rewrite(&lines);
+
+        let report = verify().unwrap();
+        assert_eq!(statuses(&report), [Integrity::Intact, Integrity::Modified, Integrity::Intact]);
+        assert_eq!(report.tail_issue, None);
+    }
+
+    #[test]
+    fn replaced_entry_breaks_the_next_link() {
+        let mut lines = log_of(3);
+        // A forged entry with a valid hash of its own still changes what the next one commits to
+        let mut entry: AuditEntry = serde_json::from_str(&lines[1]).unwrap();
+        entry.detail = "forged".to_string();
+        entry.hash = entry.compute_hash();
+        lines[1] = serde_json::to_string(&entry).unwrap();
+        rewrite(&lines);
+
+        let report = verify().unwrap();
+        assert_eq!(statuses(&report), [Integrity::Intact, Integrity::Intact, Integrity::BrokenLink]);
+    }
+
+    #[test]
+    fn removed_entry_leaves_a_gap() {
+        let mut lines = log_of(4);
+        lines.drain(1..3);
+        rewrite(&lines);
+
+        let report = verify().unwrap();
+        assert_eq!(statuses(&report), [Integrity::Intact, Integrity::Gap { missing: 2 }]);
+    }
+
+    #[test]
+    fn truncated_tail_is_reported() {
+        let mut lines = log_of(3);
+        lines.pop();
+        rewrite(&lines);
+
+        let report = verify().unwrap();
+        assert_eq!(statuses(&report), [Integrity::Intact, Integrity::Intact]);
+        assert_eq!(report.tail_issue.as_deref(), Some("1 entries missing after #1"));
+        assert!(!report.is_intact());
+    }
+}
@@ -0,0 +1,424 @@
+// src/btcaccount.rs
+//
+// The BIP44-family account behind the Bitcoin wallet. Its script type and xpub are kept in
+// btc.json next to the wallet address, so addresses can be derived and checked on chain without
+// the passphrase. Discovery walks the receive and change chains until `gap_limit` consecutive
+// addresses have no history, the rule other wallets follow, so an imported mnemonic finds funds
+// on every index they handed out. Every tracked address belongs to the wallet, which is how
+// history tells change apart from a payment.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io;
+use std::str::FromStr;
+use bitcoin::bip32::{ChildNumber, DerivationPath, Fingerprint, Xpriv, Xpub};
+use bitcoin::secp256k1::{Secp256k1, Verification};
+use bitcoin::{Address, CompressedPublicKey, Network, OutPoint, PrivateKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::chain::esplora;
+use crate::utils::json_storage;
+
+const META_FILE: &str = "btc.json";
+const ACCOUNT_FIELD: &str = "account";
+
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// Gap limits offered in the receive view. Wallets that skipped many addresses need more.
+pub const GAP_LIMIT_OPTIONS: [u32; 4] = [20, 50, 100, 200];
+
+/// Script type of the account, each under its own BIP purpose. Wallets from before the choice
+/// existed are native SegWit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AddressType {
+    /// P2PKH, BIP44.
+    #[serde(rename = "p2pkh")]
+    Legacy,
+    /// P2SH-wrapped P2WPKH, BIP49.
+    #[serde(rename = "p2sh-p2wpkh")]
+    NestedSegwit,
+    /// P2WPKH, BIP84.
+    #[default]
+    #[serde(rename = "p2wpkh")]
+    NativeSegwit,
+    /// P2TR key path, BIP86.
+    #[serde(rename = "p2tr")]
+    Taproot,
+}
+
+impl AddressType {
+    pub const ALL: [AddressType; 4] = [
+        AddressType::Taproot,
+        AddressType::NativeSegwit,
+        AddressType::NestedSegwit,
+        AddressType::Legacy,
+    ];
+
+    fn purpose(self) -> u32 {
+        match self {
+            AddressType::Legacy => 44,
+            AddressType::NestedSegwit => 49,
+            AddressType::NativeSegwit => 84,
+            AddressType::Taproot => 86,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AddressType::Legacy => "LEGACY // BIP44",
+            AddressType::NestedSegwit => "NESTED_SEGWIT // BIP49",
+            AddressType::NativeSegwit => "NATIVE_SEGWIT // BIP84",
+            AddressType::Taproot => "TAPROOT // BIP86",
+        }
+    }
+
+    pub fn account_path(self) -> String {
+        format!("m/{}'/0'/0'", self.purpose())
+    }
+
+    /// The address this type makes of `public_key`.
+    pub fn address<C: Verification>(self, secp: &Secp256k1<C>, public_key: &CompressedPublicKey) -> Address {
+        match self {
+            AddressType::Legacy => Address::p2pkh(public_key, Network::Bitcoin),
+            AddressType::NestedSegwit => Address::p2shwpkh(public_key, Network::Bitcoin),
+            AddressType::NativeSegwit => Address::p2wpkh(public_key, Network::Bitcoin),
+            // BIP86: the key tweaked with an empty script tree
+            AddressType::Taproot => Address::p2tr(secp, public_key.0.x_only_public_key().0, None, Network::Bitcoin),
+        }
+    }
+}
+
+/// The external chain hands out receive addresses; the internal one takes change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyChain {
+    Receive,
+    Change,
+}
+
+impl KeyChain {
+    fn number(self) -> u32 {
+        match self {
+            KeyChain::Receive => 0,
+            KeyChain::Change => 1,
+        }
+    }
+}
+
+/// One derived address and what is known about it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackedAddress {
+    pub index: u32,
+    pub address: String,
+    /// Has a transaction on chain or in the mempool.
+    pub used: bool,
+    /// Was shown in the receive view, or received change in a signed transaction, so funds may
+    /// already be on their way to it.
+    #[serde(default)]
+    pub issued: bool,
+}
+
+/// What the user recorded about one unspent output.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoinNote {
+    pub label: String,
+    /// Left out of coin selection until unfrozen.
+    pub frozen: bool,
+}
+
+/// The `account` object of btc.json.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Account {
+    pub script_type: AddressType,
+    pub xpub: Option<String>,
+    /// Master key fingerprint, the key origin PSBTs carry for offline signers.
+    pub fingerprint: Option<String>,
+    pub gap_limit: u32,
+    pub receive: Vec<TrackedAddress>,
+    pub change: Vec<TrackedAddress>,
+    /// Labels and freezes, keyed by outpoint (txid:vout).
+    pub coins: BTreeMap<String, CoinNote>,
+}
+
+impl Default for Account {
+    fn default() -> Self {
+        Account {
+            script_type: AddressType::default(),
+            xpub: None,
+            fingerprint: None,
+            gap_limit: DEFAULT_GAP_LIMIT,
+            receive: Vec::new(),
+            change: Vec::new(),
+            coins: BTreeMap::new(),
+        }
+    }
+}
+
+impl Account {
+    pub fn path(&self, chain: KeyChain, index: u32) -> String {
+        format!("{}/{}/{}", self.script_type.account_path(), chain.number(), index)
+    }
+
+    /// Master fingerprint and full path of address `index` on `chain`. Accounts recorded before
+    /// fingerprints were kept report 00000000, which the offline signer resolves from its seed.
+    pub fn key_origin(&self, chain: KeyChain, index: u32) -> Result<(Fingerprint, DerivationPath), String> {
+        let fingerprint = match self.fingerprint.as_deref() {
+            Some(fingerprint) => Fingerprint::from_str(fingerprint)
+                .map_err(|e| format!("Invalid fingerprint: {}", e))?,
+            None => Fingerprint::default(),
+        };
+        let path = DerivationPath::from_str(&self.path(chain, index))
+            .map_err(|_| "Invalid derivation path".to_string())?;
+        Ok((fingerprint, path))
+    }
+
+    pub fn coin(&self, outpoint: &OutPoint) -> CoinNote {
+        self.coins.get(&outpoint.to_string()).cloned().unwrap_or_default()
+    }
+
+    pub fn addresses(&self, chain: KeyChain) -> &[TrackedAddress] {
+        match chain {
+            KeyChain::Receive => &self.receive,
+            KeyChain::Change => &self.change,
+        }
+    }
+
+    fn addresses_mut(&mut self, chain: KeyChain) -> &mut Vec<TrackedAddress> {
+        match chain {
+            KeyChain::Receive => &mut self.receive,
+            KeyChain::Change => &mut self.change,
+        }
+    }
+
+    /// Index for the next change output: the lowest change address with no history that no
+    /// signed transaction has paid to yet.
+    pub fn next_change_index(&self) -> u32 {
+        self.change.iter()
+            .find(|t| !t.used && !t.issued)
+            .map(|t| t.index)
+            .unwrap_or_else(|| self.change.iter().map(|t| t.index + 1).max().unwrap_or(0))
+    }
+}
+
+/// Account xpub of a BIP32 master key, e.g. m/84'/0'/0' for native SegWit.
+pub fn account_xpub(master: &Xpriv, address_type: AddressType) -> Result<Xpub, String> {
+    let secp = Secp256k1::new();
+    let path = DerivationPath::from_str(&address_type.account_path())
+        .map_err(|_| "Invalid derivation path".to_string())?;
+    master.derive_priv(&secp, &path)
+        .map(|account| Xpub::from_priv(&secp, &account))
+        .map_err(|e| format!("Derivation failed: {}", e))
+}
+
+pub fn xpub_from_seed(seed: &[u8; 64], address_type: AddressType) -> Result<Xpub, String> {
+    let master = Xpriv::new_master(Network::Bitcoin, seed)
+        .map_err(|e| format!("Failed to create master key: {}", e))?;
+    account_xpub(&master, address_type)
+}
+
+/// Key of address `index` on `chain`. The caller erases it once signed with.
+pub fn private_key(seed: &[u8; 64], address_type: AddressType, chain: KeyChain, index: u32) -> Result<PrivateKey, String> {
+    let secp = Secp256k1::new();
+    let path = DerivationPath::from_str(&format!("{}/{}/{}", address_type.account_path(), chain.number(), index))
+        .map_err(|_| "Invalid derivation path".to_string())?;
+    Xpriv::new_master(Network::Bitcoin, seed)
+        .and_then(|xpriv| xpriv.derive_priv(&secp, &path))
+        .map(|child| child.to_priv())
+        .map_err(|e| format!("Derivation failed: {}", e))
+}
+
+/// Public key of address `index` on `chain`, derived from the account xpub alone.
+pub fn public_key_at(xpub: &Xpub, chain: KeyChain, index: u32) -> Result<CompressedPublicKey, String> {
+    let secp = Secp256k1::verification_only();
+    let path = [ChildNumber::Normal { index: chain.number() }, ChildNumber::Normal { index }];
+    xpub.derive_pub(&secp, &path)
+        .map(|child| child.to_pub())
+        .map_err(|e| format!("Derivation failed: {}", e))
+}
+
+pub fn address_at(xpub: &Xpub, address_type: AddressType, chain: KeyChain, index: u32) -> Result<Address, String> {
+    let public_key = public_key_at(xpub, chain, index)?;
+    Ok(address_type.address(&Secp256k1::verification_only(), &public_key))
+}
+
+fn account_of(meta: &Value) -> Account {
+    meta.get(ACCOUNT_FIELD)
+        .cloned()
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+pub fn load() -> Account {
+    json_storage::read_json::<Value>(META_FILE)
+        .map(|meta| account_of(&meta))
+        .unwrap_or_default()
+}
+
+fn update(update_fn: impl FnOnce(&mut Account)) -> io::Result<()> {
+    json_storage::update_json(META_FILE, |data: &mut Value| {
+        if !data.is_object() {
+            *data = Value::Object(serde_json::Map::new());
+        }
+        let mut account = account_of(data);
+        update_fn(&mut account);
+        if let (Some(obj), Ok(value)) = (data.as_object_mut(), serde_json::to_value(&account)) {
+            obj.insert(ACCOUNT_FIELD.to_string(), value);
+        }
+    })
+}
+
+/// Starts tracking the account of a created or imported wallet. Another xpub is another seed
+/// or script type, so addresses tracked for it are dropped; the gap limit is kept.
+pub fn track(xpub: &Xpub, fingerprint: Fingerprint, address_type: AddressType) -> io::Result<()> {
+    let xpub = xpub.to_string();
+    update(|account| {
+        if account.xpub.as_deref() != Some(xpub.as_str()) || account.script_type != address_type {
+            *account = Account {
+                script_type: address_type,
+                xpub: Some(xpub),
+                gap_limit: account.gap_limit,
+                ..Account::default()
+            };
+        }
+        account.fingerprint = Some(fingerprint.to_string());
+    })
+}
+
+/// Wallets created before discovery existed have no xpub yet, and those from before PSBT export
+/// no fingerprint; record both the first time their seed is decrypted.
+pub fn adopt(seed: &[u8; 64]) -> Result<(), String> {
+    let account = load();
+    if account.xpub.is_some() && account.fingerprint.is_some() {
+        return Ok(());
+    }
+    let master = Xpriv::new_master(Network::Bitcoin, seed)
+        .map_err(|e| format!("Failed to create master key: {}", e))?;
+    let xpub = account_xpub(&master, account.script_type)?;
+    track(&xpub, master.fingerprint(&Secp256k1::new()), account.script_type).map_err(|e| e.to_string())
+}
+
+pub fn set_gap_limit(gap_limit: u32) -> io::Result<()> {
+    update(|account| account.gap_limit = gap_limit)
+}
+
+// Derives and checks addresses until `gap_limit` in a row have no history
+async fn scan(account: &Account, xpub: &Xpub, chain: KeyChain) -> Result<Vec<TrackedAddress>, String> {
+    let known = account.addresses(chain);
+    let mut scanned = Vec::new();
+    let mut gap = 0;
+    let mut index = 0;
+    while gap < account.gap_limit {
+        let address = address_at(xpub, account.script_type, chain, index)?.to_string();
+        // History never goes away, so only addresses not yet seen used are asked about
+        let used = known.iter().any(|t| t.index == index && t.used)
+            || esplora::has_history(&address).await?;
+        scanned.push(TrackedAddress { index, address, used, issued: false });
+        gap = if used { 0 } else { gap + 1 };
+        index += 1;
+    }
+    Ok(scanned)
+}
+
+// Keeps what was recorded locally, like `issued`
+fn merge(tracked: &mut Vec<TrackedAddress>, scanned: Vec<TrackedAddress>) {
+    for entry in scanned {
+        match tracked.iter_mut().find(|t| t.index == entry.index) {
+            Some(existing) => existing.used |= entry.used,
+            None => tracked.push(entry),
+        }
+    }
+    tracked.sort_by_key(|t| t.index);
+}
+
+/// Scans both chains and records which addresses have been used. Runs on wallet load and on
+/// refresh through `wallet::refresh_bitcoin`; fees, signing and PSBTs use the stored account.
+pub async fn discover() -> Result<Account, String> {
+    let account = load();
+    let Some(xpub) = account.xpub.as_deref().and_then(|x| Xpub::from_str(x).ok()) else {
+        return Ok(account);
+    };
+
+    let receive = scan(&account, &xpub, KeyChain::Receive).await?;
+    let change = scan(&account, &xpub, KeyChain::Change).await?;
+    update(|stored| {
+        // The wallet may have been replaced while the scan was running
+        if stored.xpub == account.xpub && stored.script_type == account.script_type {
+            merge(&mut stored.receive, receive);
+            merge(&mut stored.change, change);
+        }
+    }).map_err(|e| e.to_string())?;
+    Ok(load())
+}
+
+/// The address to show for the next receive request: the lowest unused index not shown before,
+/// at most `gap_limit` past the last used one so a restore elsewhere still finds it. Once that
+/// window is exhausted the lowest unused address is shown again.
+pub fn next_receive() -> Option<TrackedAddress> {
+    let mut next = None;
+    let _ = update(|account| {
+        let window_end = account.receive.iter()
+            .filter(|t| t.used)
+            .map(|t| t.index + 1)
+            .max()
+            .unwrap_or(0) + account.gap_limit;
+        let index = account.receive.iter()
+            .find(|t| !t.used && !t.issued && t.index < window_end)
+            .or_else(|| account.receive.iter().find(|t| !t.used))
+            .map(|t| t.index);
+        if let Some(entry) = index.and_then(|i| account.receive.iter_mut().find(|t| t.index == i)) {
+            entry.issued = true;
+            next = Some(entry.clone());
+        }
+    });
+    next
+}
+
+/// Records that a signed transaction pays change to `address`, so the next one picks another.
+pub fn reserve_change(index: u32, address: String) -> io::Result<()> {
+    update(|account| {
+        let change = account.addresses_mut(KeyChain::Change);
+        match change.iter_mut().find(|t| t.index == index) {
+            Some(entry) => entry.issued = true,
+            None => {
+                change.push(TrackedAddress { index, address, used: false, issued: true });
+                change.sort_by_key(|t| t.index);
+            }
+        }
+    })
+}
+
+fn note_coin(outpoint: &OutPoint, note_fn: impl FnOnce(&mut CoinNote)) -> io::Result<()> {
+    update(|account| {
+        let key = outpoint.to_string();
+        let note = account.coins.entry(key.clone()).or_default();
+        note_fn(note);
+        if *note == CoinNote::default() {
+            account.coins.remove(&key);
+        }
+    })
+}
+
+pub fn set_coin_label(outpoint: &OutPoint, label: String) -> io::Result<()> {
+    note_coin(outpoint, |note| note.label = label.trim().to_string())
+}
+
+pub fn set_coin_frozen(outpoint: &OutPoint, frozen: bool) -> io::Result<()> {
+    note_coin(outpoint, |note| note.frozen = frozen)
+}
+
+/// Drops the notes of coins that have since been spent.
+pub fn prune_coins(unspent: &[OutPoint]) -> io::Result<()> {
+    let unspent: HashSet<String> = unspent.iter().map(|outpoint| outpoint.to_string()).collect();
+    update(|account| account.coins.retain(|key, _| unspent.contains(key)))
+}
+
+/// Every address the wallet holds keys for, `wallet` included for accounts without an xpub.
+pub fn owned_addresses(wallet: &str) -> HashSet<String> {
+    let account = load();
+    let mut owned: HashSet<String> = account.receive.into_iter()
+        .chain(account.change)
+        .map(|t| t.address)
+        .collect();
+    owned.insert(wallet.to_string());
+    owned
+}
@@ -0,0 +1,212 @@
+// src/chain/esplora.rs
+//
+// Bitcoin address state from an Esplora REST API.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use bitcoin::consensus::encode::deserialize_hex;
+use bitcoin::{Amount, OutPoint, Transaction, Txid};
+use serde::Deserialize;
+
+const API_URL: &str = "https://blockstream.info/api";
+// Confirmed transactions per page of address history
+const HISTORY_PAGE: usize = 25;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utxo {
+    pub outpoint: OutPoint,
+    pub value: Amount,
+    pub confirmed: bool,
+    /// Block the output was mined in, for its confirmation count.
+    pub height: Option<u32>,
+}
+
+impl Utxo {
+    pub fn confirmations(&self, tip_height: u32) -> u32 {
+        self.height.map(|height| tip_height.saturating_sub(height) + 1).unwrap_or(0)
+    }
+}
+
+/// One input or output of a transaction in an address's history. Outputs that pay no address,
+/// like OP_RETURN, and coinbase inputs have none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPart {
+    pub address: Option<String>,
+    pub value: Amount,
+}
+
+/// A transaction from an address's history, with the value of every input and output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryTx {
+    pub txid: Txid,
+    pub confirmed: bool,
+    /// Unix time of the block it was mined in.
+    pub block_time: Option<u64>,
+    pub fee: Amount,
+    pub inputs: Vec<TxPart>,
+    pub outputs: Vec<TxPart>,
+}
+
+#[derive(Deserialize)]
+struct RawStatus {
+    confirmed: bool,
+    block_height: Option<u32>,
+    #[serde(default)]
+    block_time: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct RawOutput {
+    scriptpubkey_address: Option<String>,
+    value: u64,
+}
+
+#[derive(Deserialize)]
+struct RawInput {
+    prevout: Option<RawOutput>,
+}
+
+#[derive(Deserialize)]
+struct RawTx {
+    txid: String,
+    vin: Vec<RawInput>,
+    vout: Vec<RawOutput>,
+    fee: u64,
+    status: RawStatus,
+}
+
+impl RawOutput {
+    fn part(self) -> TxPart {
+        TxPart { address: self.scriptpubkey_address, value: Amount::from_sat(self.value) }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawUtxo {
+    txid: String,
+    vout: u32,
+    value: u64,
+    status: RawStatus,
+}
+
+#[derive(Deserialize)]
+struct RawStats {
+    tx_count: u64,
+}
+
+#[derive(Deserialize)]
+struct RawAddress {
+    chain_stats: RawStats,
+    mempool_stats: RawStats,
+}
+
+async fn request(path: &str) -> Result<reqwest::Response, String> {
+    super::client()?
+        .get(format!("{}{}", API_URL, path))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Esplora request failed: {}", e))
+}
+
+async fn text(path: &str) -> Result<String, String> {
+    request(path)
+        .await?
+        .text()
+        .await
+        .map_err(|e| format!("Esplora response unreadable: {}", e))
+}
+
+async fn get<T: for<'de> Deserialize<'de>>(path: &str) -> Result<T, String> {
+    request(path)
+        .await?
+        .json()
+        .await
+        .map_err(|e| format!("Esplora response unreadable: {}", e))
+}
+
+/// Unspent outputs paying to `address`, including unconfirmed ones.
+pub async fn utxos(address: &str) -> Result<Vec<Utxo>, String> {
+    let raw: Vec<RawUtxo> = get(&format!("/address/{}/utxo", address)).await?;
+    raw.into_iter()
+        .map(|u| {
+            let txid = Txid::from_str(&u.txid).map_err(|e| format!("Invalid txid {}: {}", u.txid, e))?;
+            Ok(Utxo {
+                outpoint: OutPoint { txid, vout: u.vout },
+                value: Amount::from_sat(u.value),
+                confirmed: u.status.confirmed,
+                height: u.status.block_height,
+            })
+        })
+        .collect()
+}
+
+/// Whether `address` has ever appeared in a transaction, confirmed or not.
+pub async fn has_history(address: &str) -> Result<bool, String> {
+    let raw: RawAddress = get(&format!("/address/{}", address)).await?;
+    Ok(raw.chain_stats.tx_count + raw.mempool_stats.tx_count > 0)
+}
+
+/// Every transaction `address` appears in, unconfirmed ones first, then newest first.
+pub async fn address_txs(address: &str) -> Result<Vec<HistoryTx>, String> {
+    let mut raw: Vec<RawTx> = get(&format!("/address/{}/txs", address)).await?;
+    // The first page holds the mempool and a page of confirmed ones; the rest come a page at a time
+    let mut confirmed = raw.iter().filter(|tx| tx.status.confirmed).count();
+    while confirmed == HISTORY_PAGE {
+        let Some(last) = raw.last().map(|tx| tx.txid.clone()) else { break };
+        let page: Vec<RawTx> = get(&format!("/address/{}/txs/chain/{}", address, last)).await?;
+        confirmed = page.len();
+        raw.extend(page);
+    }
+
+    raw.into_iter()
+        .map(|tx| {
+            let txid = Txid::from_str(&tx.txid).map_err(|e| format!("Invalid txid {}: {}", tx.txid, e))?;
+            Ok(HistoryTx {
+                txid,
+                confirmed: tx.status.confirmed,
+                block_time: tx.status.block_time,
+                fee: Amount::from_sat(tx.fee),
+                inputs: tx.vin.into_iter()
+                    .map(|input| input.prevout.map(RawOutput::part).unwrap_or(TxPart { address: None, value: Amount::ZERO }))
+                    .collect(),
+                outputs: tx.vout.into_iter().map(RawOutput::part).collect(),
+            })
+        })
+        .collect()
+}
+
+/// The full transaction `txid`, which a PSBT carries for each legacy input it spends.
+pub async fn transaction(txid: &Txid) -> Result<Transaction, String> {
+    let hex = text(&format!("/tx/{}/hex", txid)).await?;
+    deserialize_hex(hex.trim()).map_err(|e| format!("Invalid transaction {}: {}", txid, e))
+}
+
+/// Height of the chain tip.
+pub async fn tip_height() -> Result<u32, String> {
+    let height = text("/blocks/tip/height").await?;
+    height.trim().parse().map_err(|e| format!("Invalid tip height {}: {}", height.trim(), e))
+}
+
+/// Fee rate in sat/vB expected to confirm within each number of blocks.
+pub async fn fee_estimates() -> Result<BTreeMap<u16, f64>, String> {
+    let raw: BTreeMap<String, f64> = get("/fee-estimates").await?;
+    Ok(raw.into_iter()
+        .filter_map(|(target, rate)| target.parse().ok().map(|target| (target, rate)))
+        .collect())
+}
+
+/// Estimated rate for confirming within `target` blocks: that of the nearest target not
+/// beyond it.
+pub fn rate_for(estimates: &BTreeMap<u16, f64>, target: u16) -> Option<f64> {
+    estimates.range(..=target).next_back()
+        .or_else(|| estimates.iter().next())
+        .map(|(_, rate)| *rate)
+}
+
+/// Fewest blocks `rate` is expected to confirm within, or None when it is below every estimate.
+pub fn target_for(estimates: &BTreeMap<u16, f64>, rate: f64) -> Option<u16> {
+    estimates.iter()
+        .find(|(_, estimate)| rate >= **estimate)
+        .map(|(target, _)| *target)
+}
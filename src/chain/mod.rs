@@ -0,0 +1,21 @@
+// src/chain/mod.rs
+//
+// Read-only chain state the signer needs before it can build a transaction: the XRPL account's
+// next sequence, the current ledger and the open-ledger fee, and the Bitcoin wallet's unspent
+// outputs. Only public data passes through here. Signed blobs still go to the ws task, which
+// owns submission and broadcast.
+
+pub mod esplora;
+pub mod xrpl;
+
+use std::time::Duration;
+use reqwest::Client;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn client() -> Result<Client, String> {
+    Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .map_err(|e| format!("HTTP client error: {}", e))
+}
@@ -0,0 +1,72 @@
+// src/chain/xrpl.rs
+//
+// XRPL JSON-RPC queries used to autofill a transaction before it is signed.
+
+use serde_json::{json, Value};
+
+const RPC_URL: &str = "https://xrplcluster.com/";
+
+// Ledgers a signed transaction stays valid for; roughly a minute on mainnet
+const LEDGER_WINDOW: u32 = 20;
+
+// Refuse to sign while the open ledger charges more than 0.1 XRP per transaction
+const MAX_FEE_DROPS: u64 = 100_000;
+
+/// Autofill values for the next transaction from one account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountState {
+    pub sequence: u32,
+    pub last_ledger_sequence: u32,
+    pub fee_drops: u64,
+}
+
+async fn call(method: &str, params: Value) -> Result<Value, String> {
+    let body = json!({ "method": method, "params": [params] });
+    let response: Value = super::client()?
+        .post(RPC_URL)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("XRPL request failed: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("XRPL response unreadable: {}", e))?;
+
+    let result = response.get("result").cloned().unwrap_or(Value::Null);
+    if result.get("status").and_then(|s| s.as_str()) == Some("error") {
+        let error = result.get("error_message")
+            .or_else(|| result.get("error"))
+            .and_then(|e| e.as_str())
+            .unwrap_or("unknown error");
+        return Err(format!("XRPL {} failed: {}", method, error));
+    }
+    Ok(result)
+}
+
+fn field_u64(value: &Value, pointer: &str) -> Option<u64> {
+    let field = value.pointer(pointer)?;
+    field.as_u64().or_else(|| field.as_str().and_then(|s| s.parse().ok()))
+}
+
+/// Next sequence of `address` in the current open ledger, and the fee to pay for it.
+pub async fn account_state(address: &str) -> Result<AccountState, String> {
+    let info = call("account_info", json!({ "account": address, "ledger_index": "current" })).await?;
+    let sequence = field_u64(&info, "/account_data/Sequence")
+        .ok_or("Account is not activated on the XRP Ledger")?;
+    let ledger = field_u64(&info, "/ledger_current_index")
+        .ok_or("XRPL response is missing the current ledger")?;
+
+    let fee = call("fee", json!({})).await?;
+    let base = field_u64(&fee, "/drops/base_fee").unwrap_or(10);
+    let open = field_u64(&fee, "/drops/open_ledger_fee").unwrap_or(base);
+    let fee_drops = open.max(base);
+    if fee_drops > MAX_FEE_DROPS {
+        return Err(format!("Network fee is unusually high ({} drops), try again later", fee_drops));
+    }
+
+    Ok(AccountState {
+        sequence: sequence as u32,
+        last_ledger_sequence: ledger as u32 + LEDGER_WINDOW,
+        fee_drops,
+    })
+}
@@ -4,6 +4,9 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing; 
 use std::sync::LazyLock;
+use std::time::Instant;
+use crate::btcaccount::AddressType;
+use crate::clipboard::AddressChain;
 
 pub static CHANNEL: LazyLock<Channel> = LazyLock::new(Channel::new);
 
@@ -20,8 +23,11 @@ pub enum Tab {
 pub enum SideBarView {
     None,
     ChangePin,
+    ChangePassphrase,
+    Backup,
     ExchangeRates,
     NetworkStatus,
+    AuditLog,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -74,22 +80,30 @@ pub struct SignTransactionState {
     pub send_transaction: Option<SignTransaction>,
 }
 
+/// An open signing session as the top bar shows it; the seed itself stays in crate::signer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningSession {
+    pub chain: AddressChain,
+    pub expires: Instant,
+    pub remaining: Option<u32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct WSCommand {
     pub command: String,
     pub wallet: Option<String>,
     pub recipient: Option<String>,
     pub amount: Option<String>,
-    pub passphrase: Option<Zeroizing<String>>,
     pub trustline_limit: Option<String>,
     pub fee: Option<String>,
     pub tx_type: Option<String>,
     pub taker_pays: Option<(String, String)>,
     pub taker_gets: Option<(String, String)>,
-    pub seed: Option<Zeroizing<String>>,
     pub flags: Option<Vec<String>>,
     pub wallet_type: Option<String>, 
-    pub bip39: Option<Zeroizing<String>>,
+    // Submit commands carry a blob already signed by crate::signer; no secret travels in a WSCommand
+    pub signed_tx: Option<String>,
+    pub tx_hash: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
@@ -124,6 +138,9 @@ pub struct XRPWalletProcessState {
 pub struct XRPImport {
     pub step: u8,
     pub seed: Option<Zeroizing<String>>, 
+    pub shares: Option<Zeroizing<Vec<String>>>, // SLIP-39 shares generated from `seed`
+    pub unified: bool, // create: seal as the master seed for both chains
+    pub address_type: AddressType, // script type of the account; unified wallets stay native SegWit
     pub error: Option<String>,
 }
 
@@ -153,6 +170,9 @@ pub struct SignTradeState {
 pub struct BTCImport {
     pub step: u8,
     pub seed: Option<Zeroizing<String>>, 
+    pub shares: Option<Zeroizing<Vec<String>>>, // SLIP-39 shares generated from `seed`
+    pub unified: bool, // create: seal as the master seed for both chains
+    pub address_type: AddressType, // script type of the account; unified wallets stay native SegWit
     pub error: Option<String>,
 }
 
@@ -170,7 +190,13 @@ pub struct BTCSignTransaction {
     pub recipient: Option<String>,
     pub amount: Option<String>,
     pub asset: String, 
-    pub fee: String, 
+    pub fee: String, // absolute, in sats, from fee_rate and the estimated vsize
+    #[serde(default)]
+    pub fee_rate: String, // sat/vB
+    #[serde(default)]
+    pub fee_target: Option<u16>, // blocks the rate is expected to confirm within
+    #[serde(default)]
+    pub inputs: Vec<String>, // coin control: outpoints to spend, empty selects automatically
 }
 
 #[derive(Debug, Clone, Default)]
@@ -178,6 +204,33 @@ pub struct BTCSignTransactionState {
     pub send_transaction: Option<BTCSignTransaction>,
 }
 
+/// The PSBT being passed between the online and the offline install.
+#[derive(Debug, Clone, Default)]
+pub struct BTCPsbtState {
+    pub psbt: Option<String>, // base64, as shown in the QR code
+    pub error: Option<String>,
+}
+
+/// The BTC account as read from chain: every receive and change address, not only the wallet
+/// address the backend reports on. `wallet` is the address it was read for.
+#[derive(Debug, Clone, Default)]
+pub struct BTCAccountState {
+    pub wallet: Option<String>,
+    pub balance: Option<f64>, // BTC, over every unspent output of the account
+    pub transactions: HashMap<String, BTCTransactionData>, // History of every used address
+    pub error: Option<String>,
+}
+
+impl BTCAccountState {
+    /// The account balance if it was read for `wallet`, else the backend's `reported` one.
+    pub fn balance_or(&self, wallet: Option<&str>, reported: f64) -> f64 {
+        match self.balance {
+            Some(balance) if wallet.is_some() && self.wallet.as_deref() == wallet => balance,
+            _ => reported,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct BTCWalletProcessState {
     pub import_wallet: Option<BTCImport>,
@@ -193,6 +246,8 @@ pub enum BTCActiveView {
     Import,   // Added
     Create,   // Added
     Send,     // Added
+    Psbt,     // Air-gapped signing: create, sign, finalize
+    Utxos,    // Coin control
 }
 
 
@@ -218,6 +273,14 @@ pub struct BTCTransactionData {
     pub receiver_addresses: Vec<String>, // List of recipient addresses
     pub sender_addresses: Vec<String>,   // List of sender addresses
     pub timestamp: String,      // ISO 8601 timestamp
+    #[serde(default)]
+    pub outputs: Vec<BTCOutput>, // Every output, so change to wallet addresses can be told apart
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BTCOutput {
+    pub address: String,
+    pub value: u64,             // Satoshis
 }
 
 //channels
@@ -240,6 +303,10 @@ pub struct Channel {
     pub crypto_ws_status_rx: watch::Receiver<bool>, 
     pub sidebar_view_tx: watch::Sender<SideBarView >,
     pub sidebar_view_rx: watch::Receiver<SideBarView >,
+    pub locked_tx: watch::Sender<bool>,
+    pub locked_rx: watch::Receiver<bool>,
+    pub signing_sessions_tx: watch::Sender<Vec<SigningSession>>,
+    pub signing_sessions_rx: watch::Receiver<Vec<SigningSession>>,
 
     //rlusd channels
     pub rlusd_tx: watch::Sender<(f64, bool, Option<f64>)>,
@@ -262,6 +329,8 @@ pub struct Channel {
     pub xrp_wallet_process_rx: watch::Receiver<XRPWalletProcessState>,
     pub trade_tx: watch::Sender<SignTradeState>,
     pub trade_rx: watch::Receiver<SignTradeState>,
+    pub xrp_backup_verified_tx: watch::Sender<bool>,
+    pub xrp_backup_verified_rx: watch::Receiver<bool>,
   
 
     //euro channels
@@ -279,6 +348,12 @@ pub struct Channel {
     pub btc_wallet_process_rx: watch::Receiver<BTCWalletProcessState>,
     pub btc_sign_transaction_tx: watch::Sender<BTCSignTransactionState>,
     pub btc_sign_transaction_rx: watch::Receiver<BTCSignTransactionState>,
+    pub btc_psbt_tx: watch::Sender<BTCPsbtState>,
+    pub btc_psbt_rx: watch::Receiver<BTCPsbtState>,
+    pub btc_account_tx: watch::Sender<BTCAccountState>,
+    pub btc_account_rx: watch::Receiver<BTCAccountState>,
+    pub btc_backup_verified_tx: watch::Sender<bool>,
+    pub btc_backup_verified_rx: watch::Receiver<bool>,
 }
 
 impl Channel {
@@ -293,6 +368,8 @@ impl Channel {
         let (exchange_ws_status_tx, exchange_ws_status_rx) = watch::channel(false);
         let (crypto_ws_status_tx, crypto_ws_status_rx) = watch::channel(false);
         let (sidebar_view_tx, sidebar_view_rx) = watch::channel(SideBarView::None);
+        let (locked_tx, locked_rx) = watch::channel(true);
+        let (signing_sessions_tx, signing_sessions_rx) = watch::channel(Vec::new());
 
 
         
@@ -310,6 +387,7 @@ impl Channel {
         let (xrp_wallet_process_tx, xrp_wallet_process_rx) = watch::channel(XRPWalletProcessState::default());
         let (transactions_tx, transactions_rx) = watch::channel(TransactionState::default());
         let (trade_tx, trade_rx) = watch::channel(SignTradeState::default());
+        let (xrp_backup_verified_tx, xrp_backup_verified_rx) = watch::channel(true);
 
      
         //euro related
@@ -320,7 +398,10 @@ impl Channel {
         let (btc_modal_tx, btc_modal_rx) = watch::channel(BTCModalState::default());
         let (btc_transactions_tx, btc_transactions_rx) = watch::channel(BTCTransactionState::default());
         let (btc_sign_transaction_tx, btc_sign_transaction_rx) = watch::channel(BTCSignTransactionState::default());
+        let (btc_psbt_tx, btc_psbt_rx) = watch::channel(BTCPsbtState::default());
+        let (btc_account_tx, btc_account_rx) = watch::channel(BTCAccountState::default());
         let (btc_wallet_process_tx, btc_wallet_process_rx) = watch::channel(BTCWalletProcessState::default());
+        let (btc_backup_verified_tx, btc_backup_verified_rx) = watch::channel(true);
 
 
         Channel {
@@ -342,6 +423,10 @@ impl Channel {
             crypto_ws_status_rx,
             sidebar_view_tx,
             sidebar_view_rx,
+            locked_tx,
+            locked_rx,
+            signing_sessions_tx,
+            signing_sessions_rx,
           
             //xrp, euro, sgd and rlusd related
             rlusd_tx,
@@ -362,6 +447,8 @@ impl Channel {
             transactions_rx,
             trade_tx,
             trade_rx,
+            xrp_backup_verified_tx,
+            xrp_backup_verified_rx,
               
             //bitcoin related
             bitcoin_wallet_tx,
@@ -374,6 +461,12 @@ impl Channel {
             btc_wallet_process_rx,
             btc_sign_transaction_tx,
             btc_sign_transaction_rx,
+            btc_psbt_tx,
+            btc_psbt_rx,
+            btc_account_tx,
+            btc_account_rx,
+            btc_backup_verified_tx,
+            btc_backup_verified_rx,
             
         }
     }
@@ -0,0 +1,142 @@
+// src/clipboard.rs
+//
+// Every clipboard write goes through here. Anything Dannesk copies is cleared again after the
+// configured timeout (if it is still on the clipboard), and the last address we copied is
+// remembered per chain so a paste can be checked against clipboard-hijacker substitution.
+
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use arboard::Clipboard;
+use bitcoin::hashes::{sha256, Hash};
+use crate::preferences;
+
+/// Chains whose addresses the guard can recognise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressChain {
+    Xrp,
+    Btc,
+}
+
+impl AddressChain {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AddressChain::Xrp => "XRP",
+            AddressChain::Btc => "BTC",
+        }
+    }
+
+    /// Loose syntactic check, enough to tell whether clipboard text is an address of this chain.
+    pub fn looks_like(&self, text: &str) -> bool {
+        let text = text.trim();
+        match self {
+            AddressChain::Xrp => {
+                text.starts_with('r')
+                    && (25..=35).contains(&text.len())
+                    && text.chars().all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c))
+            }
+            AddressChain::Btc => bitcoin::Address::from_str(text).is_ok(),
+        }
+    }
+}
+
+struct Copied {
+    // Only a digest is kept, so a copied secret is not held in memory here
+    digest: [u8; 32],
+    address: Option<(AddressChain, String)>,
+}
+
+static LAST_COPY: Mutex<Option<Copied>> = Mutex::new(None);
+
+// Bumped on every copy so an older auto-clear timer never wipes a newer copy
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn digest(text: &str) -> [u8; 32] {
+    sha256::Hash::hash(text.as_bytes()).to_byte_array()
+}
+
+fn read_text() -> Option<String> {
+    Clipboard::new().ok()?.get_text().ok()
+}
+
+fn copy(text: &str, address: Option<(AddressChain, String)>) {
+    let Ok(mut ctx) = Clipboard::new() else { return; };
+    if ctx.set_text(text.to_string()).is_err() {
+        return;
+    }
+
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let digest = digest(text);
+    if let Ok(mut last) = LAST_COPY.lock() {
+        *last = Some(Copied { digest, address });
+    }
+
+    if let Some(secs) = preferences::load().clipboard_clear_secs {
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(secs as u64)).await;
+            if GENERATION.load(Ordering::SeqCst) == generation {
+                clear_if_ours();
+            }
+        });
+    }
+}
+
+/// Copies a receive address and remembers it for the hijack check.
+pub fn copy_address(chain: AddressChain, address: &str) {
+    copy(address, Some((chain, address.to_string())));
+}
+
+/// Copies sensitive text such as a mnemonic. Subject to the same auto-clear.
+pub fn copy_secret(text: &str) {
+    copy(text, None);
+}
+
+/// Empties the clipboard unconditionally, e.g. after a pasted secret has been used.
+pub fn clear() {
+    if let Ok(mut ctx) = Clipboard::new() {
+        let _ = ctx.set_text("");
+    }
+    if let Ok(mut last) = LAST_COPY.lock() {
+        *last = None;
+    }
+}
+
+/// Empties the clipboard only if it still holds what Dannesk put there.
+pub fn clear_if_ours() {
+    let ours = match (read_text(), LAST_COPY.lock()) {
+        (Some(current), Ok(last)) => last.as_ref().map(|c| c.digest == digest(&current)).unwrap_or(false),
+        _ => false,
+    };
+    if ours {
+        clear();
+    }
+}
+
+/// Called when a recipient field changes. If `value` is what's on the clipboard (i.e. it was
+/// pasted) and it is a different `chain` address from the one Dannesk last copied, the
+/// clipboard was most likely rewritten by malware. Returns the warning to show.
+pub fn check_pasted_recipient(chain: AddressChain, value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let copied = LAST_COPY.lock().ok()?
+        .as_ref()
+        .and_then(|c| c.address.clone())
+        .filter(|(c, _)| *c == chain)
+        .map(|(_, addr)| addr)?;
+
+    let current = read_text()?;
+    let current = current.trim();
+    if current != value || current == copied || !chain.looks_like(current) {
+        return None;
+    }
+
+    Some(format!(
+        "CLIPBOARD_CHANGED: Dannesk copied {} but the clipboard now holds {}. \
+         Clipboard-hijacking malware swaps addresses this way. Verify every character before sending.",
+        copied, current
+    ))
+}
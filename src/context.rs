@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use tokio::sync::watch; // watch::Receiver
 use tokio::sync::mpsc; // mpsc::Sender
 use crate::channel::{CHANNEL, Tab, ProgressState, SignTradeState, XRPWalletProcessState, 
-    BTCWalletProcessState, TransactionState, SideBarView, BTCSignTransactionState, SignTransactionState, XRPModalState, BTCModalState, BTCTransactionState, WSCommand};
+    BTCWalletProcessState, TransactionState, SideBarView, BTCSignTransactionState, BTCPsbtState, BTCAccountState, SignTransactionState, XRPModalState, BTCModalState, BTCTransactionState, WSCommand, SigningSession};
 
 // --- Define Context Structs (Bundles of Signals) ---
 
@@ -19,6 +19,8 @@ pub struct GlobalContext {
     pub crypto_ws_status: Signal<bool>,
     pub ws_tx: mpsc::Sender<WSCommand>, 
     pub sidebar_view: Signal<SideBarView>,
+    pub locked: Signal<bool>,
+    pub signing_sessions: Signal<Vec<SigningSession>>,
 
 
 
@@ -32,6 +34,7 @@ pub struct XrpContext {
     pub wallet_process: Signal<XRPWalletProcessState>, 
     pub transactions: Signal<TransactionState>,
     pub trade: Signal<SignTradeState>,
+    pub backup_verified: Signal<bool>,
 }
 
 #[derive(Clone, Copy)]
@@ -56,6 +59,9 @@ pub struct BtcContext {
     pub btc_transactions: Signal<BTCTransactionState>,
     pub btc_wallet_process: Signal<BTCWalletProcessState>, 
     pub btc_sign_transaction: Signal<BTCSignTransactionState>,
+    pub btc_psbt: Signal<BTCPsbtState>,
+    pub btc_account: Signal<BTCAccountState>,
+    pub backup_verified: Signal<bool>,
 
 
 }
@@ -73,6 +79,8 @@ pub fn setup_contexts(ws_tx: mpsc::Sender<WSCommand>) {
         crypto_ws_status: use_signal(|| CHANNEL.crypto_ws_status_rx.borrow().clone()),
         ws_tx, // NEW: Add here (no clone needed, as it's passed by value)
         sidebar_view: use_signal(|| CHANNEL.sidebar_view_rx.borrow().clone()),
+        locked: use_signal(|| CHANNEL.locked_rx.borrow().clone()),
+        signing_sessions: use_signal(|| CHANNEL.signing_sessions_rx.borrow().clone()),
 
 
 
@@ -89,6 +97,8 @@ use_context_provider(|| global.clone());
     subscribe_to_channel(global.exchange_ws_status, CHANNEL.exchange_ws_status_rx.clone());
     subscribe_to_channel(global.crypto_ws_status, CHANNEL.crypto_ws_status_rx.clone());
     subscribe_to_channel(global.sidebar_view, CHANNEL.sidebar_view_rx.clone());
+    subscribe_to_channel(global.locked, CHANNEL.locked_rx.clone());
+    subscribe_to_channel(global.signing_sessions, CHANNEL.signing_sessions_rx.clone());
 
 
 
@@ -101,6 +111,7 @@ use_context_provider(|| global.clone());
         wallet_process: use_signal(|| CHANNEL.xrp_wallet_process_rx.borrow().clone()), 
         transactions: use_signal(|| CHANNEL.transactions_rx.borrow().clone()),
         trade: use_signal(|| CHANNEL.trade_rx.borrow().clone()),
+        backup_verified: use_signal(|| CHANNEL.xrp_backup_verified_rx.borrow().clone()),
 
     };
     use_context_provider(|| xrp);
@@ -111,6 +122,7 @@ use_context_provider(|| global.clone());
     subscribe_to_channel(xrp.wallet_process, CHANNEL.xrp_wallet_process_rx.clone()); 
     subscribe_to_channel(xrp.transactions, CHANNEL.transactions_rx.clone());
     subscribe_to_channel(xrp.trade, CHANNEL.trade_rx.clone());
+    subscribe_to_channel(xrp.backup_verified, CHANNEL.xrp_backup_verified_rx.clone());
 
 
     // RLUSD Context
@@ -144,6 +156,9 @@ use_context_provider(|| global.clone());
         btc_transactions: use_signal(|| CHANNEL.btc_transactions_rx.borrow().clone()),
         btc_wallet_process: use_signal(|| CHANNEL.btc_wallet_process_rx.borrow().clone()), 
         btc_sign_transaction: use_signal(|| CHANNEL.btc_sign_transaction_rx.borrow().clone()),
+        btc_psbt: use_signal(|| CHANNEL.btc_psbt_rx.borrow().clone()),
+        btc_account: use_signal(|| CHANNEL.btc_account_rx.borrow().clone()),
+        backup_verified: use_signal(|| CHANNEL.btc_backup_verified_rx.borrow().clone()),
 
 
     };
@@ -154,6 +169,9 @@ use_context_provider(|| global.clone());
     subscribe_to_channel(btc.btc_transactions, CHANNEL.btc_transactions_rx.clone());
     subscribe_to_channel(btc.btc_wallet_process, CHANNEL.btc_wallet_process_rx.clone());
     subscribe_to_channel(btc.btc_sign_transaction, CHANNEL.btc_sign_transaction_rx.clone());
+    subscribe_to_channel(btc.btc_psbt, CHANNEL.btc_psbt_rx.clone());
+    subscribe_to_channel(btc.btc_account, CHANNEL.btc_account_rx.clone());
+    subscribe_to_channel(btc.backup_verified, CHANNEL.btc_backup_verified_rx.clone());
  
 
 }
@@ -0,0 +1,129 @@
+// src/entropy.rs
+//
+// Fresh mnemonics for the create flows. Entropy always comes from the OS RNG; the user can
+// additionally mix in dice rolls or coin flips. The mix is a plain XOR with a SHA-256 of the
+// rolls, so the result can be recomputed by hand from the values shown on screen:
+//
+//     ENTROPY = RNG XOR SHA256(ROLLS)[0..N]
+//
+// Because RNG is uniform and independent of the rolls, the result is never weaker than RNG alone,
+// and a backdoored RNG is still covered as long as the rolls are honest and long enough.
+
+use bip39::{Language, Mnemonic};
+use bitcoin::hashes::{sha256, Hash};
+use rand::{rng, Rng};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Mnemonic lengths offered at creation.
+pub const WORD_COUNTS: [usize; 3] = [12, 18, 24];
+pub const DEFAULT_WORD_COUNT: usize = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntropySource {
+    #[default]
+    Dice,
+    Coin,
+}
+
+impl EntropySource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EntropySource::Dice => "DICE_D6",
+            EntropySource::Coin => "COIN_FLIPS",
+        }
+    }
+
+    /// Accepted symbols, as typed by the user.
+    pub fn alphabet(&self) -> &'static str {
+        match self {
+            EntropySource::Dice => "1-6",
+            EntropySource::Coin => "H/T or 1/0",
+        }
+    }
+
+    /// Rolls needed for the user input alone to cover the full entropy of `word_count` words.
+    pub fn recommended_rolls(&self, word_count: usize) -> usize {
+        let bits = entropy_len(word_count) * 8;
+        match self {
+            // log2(6) ~= 2.585 bits per roll
+            EntropySource::Dice => (bits as f64 / 6f64.log2()).ceil() as usize,
+            EntropySource::Coin => bits,
+        }
+    }
+
+    /// Normalises the typed rolls: whitespace and separators are dropped, coins become 1 (heads)
+    /// and 0 (tails). The returned string is exactly what gets hashed.
+    pub fn normalise(&self, input: &str) -> Result<Zeroizing<String>, String> {
+        let mut out = Zeroizing::new(String::with_capacity(input.len()));
+        for c in input.chars().filter(|c| !c.is_whitespace() && *c != ',' && *c != '-') {
+            let symbol = match (self, c.to_ascii_uppercase()) {
+                (EntropySource::Dice, d @ '1'..='6') => d,
+                (EntropySource::Coin, 'H' | '1') => '1',
+                (EntropySource::Coin, 'T' | '0') => '0',
+                _ => return Err(format!("'{}' is not a valid {} value ({})", c, self.label(), self.alphabet())),
+            };
+            out.push(symbol);
+        }
+        Ok(out)
+    }
+}
+
+/// Bytes of entropy behind a BIP39 mnemonic of `word_count` words (12 -> 16, 18 -> 24, 24 -> 32).
+pub fn entropy_len(word_count: usize) -> usize {
+    word_count * 4 / 3
+}
+
+/// Every intermediate value of a mix, hex encoded, so the user can check the formula.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixReport {
+    pub rolls: Zeroizing<String>,
+    pub rng_hex: Zeroizing<String>,
+    pub digest_hex: Zeroizing<String>,
+    pub entropy_hex: Zeroizing<String>,
+}
+
+fn hex(bytes: &[u8]) -> Zeroizing<String> {
+    Zeroizing::new(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// A new English mnemonic of `word_count` words from the OS RNG only.
+pub fn new_mnemonic(word_count: usize) -> Result<Zeroizing<String>, String> {
+    mixed_mnemonic(word_count, None).map(|(mnemonic, _)| mnemonic)
+}
+
+/// A new mnemonic of `word_count` words. With `rolls`, RNG output is XORed with SHA256 of the
+/// normalised rolls before `Mnemonic::from_entropy_in`, and the report of that mix is returned.
+pub fn mixed_mnemonic(
+    word_count: usize,
+    rolls: Option<(EntropySource, &str)>,
+) -> Result<(Zeroizing<String>, Option<MixReport>), String> {
+    if !WORD_COUNTS.contains(&word_count) {
+        return Err(format!("Unsupported mnemonic length: {} words", word_count));
+    }
+
+    let len = entropy_len(word_count);
+    let mut entropy = Zeroizing::new(vec![0u8; len]);
+    rng().fill_bytes(&mut entropy);
+
+    let report = match rolls {
+        None => None,
+        Some((source, input)) => {
+            let rolls = source.normalise(input)?;
+            if rolls.is_empty() {
+                return Err(format!("No {} entered", source.label()));
+            }
+            let rng_hex = hex(&entropy);
+            let mut digest = sha256::Hash::hash(rolls.as_bytes()).to_byte_array();
+            for (byte, d) in entropy.iter_mut().zip(digest.iter()) {
+                *byte ^= d;
+            }
+            let digest_hex = hex(&digest[..len]);
+            digest.zeroize();
+            Some(MixReport { rolls, rng_hex, digest_hex, entropy_hex: hex(&entropy) })
+        }
+    };
+
+    let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
+        .map_err(|e| format!("Mnemonic generation failed: {}", e))?;
+    Ok((Zeroizing::new(mnemonic.to_string()), report))
+}
@@ -0,0 +1,287 @@
+// src/keystore.rs
+//
+// Versioned on-disk format for the encrypted wallet files (xrp_encrypt.json,
+// btc_encrypt.json). Every record carries the KDF that produced its key, so the
+// Argon2 cost can be raised later without orphaning older wallets.
+
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use argon2::{Argon2, Algorithm, Version, Params};
+use crate::secmem::{SecretBytes, SecretString};
+use crate::encrypt::encrypt_data;
+use crate::decrypt::decrypt_data;
+use crate::utils::json_storage;
+use crate::channel::{CHANNEL, ProgressState};
+
+/// Current on-disk format. Version 1 is the original `{address, encrypted_phrase, salt, iv}` layout.
+pub const KEYSTORE_VERSION: u32 = 2;
+
+pub const KDF_ARGON2ID: &str = "argon2id";
+pub const CIPHER_AES_256_GCM: &str = "aes-256-gcm";
+
+// Calibrated parameters for this machine live next to the wallet files.
+const KDF_CONFIG_FILE: &str = "kdf.json";
+
+// Calibration aims for roughly this long per unlock and never drops below the legacy cost.
+const TARGET_UNLOCK: Duration = Duration::from_millis(1000);
+const MAX_MEMORY_KIB: u32 = 262_144; // 256MB
+const MAX_ITERATIONS: u32 = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub algorithm: String,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub lanes: u32,
+}
+
+impl KdfParams {
+    /// The parameters every version 1 file was written with (64MB RAM, 3 iterations, 4 lanes).
+    pub fn legacy() -> Self {
+        KdfParams {
+            algorithm: KDF_ARGON2ID.to_string(),
+            memory_kib: 65536,
+            iterations: 3,
+            lanes: 4,
+        }
+    }
+
+    /// True if `self` costs less than `other` in memory, time or parallelism.
+    pub fn weaker_than(&self, other: &KdfParams) -> bool {
+        self.memory_kib < other.memory_kib || self.iterations < other.iterations || self.lanes < other.lanes
+    }
+
+    pub fn derive_key(&self, passphrase: &str, salt: &[u8]) -> Result<SecretBytes, String> {
+        if self.algorithm != KDF_ARGON2ID {
+            return Err(format!("Unsupported KDF: {}", self.algorithm));
+        }
+
+        // Hashed straight into locked memory, so no copy of the key is left on the stack
+        let mut key = SecretBytes::zeroed(32);
+        let params = Params::new(self.memory_kib, self.iterations, self.lanes, None)
+            .map_err(|e| e.to_string())?;
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        argon2.hash_password_into(passphrase.as_bytes(), salt, key.as_mut_slice())
+            .map_err(|e| e.to_string())?;
+
+        Ok(key)
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams::legacy()
+    }
+}
+
+fn legacy_version() -> u32 {
+    1
+}
+
+fn default_cipher() -> String {
+    CIPHER_AES_256_GCM.to_string()
+}
+
+/// One encrypted mnemonic plus everything needed to decrypt it again.
+/// Missing fields default to the version 1 values, so old files parse unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreRecord {
+    #[serde(default = "legacy_version")]
+    pub version: u32,
+    pub address: String,
+    #[serde(default = "default_cipher")]
+    pub cipher: String,
+    #[serde(default)]
+    pub kdf: KdfParams,
+    pub encrypted_phrase: String,
+    pub salt: String,
+    pub iv: String,
+}
+
+impl KeystoreRecord {
+    pub fn needs_upgrade(&self, current: &KdfParams) -> bool {
+        self.version < KEYSTORE_VERSION || self.kdf.weaker_than(current)
+    }
+}
+
+/// Parameters to use for new keystores on this machine, as cached in kdf.json. Until
+/// `ensure_calibrated` has run this is the legacy cost; unlocking never calibrates.
+pub fn calibrated_params() -> KdfParams {
+    json_storage::read_json::<KdfParams>(KDF_CONFIG_FILE)
+        .ok()
+        .filter(|params| !params.weaker_than(&KdfParams::legacy()))
+        .unwrap_or_else(KdfParams::legacy)
+}
+
+/// Calibrates and caches this machine's parameters if kdf.json holds none yet. Runs once at
+/// startup, off the UI thread, since it spends a few seconds deriving keys.
+pub fn ensure_calibrated() -> Result<(), String> {
+    let cached = json_storage::read_json::<KdfParams>(KDF_CONFIG_FILE);
+    if cached.is_ok_and(|params| !params.weaker_than(&KdfParams::legacy())) {
+        return Ok(());
+    }
+
+    let params = calibrate(TARGET_UNLOCK);
+    json_storage::write_json(KDF_CONFIG_FILE, &params)
+        .map_err(|e| format!("Could not cache KDF calibration: {}", e))
+}
+
+/// Picks Argon2id parameters that take about `target` to derive a key on the current machine.
+/// Memory is raised first (up to 256MB), then iterations fill the remaining budget.
+pub fn calibrate(target: Duration) -> KdfParams {
+    let floor = KdfParams::legacy();
+    let mut params = KdfParams { iterations: 1, ..floor.clone() };
+
+    let mut per_pass = match time_derivation(&params) {
+        Some(elapsed) => elapsed,
+        None => return floor,
+    };
+
+    // Double memory while a single pass still leaves room for the legacy iteration count.
+    while params.memory_kib < MAX_MEMORY_KIB && per_pass * 2 * floor.iterations < target {
+        let candidate = KdfParams { memory_kib: params.memory_kib * 2, ..params.clone() };
+        match time_derivation(&candidate) {
+            Some(elapsed) => {
+                params = candidate;
+                per_pass = elapsed;
+            }
+            None => break,
+        }
+    }
+
+    let fitted = (target.as_millis() / per_pass.as_millis().max(1)) as u32;
+    params.iterations = fitted.clamp(floor.iterations, MAX_ITERATIONS);
+    params
+}
+
+fn time_derivation(params: &KdfParams) -> Option<Duration> {
+    let salt = [0u8; 16];
+    let start = Instant::now();
+    params.derive_key("dannesk-calibration", &salt).ok()?;
+    Some(start.elapsed())
+}
+
+/// Encrypts `seed` under `passphrase` with this machine's calibrated parameters.
+pub fn seal(
+    address: String,
+    passphrase: &str,
+    seed: &str,
+) -> Result<KeystoreRecord, String> {
+    seal_with(address, passphrase, seed, calibrated_params())
+}
+
+pub fn seal_with(
+    address: String,
+    passphrase: &str,
+    seed: &str,
+    kdf: KdfParams,
+) -> Result<KeystoreRecord, String> {
+    let (encrypted_phrase, salt, iv) = encrypt_data(passphrase, seed.as_bytes(), &kdf)?;
+
+    Ok(KeystoreRecord {
+        version: KEYSTORE_VERSION,
+        address,
+        cipher: CIPHER_AES_256_GCM.to_string(),
+        kdf,
+        encrypted_phrase,
+        salt,
+        iv,
+    })
+}
+
+pub fn load(filename: &str) -> Result<KeystoreRecord, String> {
+    json_storage::read_json::<KeystoreRecord>(filename).map_err(|e| e.to_string())
+}
+
+pub fn save(filename: &str, record: &KeystoreRecord) -> Result<(), String> {
+    json_storage::write_json(filename, record).map_err(|e| e.to_string())
+}
+
+pub fn decrypt_record(record: &KeystoreRecord, passphrase: &str) -> Result<SecretString, String> {
+    if record.cipher != CIPHER_AES_256_GCM {
+        return Err(format!("Unsupported cipher: {}", record.cipher));
+    }
+    decrypt_data(passphrase, &record.encrypted_phrase, &record.salt, &record.iv, &record.kdf)
+}
+
+/// Decrypts the keystore in `filename`. Files written by an older format or with weaker
+/// parameters than this machine's calibration are re-sealed in place after a successful decrypt.
+pub fn open(filename: &str, passphrase: &str) -> Result<SecretString, String> {
+    let record = load(filename)?;
+    let seed = decrypt_record(&record, passphrase)?;
+
+    let current = calibrated_params();
+    if record.needs_upgrade(&current) {
+        // The old record still opens, so a failed upgrade only gets reported and is retried
+        // on the next unlock
+        if let Err(e) = reseal(filename, record.address.clone(), passphrase, &seed, current) {
+            let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                progress: 1.0,
+                message: format!("Error: Keystore upgrade for {} failed: {}", filename, e),
+            }));
+        }
+    }
+
+    Ok(seed)
+}
+
+fn reseal(filename: &str, address: String, passphrase: &str, seed: &str, params: KdfParams) -> Result<(), String> {
+    let upgraded = seal_with(address, passphrase, seed, params)?;
+    save(filename, &upgraded)?;
+    // The superseded record is weaker; leaving it as .bak would undo the upgrade
+    json_storage::discard_backup(filename).map_err(|e| e.to_string())
+}
+
+/// Re-encrypts the keystore in `filename` under `new_passphrase` with a fresh salt and IV.
+/// The old passphrase must decrypt the current record before anything is written.
+pub fn change_passphrase(
+    filename: &str,
+    old_passphrase: &str,
+    new_passphrase: &str,
+) -> Result<(), String> {
+    let record = load(filename)?;
+    let seed = decrypt_record(&record, old_passphrase)
+        .map_err(|_| "Current passphrase is incorrect".to_string())?;
+
+    let rotated = seal(record.address.clone(), new_passphrase, &seed)?;
+    save(filename, &rotated)?;
+
+    // The previous generation is still readable with the old passphrase.
+    json_storage::discard_backup(filename).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn version_1_record_is_resealed_as_version_2() {
+        json_storage::use_test_root();
+        let (encrypted_phrase, salt, iv) = encrypt_data("pass", SEED.as_bytes(), &KdfParams::legacy()).unwrap();
+        // The original layout, without version, cipher or kdf fields
+        let v1 = serde_json::json!({ "address": "rTest", "encrypted_phrase": encrypted_phrase, "salt": salt, "iv": iv });
+        json_storage::write_json("xrp_encrypt.json", &v1).unwrap();
+        assert_eq!(load("xrp_encrypt.json").unwrap().version, 1);
+
+        assert_eq!(open("xrp_encrypt.json", "pass").unwrap().as_str(), SEED);
+
+        let upgraded = load("xrp_encrypt.json").unwrap();
+        assert_eq!(upgraded.version, KEYSTORE_VERSION);
+        assert_eq!(upgraded.cipher, CIPHER_AES_256_GCM);
+        assert_ne!(upgraded.salt, salt);
+        // The weaker v1 generation must not survive as a fallback
+        let path = json_storage::get_config_path("xrp_encrypt.json").unwrap();
+        assert!(!path.with_extension("json.bak").exists());
+        assert_eq!(open("xrp_encrypt.json", "pass").unwrap().as_str(), SEED);
+    }
+
+    #[test]
+    fn fewer_lanes_is_weaker() {
+        let legacy = KdfParams::legacy();
+        assert!(KdfParams { lanes: 1, ..legacy.clone() }.weaker_than(&legacy));
+        assert!(!legacy.weaker_than(&legacy));
+    }
+}
@@ -0,0 +1,156 @@
+// src/lock.rs
+//
+// Returns the app to the PIN screen after inactivity, on an explicit "lock now", and when the
+// desktop session locks or suspends. Locking also drops every in-memory draft that could hold
+// a recipient, amount or mnemonic, and any signing session with its unlocked seed.
+
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use crate::channel::{
+    CHANNEL, SignTransactionState, SignTradeState, BTCSignTransactionState,
+    XRPWalletProcessState, BTCWalletProcessState, XRPModalState, BTCModalState, SideBarView,
+};
+use crate::preferences;
+use crate::utils::json_storage::{self, Profile};
+
+static LAST_ACTIVITY: LazyLock<Mutex<Instant>> = LazyLock::new(|| Mutex::new(Instant::now()));
+
+// Profile whose wallets are currently loaded into the channels; None until the first unlock.
+static LOADED_PROFILE: Mutex<Option<Profile>> = Mutex::new(None);
+
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Records user input. Called from the root element's pointer and key handlers.
+pub fn touch() {
+    if let Ok(mut last) = LAST_ACTIVITY.lock() {
+        *last = Instant::now();
+    }
+}
+
+fn idle_for() -> Duration {
+    LAST_ACTIVITY.lock().map(|last| last.elapsed()).unwrap_or_default()
+}
+
+pub fn is_locked() -> bool {
+    *CHANNEL.locked_rx.borrow()
+}
+
+/// Activates the profile the PIN opened and loads its wallets if they aren't loaded already.
+/// Wallets are never loaded before the first unlock, so the PIN screen reveals nothing.
+pub fn unlock(profile: Profile) {
+    json_storage::set_profile(profile);
+
+    let needs_load = LOADED_PROFILE.lock()
+        .map(|mut loaded| loaded.replace(profile) != Some(profile))
+        .unwrap_or(true);
+    if needs_load {
+        crate::wallet::reset_wallet_channels();
+        if let Some(tx) = crate::UI_COMMANDS_TX.get() {
+            crate::wallet::load_wallets(tx.clone());
+        }
+    }
+
+    touch();
+    let _ = CHANNEL.locked_tx.send(false);
+}
+
+/// Clears all drafts and pending wallet flows, ends any signing session, then shows the PIN
+/// screen.
+pub fn lock_now() {
+    crate::signer::session::end_all("on lock");
+
+    let _ = CHANNEL.sign_transaction_tx.send(SignTransactionState::default());
+    let _ = CHANNEL.trade_tx.send(SignTradeState::default());
+    let _ = CHANNEL.btc_sign_transaction_tx.send(BTCSignTransactionState::default());
+    let _ = CHANNEL.xrp_wallet_process_tx.send(XRPWalletProcessState::default());
+    let _ = CHANNEL.btc_wallet_process_tx.send(BTCWalletProcessState::default());
+    let _ = CHANNEL.xrp_modal_tx.send(XRPModalState::default());
+    let _ = CHANNEL.btc_modal_tx.send(BTCModalState::default());
+    let _ = CHANNEL.sidebar_view_tx.send(SideBarView::None);
+
+    // The PIN screen always runs against the primary profile
+    json_storage::set_profile(Profile::Primary);
+    let _ = CHANNEL.locked_tx.send(true);
+}
+
+/// Locks once the configured idle timeout passes without input.
+pub async fn run_idle_monitor() {
+    loop {
+        tokio::time::sleep(IDLE_CHECK_INTERVAL).await;
+        if is_locked() {
+            continue;
+        }
+
+        // Re-read each tick so a changed timeout applies without a restart
+        if let Some(minutes) = preferences::load().idle_lock_minutes {
+            if idle_for() >= Duration::from_secs(minutes as u64 * 60) {
+                lock_now();
+            }
+        }
+    }
+}
+
+/// Watches logind and the screensaver for session lock / suspend and locks with them.
+/// Uses `gdbus monitor` so no D-Bus bindings are needed; silently does nothing without it.
+#[cfg(target_os = "linux")]
+pub async fn run_session_lock_monitor() {
+    let session_path = std::env::var("XDG_SESSION_ID").ok().map(|id| {
+        // logind escapes session ids the same way systemd escapes object path labels
+        let escaped: String = id.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_string() } else { format!("_{:02x}", c as u32) })
+            .collect();
+        let escaped = match escaped.chars().next() {
+            Some(c) if c.is_ascii_digit() => format!("_3{}", escaped),
+            _ => escaped,
+        };
+        format!("/org/freedesktop/login1/session/{}", escaped)
+    });
+
+    let system = tokio::spawn(monitor_bus(&["--system", "--dest", "org.freedesktop.login1"], session_path));
+    let screensaver = tokio::spawn(monitor_bus(&["--session", "--dest", "org.freedesktop.ScreenSaver"], None));
+    let gnome = tokio::spawn(monitor_bus(&["--session", "--dest", "org.gnome.ScreenSaver"], None));
+    let _ = tokio::join!(system, screensaver, gnome);
+}
+
+#[cfg(not(target_os = "linux"))]
+pub async fn run_session_lock_monitor() {}
+
+#[cfg(target_os = "linux")]
+async fn monitor_bus(args: &'static [&'static str], session_path: Option<String>) {
+    use std::process::Stdio;
+    use tokio::io::{AsyncBufReadExt, BufReader};
+    use tokio::process::Command;
+
+    let Ok(mut child) = Command::new("gdbus")
+        .arg("monitor")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+    else {
+        return;
+    };
+
+    let Some(stdout) = child.stdout.take() else { return; };
+    let mut lines = BufReader::new(stdout).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        if is_lock_signal(&line, session_path.as_deref())
+            && !is_locked()
+            && preferences::load().lock_on_session_lock
+        {
+            lock_now();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn is_lock_signal(line: &str, session_path: Option<&str>) -> bool {
+    // logind Session.Lock is per session; ignore other users' sessions when ours is known
+    if line.contains("org.freedesktop.login1.Session.Lock ") {
+        return session_path.map(|p| line.starts_with(p)).unwrap_or(true);
+    }
+    line.contains("org.freedesktop.login1.Manager.PrepareForSleep (true,)")
+        || (line.contains("ScreenSaver.ActiveChanged") && line.contains("(true,)"))
+}
@@ -22,6 +22,21 @@ mod utils;
 mod pin;
 mod encrypt;
 mod decrypt;
+mod keystore;
+mod archive;
+mod slip39;
+mod paper;
+mod entropy;
+mod mnemonic;
+mod secmem;
+mod master;
+mod btcaccount;
+mod chain;
+mod signer;
+mod audit;
+mod preferences;
+mod lock;
+mod clipboard;
 mod ws;     
 mod wallet; 
 mod context;
@@ -51,6 +66,11 @@ enum AppState {
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
 
+    // Before any secret is loaded: no core dumps, no ptrace from same-user processes
+    if let Err(e) = secmem::harden_process() {
+        println!("{}", e);
+    }
+
     startup::init_globals();
 
     println!("Starting main - before init_startup");
@@ -87,11 +107,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
     join_handles.push(crypto_handle);
     
-    let tx_clone = commands_tx.clone();
-    let wallet_handle = handle.spawn_blocking(move || {
-        wallet::load_wallets(tx_clone); 
-    });
-    join_handles.push(wallet_handle);
+    // Wallets are loaded by lock::unlock once the PIN tells us which profile to open
 
     
     #[cfg(target_os = "windows")]
@@ -146,6 +162,9 @@ let window_icon = {
     dioxus_native::launch_cfg(App, vec![], vec![Box::new(window_attr) as Box<dyn Any>]);
     println!("Dioxus app exited");
 
+    // Statics are never dropped, so wipe an open signing session explicitly
+    signer::session::end_all("on exit");
+
     // shutdown logic
     handle.block_on(async {
         println!("Sending websocket shutdown signals.");
@@ -168,8 +187,8 @@ fn App() -> Element {
     let global = use_context::<GlobalContext>();
     let is_dark = global.theme_user.read().0;
     
-    // Track if the user has successfully unlocked the PIN
-    let mut unlocked = use_signal(|| false);
+    // Locked until the PIN is entered; idle timeout, session lock and "lock now" set it again
+    let locked = global.locked;
     
     // Read the version signal directly. This ensures that if the background
     // fetch finishes 2 seconds after launch, this component re-renders.
@@ -180,7 +199,7 @@ fn App() -> Element {
         // FORCE update if version exists and doesn't match
         Some(v) if v != VERSION => AppState::UpdatePrompt,
         // Otherwise, check if we are in Dashboard or PinEntry
-        _ => if *unlocked.read() { AppState::Dashboard } else { AppState::PinEntry },
+        _ => if !*locked.read() { AppState::Dashboard } else { AppState::PinEntry },
     };
 
     let theme_css = if is_dark { DARK_CSS } else { LIGHT_CSS };
@@ -192,13 +211,17 @@ fn App() -> Element {
             class: "theme-root",
             class: if is_dark { "dark" }, 
             style: "display: flex; flex-direction: column; height: 100vh; width: 100%; overflow: hidden;",
+            // Any input counts as activity for the idle auto-lock
+            onmousemove: move |_| lock::touch(),
+            onmousedown: move |_| lock::touch(),
+            onkeydown: move |_| lock::touch(),
 
             
 
             match current_view {
                 AppState::UpdatePrompt => rsx! { UpdatePrompt {} },
                 AppState::PinEntry => rsx! {
-                    PinScreen { on_unlock: move |_| unlocked.set(true) }
+                    PinScreen { on_unlock: move |profile| lock::unlock(profile) }
                 },
                 AppState::Dashboard => rsx! {
                     ui::dashboard::render_dashboard {}
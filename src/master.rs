@@ -0,0 +1,241 @@
+// src/master.rs
+//
+// Opt-in unified wallet: one BIP39 mnemonic (plus optional passphrase) derives both the BIP84
+// Bitcoin account and the XRPL account, sealed once in master_encrypt.json. A chain's metadata
+// file (xrp.json / btc.json) opts in with `"keystore": "master_encrypt.json"`; chains without
+// that field keep using their own xrp_encrypt.json / btc_encrypt.json exactly as before.
+
+use std::io;
+use std::str::FromStr;
+use bitcoin::bip32::{Fingerprint, Xpriv, Xpub};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::{Address, CompressedPublicKey, Network, PrivateKey};
+use ripple_address_codec::{encode_seed, Ed25519};
+use serde_json::Value;
+use xrpl::wallet::Wallet;
+use zeroize::Zeroize;
+use crate::btcaccount::AddressType;
+use crate::clipboard::AddressChain;
+use crate::keystore;
+use crate::secmem::SecretString;
+use crate::utils::json_storage;
+
+pub const MASTER_KEYSTORE: &str = "master_encrypt.json";
+const KEYSTORE_FIELD: &str = "keystore";
+
+/// Everything a unified mnemonic resolves to. `fingerprint` is the BIP32 master key fingerprint,
+/// stored as the keystore's address so the record names its seed without revealing an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnifiedAccounts {
+    pub fingerprint: String,
+    pub xrp: String,
+    pub btc: String,
+    pub btc_xpub: Xpub,
+}
+
+impl UnifiedAccounts {
+    pub fn address(&self, chain: AddressChain) -> &str {
+        match chain {
+            AddressChain::Xrp => &self.xrp,
+            AddressChain::Btc => &self.btc,
+        }
+    }
+}
+
+pub fn meta_file(chain: AddressChain) -> &'static str {
+    match chain {
+        AddressChain::Xrp => "xrp.json",
+        AddressChain::Btc => "btc.json",
+    }
+}
+
+fn own_keystore(chain: AddressChain) -> &'static str {
+    match chain {
+        AddressChain::Xrp => "xrp_encrypt.json",
+        AddressChain::Btc => "btc_encrypt.json",
+    }
+}
+
+fn other(chain: AddressChain) -> AddressChain {
+    match chain {
+        AddressChain::Xrp => AddressChain::Btc,
+        AddressChain::Btc => AddressChain::Xrp,
+    }
+}
+
+/// XRPL wallet of a BIP39 seed: its first 16 bytes as an Ed25519 family seed. The caller wipes
+/// `private_key` and `seed` once done with them.
+pub fn xrp_wallet(seed: &[u8; 64]) -> Result<Wallet, String> {
+    let mut entropy: [u8; 16] = seed[0..16].try_into().expect("BIP39 seed is 64 bytes");
+    let mut base58_seed = encode_seed(&entropy, &Ed25519);
+    entropy.zeroize();
+    let wallet = Wallet::new(&base58_seed, 0);
+    base58_seed.zeroize();
+    wallet.map_err(|e| format!("Wallet creation failed: {}", e))
+}
+
+/// XRPL classic address of a BIP39 seed.
+pub fn xrp_address(seed: &[u8; 64]) -> Result<String, String> {
+    let mut wallet = xrp_wallet(seed)?;
+    wallet.private_key.zeroize();
+    wallet.seed.zeroize();
+    Ok(wallet.classic_address.clone())
+}
+
+/// Key of the first BIP84 receive address, m/84'/0'/0'/0/0.
+pub fn btc_key(seed: &[u8; 64]) -> Result<PrivateKey, String> {
+    crate::btcaccount::private_key(seed, AddressType::NativeSegwit, crate::btcaccount::KeyChain::Receive, 0)
+}
+
+/// First BIP84 receive address (P2WPKH).
+pub fn btc_address(seed: &[u8; 64]) -> Result<String, String> {
+    let secp = Secp256k1::new();
+    let mut key = btc_key(seed)?;
+    let public_key = key.public_key(&secp);
+    key.inner.non_secure_erase();
+    Ok(Address::p2wpkh(&CompressedPublicKey(public_key.inner), Network::Bitcoin).to_string())
+}
+
+fn fingerprint(seed: &[u8; 64]) -> Result<String, String> {
+    let secp = Secp256k1::new();
+    Xpriv::new_master(Network::Bitcoin, seed)
+        .map(|xpriv| xpriv.fingerprint(&secp).to_string())
+        .map_err(|e| format!("Derivation failed: {}", e))
+}
+
+/// Both accounts of `mnemonic` under `bip39_pass`. A different BIP39 passphrase is a
+/// different wallet, so it must match the one used when the master seed was created.
+pub fn derive_accounts(mnemonic: &str, bip39_pass: &str) -> Result<UnifiedAccounts, String> {
+    let parsed = crate::mnemonic::parse_any(mnemonic)?;
+    let mut seed = parsed.to_seed(bip39_pass);
+
+    let accounts = fingerprint(&seed).and_then(|fingerprint| Ok(UnifiedAccounts {
+        fingerprint,
+        xrp: xrp_address(&seed)?,
+        btc: btc_address(&seed)?,
+        btc_xpub: crate::btcaccount::xpub_from_seed(&seed, AddressType::NativeSegwit)?,
+    }));
+
+    seed.zeroize();
+    accounts
+}
+
+fn exists(file: &str) -> bool {
+    json_storage::get_config_path(file).map(|p| p.exists()).unwrap_or(false)
+}
+
+/// True once a master keystore has been written for the active profile.
+pub fn master_exists() -> bool {
+    exists(MASTER_KEYSTORE)
+}
+
+fn read_meta(chain: AddressChain) -> Option<Value> {
+    let file = meta_file(chain);
+    if !exists(file) {
+        return None;
+    }
+    json_storage::read_json::<Value>(file).ok()
+}
+
+/// True if `chain` signs from the master keystore.
+pub fn is_unified(chain: AddressChain) -> bool {
+    read_meta(chain)
+        .and_then(|meta| meta.get(KEYSTORE_FIELD).and_then(|v| v.as_str()).map(|f| f == MASTER_KEYSTORE))
+        .unwrap_or(false)
+}
+
+/// The keystore holding `chain`'s key, whichever mode it was created in.
+pub fn keystore_file(chain: AddressChain) -> &'static str {
+    if is_unified(chain) { MASTER_KEYSTORE } else { own_keystore(chain) }
+}
+
+fn key_deleted(chain: AddressChain) -> bool {
+    read_meta(chain)
+        .and_then(|meta| meta.get("private_key_deleted").and_then(|v| v.as_bool()))
+        .unwrap_or(false)
+}
+
+// The other chain still has a live key in the master keystore
+fn still_needed_by_other(chain: AddressChain) -> bool {
+    let chain = other(chain);
+    is_unified(chain) && !key_deleted(chain)
+}
+
+/// Deletes `chain`'s key file when its key is purged or the wallet removed. The master keystore
+/// is kept while the other chain still signs from it. Returns whether a file was removed.
+pub fn release_key(chain: AddressChain) -> io::Result<bool> {
+    let file = keystore_file(chain);
+    if file == MASTER_KEYSTORE && still_needed_by_other(chain) {
+        return Ok(false);
+    }
+    if !exists(file) {
+        return Ok(false);
+    }
+    json_storage::remove_json(file).map(|_| true)
+}
+
+/// Points `chain`'s metadata at the master keystore. The mnemonic was verified before the
+/// master seed was sealed, so the backup counts for every chain derived from it.
+fn link(chain: AddressChain, address: &str) -> io::Result<()> {
+    json_storage::update_json(meta_file(chain), |data: &mut Value| {
+        if !data.is_object() {
+            *data = Value::Object(serde_json::Map::new());
+        }
+        if let Some(obj) = data.as_object_mut() {
+            obj.insert("address".to_string(), Value::String(address.to_string()));
+            obj.insert(KEYSTORE_FIELD.to_string(), Value::String(MASTER_KEYSTORE.to_string()));
+            obj.insert("backup_verified".to_string(), Value::Bool(true));
+            obj.remove("private_key_deleted");
+        }
+    })
+}
+
+// BTC also needs its account xpub for address discovery
+fn link_account(chain: AddressChain, accounts: &UnifiedAccounts) -> Result<(), String> {
+    link(chain, accounts.address(chain)).map_err(|e| e.to_string())?;
+    if chain == AddressChain::Btc {
+        let fingerprint = Fingerprint::from_str(&accounts.fingerprint).map_err(|e| e.to_string())?;
+        crate::btcaccount::track(&accounts.btc_xpub, fingerprint, AddressType::NativeSegwit).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Seals `mnemonic` as the master seed and links both chains to it.
+pub fn create(mnemonic: &str, bip39_pass: &str, passphrase: &str) -> Result<UnifiedAccounts, String> {
+    if master_exists() {
+        return Err("A master seed already exists for this profile".to_string());
+    }
+
+    let accounts = derive_accounts(mnemonic, bip39_pass)?;
+    let record = keystore::seal(accounts.fingerprint.clone(), passphrase, mnemonic)
+        .map_err(|e| format!("Encryption failed: {}", e))?;
+    keystore::save(MASTER_KEYSTORE, &record)?;
+
+    for chain in [AddressChain::Xrp, AddressChain::Btc] {
+        link_account(chain, &accounts)?;
+    }
+    Ok(accounts)
+}
+
+/// Decrypts the master seed once and links `chain` to its account, e.g. after that chain's
+/// wallet was removed while the other one kept using the master seed.
+pub fn attach(chain: AddressChain, bip39_pass: &str, passphrase: &str) -> Result<UnifiedAccounts, String> {
+    let mnemonic = open(passphrase)?;
+    let accounts = derive_accounts(&mnemonic, bip39_pass)?;
+    link_account(chain, &accounts)?;
+    Ok(accounts)
+}
+
+pub fn open(passphrase: &str) -> Result<SecretString, String> {
+    keystore::open(MASTER_KEYSTORE, passphrase)
+        .map_err(|_| "Master seed passphrase is incorrect".to_string())
+}
+
+/// The keystore to decrypt when `chain` signs. A purged unified chain must not keep signing
+/// from the master seed the other chain still relies on.
+pub fn signing_keystore(chain: AddressChain) -> Result<&'static str, String> {
+    if key_deleted(chain) {
+        return Err("Private key was deleted for this wallet".to_string());
+    }
+    Ok(keystore_file(chain))
+}
@@ -0,0 +1,168 @@
+// src/mnemonic.rs
+//
+// Word-level helpers for typing in an existing BIP39 phrase: language detection across every
+// wordlist the bip39 crate ships, per-word validity, prefix autocomplete and checksum feedback
+// before anything is submitted.
+
+use std::borrow::Cow;
+use bip39::{Language, Mnemonic};
+use zeroize::Zeroizing;
+
+/// Letters typed before suggestions are offered. Every English word is unique by its first four.
+pub const AUTOCOMPLETE_MIN: usize = 4;
+const MAX_SUGGESTIONS: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordState {
+    Empty,
+    /// Exact wordlist match.
+    Valid,
+    /// Not a word yet, but the start of at least one.
+    Partial,
+    Invalid,
+}
+
+pub fn language_name(language: Language) -> &'static str {
+    match language {
+        Language::English => "ENGLISH",
+        Language::SimplifiedChinese => "CHINESE_SIMPLIFIED",
+        Language::TraditionalChinese => "CHINESE_TRADITIONAL",
+        Language::Czech => "CZECH",
+        Language::French => "FRENCH",
+        Language::Italian => "ITALIAN",
+        Language::Japanese => "JAPANESE",
+        Language::Korean => "KOREAN",
+        Language::Portuguese => "PORTUGUESE",
+        Language::Spanish => "SPANISH",
+    }
+}
+
+/// Lowercased NFKD form, which is how the wordlists are stored. Accented Spanish/French and
+/// Japanese kana only compare equal after this.
+pub fn normalize_word(word: &str) -> String {
+    let mut cow = Cow::Owned(word.trim().to_lowercase());
+    Mnemonic::normalize_utf8_cow(&mut cow);
+    cow.into_owned()
+}
+
+// Chinese words are single characters, so a prefix threshold makes no sense there
+fn autocomplete_min(language: Language) -> usize {
+    match language {
+        Language::SimplifiedChinese | Language::TraditionalChinese => 1,
+        _ => AUTOCOMPLETE_MIN,
+    }
+}
+
+/// Language whose wordlist contains the most of `words` (whole words or unambiguous prefixes),
+/// preferring English on a tie because it is listed first. `None` until one word is recognised.
+pub fn detect_language(words: &[String]) -> Option<Language> {
+    let words: Vec<&String> = words.iter().filter(|w| !w.trim().is_empty()).collect();
+    Language::ALL.iter()
+        .map(|&lang| (lang, words.iter().filter(|w| complete(lang, w).is_some()).count()))
+        .filter(|(_, hits)| *hits > 0)
+        .fold(None, |best: Option<(Language, usize)>, (lang, hits)| match best {
+            Some((_, top)) if top >= hits => best,
+            _ => Some((lang, hits)),
+        })
+        .map(|(lang, _)| lang)
+}
+
+pub fn word_state(language: Language, word: &str) -> WordState {
+    let word = normalize_word(word);
+    if word.is_empty() {
+        WordState::Empty
+    } else if language.find_word(&word).is_some() {
+        WordState::Valid
+    } else if !language.words_by_prefix(&word).is_empty() {
+        WordState::Partial
+    } else {
+        WordState::Invalid
+    }
+}
+
+/// Candidates for a partially typed word once enough of it is entered.
+pub fn suggestions(language: Language, word: &str) -> Vec<&'static str> {
+    let word = normalize_word(word);
+    if word.chars().count() < autocomplete_min(language) || language.find_word(&word).is_some() {
+        return Vec::new();
+    }
+    language.words_by_prefix(&word).iter().take(MAX_SUGGESTIONS).copied().collect()
+}
+
+/// The full word when `word` is an exact match or an unambiguous prefix of enough letters.
+pub fn complete(language: Language, word: &str) -> Option<&'static str> {
+    let word = normalize_word(word);
+    if let Some(index) = language.find_word(&word) {
+        return Some(language.word_list()[index as usize]);
+    }
+    match language.words_by_prefix(&word) {
+        [only] if word.chars().count() >= autocomplete_min(language) => Some(only),
+        _ => None,
+    }
+}
+
+/// Validates the complete phrase, including the checksum, in the detected language.
+pub fn check_phrase(language: Language, words: &[&str]) -> Result<(), String> {
+    Mnemonic::parse_in_normalized(language, &words.join(" "))
+        .map(|_| ())
+        .map_err(|e| format!("{}", e))
+}
+
+/// Parses a stored or submitted phrase in whichever supported language it was written. Simplified
+/// and Traditional Chinese share many characters, so a phrase may fit both lists; any language
+/// whose checksum holds will do, since the seed only depends on the phrase itself.
+pub fn parse_any(phrase: &str) -> Result<Mnemonic, String> {
+    let mut normalized = Cow::Borrowed(phrase);
+    Mnemonic::normalize_utf8_cow(&mut normalized);
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+
+    Language::ALL.iter()
+        .filter(|lang| words.iter().all(|w| lang.find_word(w).is_some()))
+        .find_map(|&lang| Mnemonic::parse_in_normalized(lang, &normalized).ok())
+        .map_or_else(|| Mnemonic::parse(phrase).map_err(|e| format!("Invalid mnemonic: {}", e)), Ok)
+}
+
+/// Turns the import grid into the phrase that gets sealed: words must fill the grid from the top,
+/// prefixes are completed, and the checksum must hold in the detected language.
+pub fn resolve_phrase(cells: &[String]) -> Result<Zeroizing<String>, String> {
+    let count = cells.iter().filter(|w| !w.trim().is_empty()).count();
+    if !crate::entropy::WORD_COUNTS.contains(&count) || cells[..count].iter().any(|w| w.trim().is_empty()) {
+        return Err("MNEMONIC_INTEGRITY_CHECK_FAILED: 12_18_OR_24_WORDS_REQUIRED".to_string());
+    }
+
+    let language = detect_language(cells)
+        .ok_or_else(|| "MNEMONIC_INTEGRITY_CHECK_FAILED: UNKNOWN_WORDLIST".to_string())?;
+    let words = cells[..count].iter().enumerate()
+        .map(|(i, w)| complete(language, w).ok_or_else(|| {
+            format!("WORD_{:02}_NOT_IN_{}_WORDLIST", i + 1, language_name(language))
+        }))
+        .collect::<Result<Vec<&str>, String>>()?;
+
+    check_phrase(language, &words)
+        .map_err(|_| "MNEMONIC_INTEGRITY_CHECK_FAILED: CHECKSUM_MISMATCH".to_string())?;
+    Ok(Zeroizing::new(words.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phrase_in_both_chinese_lists_parses() {
+        // Zero entropy only uses characters the Simplified and Traditional lists share
+        let simplified = Mnemonic::from_entropy_in(Language::SimplifiedChinese, &[0; 16]).unwrap().to_string();
+        let traditional = Mnemonic::from_entropy_in(Language::TraditionalChinese, &[0; 16]).unwrap().to_string();
+        assert_eq!(simplified, traditional);
+        assert!(Mnemonic::parse(&simplified).is_err());
+
+        let expected = Mnemonic::from_entropy_in(Language::SimplifiedChinese, &[0; 16]).unwrap().to_seed("");
+        assert_eq!(parse_any(&simplified).unwrap().to_seed(""), expected);
+    }
+
+    #[test]
+    fn english_phrase_parses() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(parse_any(phrase).unwrap().language(), Language::English);
+        assert!(parse_any("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon").is_err());
+    }
+}
@@ -0,0 +1,185 @@
+// src/paper.rs
+//
+// Printable paper backup of a freshly generated mnemonic. The sheet is a single self-contained
+// A4 SVG (no fonts, images or scripts pulled from anywhere) built entirely on this machine, so it
+// can be printed from any browser or viewer without the words ever being retyped.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use bip39::{Language, Mnemonic};
+use qrcode::{QrCode, types::Color};
+use zeroize::{Zeroize, Zeroizing};
+use crate::btcaccount::{self, AddressType, KeyChain};
+use crate::clipboard::AddressChain;
+use crate::utils::json_storage;
+
+pub const PAPER_EXTENSION: &str = "svg";
+
+// A4 portrait, in millimetres
+const PAGE_W: f32 = 210.0;
+const PAGE_H: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const GRID_COLUMNS: usize = 3;
+const QR_SIZE: f32 = 60.0;
+
+fn network_label(chain: AddressChain) -> &'static str {
+    match chain {
+        AddressChain::Xrp => "XRP_MAINNET",
+        AddressChain::Btc => "BITCOIN_MAINNET",
+    }
+}
+
+fn derivation_info(chain: AddressChain, btc_type: AddressType) -> String {
+    match chain {
+        AddressChain::Xrp => "BIP39 SEED > FIRST 16 BYTES > ED25519 FAMILY SEED".to_string(),
+        AddressChain::Btc => format!("{} {}/0/0", btc_type.label(), btc_type.account_path()),
+    }
+}
+
+/// Suggested location: ~/dannesk-paper-<chain>-YYYYMMDD.svg
+pub fn default_path(chain: AddressChain) -> String {
+    let chain = match chain {
+        AddressChain::Xrp => "xrp",
+        AddressChain::Btc => "btc",
+    };
+    let name = format!(
+        "dannesk-paper-{}-{}.{}",
+        chain,
+        chrono::Local::now().format("%Y%m%d"),
+        PAPER_EXTENSION,
+    );
+    dirs::home_dir()
+        .map(|home| home.join(&name))
+        .unwrap_or_else(|| PathBuf::from(&name))
+        .to_string_lossy()
+        .to_string()
+}
+
+/// First receive address of `mnemonic`, derived the same way the create flow does it.
+/// The paper sheet has no BIP39 passphrase to work with, so an empty one is assumed.
+fn first_address(chain: AddressChain, btc_type: AddressType, mnemonic: &Mnemonic) -> Result<String, String> {
+    let mut seed = mnemonic.to_seed("");
+
+    let address = match chain {
+        AddressChain::Xrp => crate::master::xrp_address(&seed),
+        AddressChain::Btc => btcaccount::xpub_from_seed(&seed, btc_type)
+            .and_then(|xpub| btcaccount::address_at(&xpub, btc_type, KeyChain::Receive, 0))
+            .map(|address| address.to_string()),
+    };
+
+    seed.zeroize();
+    address
+}
+
+// One <path> with a unit square per dark module, scaled into a `size` mm box at (x, y).
+// A four-module quiet zone is kept inside the box so scanners lock on reliably.
+fn qr_svg(data: &[u8], x: f32, y: f32, size: f32) -> Result<String, String> {
+    let code = QrCode::new(data).map_err(|e| format!("QR encoding failed: {}", e))?;
+    let width = code.width();
+    let scale = size / (width + 8) as f32;
+
+    let mut path = String::with_capacity(width * width * 12);
+    for row in 0..width {
+        for col in 0..width {
+            if code[(col, row)] == Color::Dark {
+                path.push_str(&format!("M{} {}h1v1h-1z", col + 4, row + 4));
+            }
+        }
+    }
+
+    Ok(format!(
+        r##"<rect x="{x}" y="{y}" width="{size}" height="{size}" fill="#ffffff" stroke="#000000" stroke-width="0.2"/><g transform="translate({x} {y}) scale({scale})"><path d="{path}" fill="#000000"/></g>"##
+    ))
+}
+
+fn text(x: f32, y: f32, size: f32, weight: &str, content: &str) -> String {
+    format!(
+        r##"<text xml:space="preserve" x="{x}" y="{y}" font-family="monospace" font-size="{size}" font-weight="{weight}" fill="#000000">{content}</text>"##
+    )
+}
+
+/// Renders the backup sheet. `btc_type` is the Bitcoin account whose address and path are
+/// printed; XRP sheets ignore it. `include_entropy` adds a QR of the raw BIP39 entropy (hex),
+/// which restores the wallet on its own and must be treated exactly like the words.
+pub fn render_svg(chain: AddressChain, btc_type: AddressType, mnemonic: &str, include_entropy: bool) -> Result<Zeroizing<String>, String> {
+    let parsed = Mnemonic::parse_in(Language::English, mnemonic)
+        .map_err(|e| format!("Invalid mnemonic: {}", e))?;
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let address = first_address(chain, btc_type, &parsed)?;
+    let created = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+    let mut svg = Zeroizing::new(String::with_capacity(64 * 1024));
+    svg.push_str(&format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{PAGE_W}mm" height="{PAGE_H}mm" viewBox="0 0 {PAGE_W} {PAGE_H}"><rect width="{PAGE_W}" height="{PAGE_H}" fill="#ffffff"/>"##
+    ));
+
+    // Header
+    svg.push_str(&text(MARGIN, MARGIN + 5.0, 6.0, "bold", "DANNESK // PAPER BACKUP"));
+    let meta = [
+        format!("NETWORK     {}", network_label(chain)),
+        format!("CREATED     {}", created),
+        format!("MNEMONIC    BIP39 ENGLISH // {} WORDS", words.len()),
+        format!("DERIVATION  {}", derivation_info(chain, btc_type)),
+    ];
+    for (i, line) in meta.iter().enumerate() {
+        svg.push_str(&text(MARGIN, MARGIN + 14.0 + i as f32 * 5.5, 3.5, "normal", line));
+    }
+
+    // Word grid, numbered down each column so it reads like the on-screen layout
+    let grid_top = MARGIN + 40.0;
+    let rows = words.len().div_ceil(GRID_COLUMNS);
+    let cell_w = (PAGE_W - 2.0 * MARGIN) / GRID_COLUMNS as f32;
+    let cell_h = 10.0;
+    for (i, word) in words.iter().enumerate() {
+        let (col, row) = (i / rows, i % rows);
+        let x = MARGIN + col as f32 * cell_w;
+        let y = grid_top + row as f32 * cell_h;
+        svg.push_str(&format!(
+            r##"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="#000000" stroke-width="0.3"/>"##,
+            x + 1.0, y, cell_w - 2.0, cell_h - 1.5
+        ));
+        svg.push_str(&text(x + 3.0, y + 5.8, 3.0, "normal", &format!("{:02}", i + 1)));
+        svg.push_str(&text(x + 11.0, y + 6.0, 4.5, "bold", word));
+    }
+
+    // QR codes
+    let qr_top = grid_top + rows as f32 * cell_h + 10.0;
+    svg.push_str(&qr_svg(address.as_bytes(), MARGIN, qr_top, QR_SIZE)?);
+    svg.push_str(&text(MARGIN, qr_top + QR_SIZE + 5.0, 3.0, "bold", "RECEIVE ADDRESS (PUBLIC)"));
+    svg.push_str(&text(MARGIN, qr_top + QR_SIZE + 10.0, 2.6, "normal", &address));
+    svg.push_str(&text(MARGIN, qr_top + QR_SIZE + 14.5, 2.4, "normal", "Assumes no BIP39 passphrase. A passphrase derives a different address."));
+
+    if include_entropy {
+        let mut entropy = parsed.to_entropy();
+        let hex = Zeroizing::new(entropy.iter().map(|b| format!("{:02x}", b)).collect::<String>());
+        entropy.zeroize();
+        let x = PAGE_W - MARGIN - QR_SIZE;
+        svg.push_str(&qr_svg(hex.as_bytes(), x, qr_top, QR_SIZE)?);
+        svg.push_str(&text(x, qr_top + QR_SIZE + 5.0, 3.0, "bold", "ENTROPY HEX (SECRET)"));
+    }
+
+    // Footer
+    let footer = [
+        "Anyone holding this sheet controls the funds. Store it offline; never photograph or scan it.",
+        "Restore in any BIP39 wallet with the words above, plus your BIP39 passphrase if you set one.",
+    ];
+    for (i, line) in footer.iter().enumerate() {
+        svg.push_str(&text(MARGIN, PAGE_H - MARGIN - 5.0 + i as f32 * 4.5, 2.8, "normal", line));
+    }
+
+    svg.push_str("</svg>");
+    Ok(svg)
+}
+
+/// Renders the sheet and writes it to `path`, readable by the current user only. An existing
+/// file is never replaced.
+pub fn write_svg(path: &Path, chain: AddressChain, btc_type: AddressType, mnemonic: &str, include_entropy: bool) -> Result<(), String> {
+    let svg = render_svg(chain, btc_type, mnemonic, include_entropy)?;
+    let mut file = json_storage::create_new_file(path, 0o600).map_err(|e| match e.kind() {
+        io::ErrorKind::AlreadyExists => format!("{} already exists. Choose another file name", path.display()),
+        _ => format!("Could not write backup sheet: {}", e),
+    })?;
+    file.write_all(svg.as_bytes())
+        .and_then(|_| file.sync_all())
+        .map_err(|e| format!("Could not write backup sheet: {}", e))
+}
@@ -4,9 +4,19 @@ use rand::RngExt;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
-use crate::utils::json_storage;
+use crate::utils::json_storage::{self, Profile};
 use argon2::{Argon2, Algorithm, Version, Params};
 use zeroize::{Zeroize};
+use crate::channel::CHANNEL;
+use crate::audit::{self, AuditEvent};
+
+// Failed attempts allowed before delays start, and the delay ladder after that.
+const FREE_ATTEMPTS: u32 = 3;
+const BASE_DELAY_SECS: u64 = 30;
+const MAX_DELAY_SECS: u64 = 3600;
+
+/// Choices offered for the opt-in "wipe keys after N consecutive failures" policy.
+pub const WIPE_POLICY_OPTIONS: [Option<u32>; 3] = [None, Some(10), Some(20)];
 
 #[derive(Debug)]
 pub enum PinError {
@@ -14,25 +24,148 @@ pub enum PinError {
     IoError(String),
     PinNotSet,
     IncorrectPin,
+    LockedOut(u64),
+    KeysWiped,
+    DuressMatchesPin,
+    FormatLocked,
 }
 
 impl fmt::Display for PinError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            PinError::InvalidPin => write!(f, "Invalid PIN: must be a six-digit number"),
+            PinError::InvalidPin => write!(f, "Invalid PIN: does not match the configured format"),
+            PinError::DuressMatchesPin => write!(f, "Duress PIN must differ from the main PIN"),
+            PinError::FormatLocked => write!(f, "Unlock format cannot be changed while a duress PIN is set"),
             PinError::IoError(e) => write!(f, "IO error: {}", e),
             PinError::PinNotSet => write!(f, "PIN not set"),
             PinError::IncorrectPin => write!(f, "Incorrect PIN"),
+            PinError::LockedOut(secs) => write!(f, "Too many failed attempts, retry in {}s", secs),
+            PinError::KeysWiped => write!(f, "Too many failed attempts, encrypted keys were wiped"),
         }
     }
 }
 
 impl Error for PinError {}
 
+/// What kind of secret unlocks the app. Files written before this existed hold a six-digit PIN.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretType {
+    #[default]
+    Numeric,
+    Passphrase,
+}
+
+fn default_secret_length() -> usize {
+    6
+}
+
+/// Shape of the unlock secret: an exact-length numeric PIN, or a passphrase with a minimum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnlockFormat {
+    pub secret_type: SecretType,
+    pub length: usize,
+}
+
+impl UnlockFormat {
+    pub const LEGACY: UnlockFormat = UnlockFormat { secret_type: SecretType::Numeric, length: 6 };
+    pub const MIN_PASSPHRASE: usize = 8;
+
+    /// Formats offered when setting or changing the unlock secret.
+    pub const OPTIONS: [UnlockFormat; 5] = [
+        UnlockFormat::LEGACY,
+        UnlockFormat { secret_type: SecretType::Numeric, length: 8 },
+        UnlockFormat { secret_type: SecretType::Numeric, length: 10 },
+        UnlockFormat { secret_type: SecretType::Numeric, length: 12 },
+        UnlockFormat { secret_type: SecretType::Passphrase, length: UnlockFormat::MIN_PASSPHRASE },
+    ];
+
+    pub fn is_numeric(&self) -> bool {
+        self.secret_type == SecretType::Numeric
+    }
+
+    pub fn label(&self) -> String {
+        match self.secret_type {
+            SecretType::Numeric => format!("{}_DIGIT_PIN", self.length),
+            SecretType::Passphrase => "PASSPHRASE".to_string(),
+        }
+    }
+
+    /// Whether `input` can still grow into a valid secret (used to filter keystrokes).
+    pub fn accepts_partial(&self, input: &str) -> bool {
+        match self.secret_type {
+            SecretType::Numeric => input.len() <= self.length && input.chars().all(|c| c.is_ascii_digit()),
+            SecretType::Passphrase => true,
+        }
+    }
+
+    /// Numeric PINs submit themselves once the last digit is entered.
+    pub fn is_complete(&self, input: &str) -> bool {
+        self.is_numeric() && input.len() == self.length
+    }
+
+    pub fn validate(&self, secret: &str) -> Result<(), PinError> {
+        let valid = match self.secret_type {
+            SecretType::Numeric => {
+                (6..=12).contains(&self.length)
+                    && secret.len() == self.length
+                    && secret.chars().all(|c| c.is_ascii_digit())
+            }
+            SecretType::Passphrase => secret.chars().count() >= Self::MIN_PASSPHRASE,
+        };
+        if valid { Ok(()) } else { Err(PinError::InvalidPin) }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PinData {
     pub pin_hash: String, // Base64-encoded Argon2 hash
     pub pin_salt: String, // Base64-encoded salt
+    #[serde(default)]
+    pub failed_attempts: u32, // Consecutive failures, reset on success
+    #[serde(default)]
+    pub last_failure: Option<i64>, // Unix timestamp of the latest failure
+    #[serde(default)]
+    pub wipe_after: Option<u32>, // Opt-in: wipe encrypted keys after this many failures
+    #[serde(default)]
+    pub duress_hash: Option<String>, // Optional second PIN that opens the decoy profile
+    #[serde(default)]
+    pub duress_salt: Option<String>,
+    #[serde(default)]
+    pub secret_type: SecretType, // Numeric PIN or passphrase
+    #[serde(default = "default_secret_length")]
+    pub secret_length: usize, // PIN digits, or minimum passphrase length
+}
+
+impl PinData {
+    pub fn format(&self) -> UnlockFormat {
+        UnlockFormat { secret_type: self.secret_type, length: self.secret_length }
+    }
+
+    /// Seconds still to wait before the next attempt is accepted, if any.
+    pub fn lockout_remaining(&self, now: i64) -> Option<u64> {
+        let delay = lockout_delay(self.failed_attempts);
+        let last = self.last_failure?;
+        if delay == 0 {
+            return None;
+        }
+        let unlock_at = last.saturating_add(delay as i64);
+        // A clock moved backwards must not turn into an unbounded lockout
+        if now < last {
+            return Some(delay);
+        }
+        (now < unlock_at).then(|| (unlock_at - now) as u64)
+    }
+}
+
+/// Wait imposed after `failed_attempts` consecutive failures: none for the first few,
+/// then 30s doubling per attempt, capped at one hour.
+pub fn lockout_delay(failed_attempts: u32) -> u64 {
+    if failed_attempts < FREE_ATTEMPTS {
+        return 0;
+    }
+    let steps = (failed_attempts - FREE_ATTEMPTS).min(16);
+    (BASE_DELAY_SECS << steps).min(MAX_DELAY_SECS)
 }
 
 pub fn load_pin_data() -> Result<PinData, PinError> {
@@ -46,60 +179,230 @@ pub fn save_pin_data(pin_data: &PinData) -> Result<(), PinError> {
     Ok(())
 }
 
-pub fn set_pin(pin: &str) -> Result<(), PinError> {
-    if !pin.chars().all(|c| c.is_digit(10)) || pin.len() != 6 {
-        return Err(PinError::InvalidPin);
-    }
+/// The configured unlock format; six-digit PIN when nothing is stored yet.
+pub fn load_format() -> UnlockFormat {
+    load_pin_data().map(|d| d.format()).unwrap_or(UnlockFormat::LEGACY)
+}
 
-    let salt: [u8; 16] = rand::rng().random();
+fn hash_pin(pin: &str, salt: &[u8]) -> Result<[u8; 32], PinError> {
     let mut hash = [0u8; 32];
 
     // Use consistent parameters: 64MB RAM, 3 iterations, 4 threads
     let params = Params::new(65536, 3, 4, None).map_err(|_| PinError::InvalidPin)?;
     let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
 
-    argon2.hash_password_into(pin.as_bytes(), &salt, &mut hash)
+    argon2.hash_password_into(pin.as_bytes(), salt, &mut hash)
         .map_err(|_| PinError::InvalidPin)?;
+    Ok(hash)
+}
 
-    let pin_data = PinData {
-        pin_hash: BASE64.encode(&hash),
-        pin_salt: BASE64.encode(&salt),
-    };
-
+/// Hashes `pin` under a fresh salt, returning (hash, salt) in Base64.
+fn new_pin_hash(pin: &str) -> Result<(String, String), PinError> {
+    let salt: [u8; 16] = rand::rng().random();
+    let mut hash = hash_pin(pin, &salt)?;
+    let encoded = (BASE64.encode(&hash), BASE64.encode(&salt));
     hash.zeroize(); // Wipe the temporary hash buffer
-    save_pin_data(&pin_data)?;
-    Ok(())
+    Ok(encoded)
 }
 
-pub fn verify_pin(pin: &str) -> Result<(), PinError> {
-    let pin_data = load_pin_data().map_err(|_| PinError::PinNotSet)?;
-
-    let stored_hash = BASE64.decode(&pin_data.pin_hash)
+fn matches(pin: &str, stored_hash: &str, stored_salt: &str) -> Result<bool, PinError> {
+    let stored_hash = BASE64.decode(stored_hash)
         .map_err(|_| PinError::IncorrectPin)?;
-    let salt = BASE64.decode(&pin_data.pin_salt)
+    let salt = BASE64.decode(stored_salt)
         .map_err(|_| PinError::IncorrectPin)?;
 
-    let mut computed_hash = [0u8; 32];
-    let params = Params::new(65536, 3, 4, None).map_err(|_| PinError::IncorrectPin)?;
-    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
-
-    argon2.hash_password_into(pin.as_bytes(), &salt, &mut computed_hash)
-        .map_err(|_| PinError::IncorrectPin)?;
+    let mut computed_hash = hash_pin(pin, &salt).map_err(|_| PinError::IncorrectPin)?;
 
     // Constant-time comparison is handled by standard slice equality in this context,
     // though for extreme cases you'd use a constant-time crate.
     let is_valid = computed_hash == stored_hash.as_slice();
     computed_hash.zeroize();
+    Ok(is_valid)
+}
+
+/// Stores a new unlock secret in `format`. Switching formats drops the duress PIN, which
+/// could no longer be typed on the new entry screen.
+pub fn set_pin(pin: &str, format: UnlockFormat) -> Result<(), PinError> {
+    format.validate(pin)?;
+
+    // A new PIN starts with a clean counter but keeps the wipe policy and duress PIN
+    let existing = load_pin_data().ok();
+    let keep_duress = existing.as_ref().map(|d| d.format() == format).unwrap_or(false);
+    if let Some((Some(hash), Some(salt))) = existing.as_ref().map(|d| (&d.duress_hash, &d.duress_salt)) {
+        if keep_duress && matches(pin, hash, salt)? {
+            return Err(PinError::DuressMatchesPin);
+        }
+    }
+
+    let (pin_hash, pin_salt) = new_pin_hash(pin)?;
+
+    let pin_data = PinData {
+        pin_hash,
+        pin_salt,
+        failed_attempts: 0,
+        last_failure: None,
+        wipe_after: existing.as_ref().and_then(|d| d.wipe_after),
+        duress_hash: existing.as_ref().filter(|_| keep_duress).and_then(|d| d.duress_hash.clone()),
+        duress_salt: existing.as_ref().filter(|_| keep_duress).and_then(|d| d.duress_salt.clone()),
+        secret_type: format.secret_type,
+        secret_length: format.length,
+    };
+
+    save_pin_data(&pin_data)?;
+    Ok(())
+}
+
+/// Checks `pin` against the main PIN and, if configured, the duress PIN.
+/// Returns the profile the PIN unlocks; both outcomes look identical to an observer.
+pub fn verify_pin(pin: &str) -> Result<Profile, PinError> {
+    let result = check_pin(pin);
+    audit_attempt(&result, "unlock");
+    result
+}
+
+// Unlocks by either PIN are logged the same way, so the log does not give the duress PIN away
+fn audit_attempt(result: &Result<Profile, PinError>, context: &str) {
+    match result {
+        Ok(_) => audit::record(AuditEvent::PinUnlocked, context),
+        Err(PinError::IncorrectPin) => audit::record(AuditEvent::PinFailed, context),
+        Err(PinError::LockedOut(secs)) => audit::record(AuditEvent::PinLockedOut, format!("{}, retry in {}s", context, secs)),
+        Err(PinError::KeysWiped) => {
+            audit::record(AuditEvent::PinFailed, context);
+            audit::record(AuditEvent::KeysWiped, "wipe-after-failures policy");
+        }
+        Err(_) => {}
+    }
+}
+
+fn check_pin(pin: &str) -> Result<Profile, PinError> {
+    let mut pin_data = load_pin_data().map_err(|_| PinError::PinNotSet)?;
+
+    let now = chrono::Utc::now().timestamp();
+    if let Some(secs) = pin_data.lockout_remaining(now) {
+        return Err(PinError::LockedOut(secs));
+    }
+
+    // Count the attempt before hashing, so killing the app mid-check cannot skip the counter
+    pin_data.failed_attempts = pin_data.failed_attempts.saturating_add(1);
+    pin_data.last_failure = Some(now);
+    save_pin_data(&pin_data)?;
+
+    let is_primary = matches(pin, &pin_data.pin_hash, &pin_data.pin_salt)?;
+
+    // Always hash against the duress PIN too, so timing does not reveal which one matched
+    let is_duress = match (&pin_data.duress_hash, &pin_data.duress_salt) {
+        (Some(hash), Some(salt)) => matches(pin, hash, salt)?,
+        _ => false,
+    };
+
+    if is_primary || is_duress {
+        pin_data.failed_attempts = 0;
+        pin_data.last_failure = None;
+        save_pin_data(&pin_data)?;
+        return Ok(if is_primary { Profile::Primary } else { Profile::Decoy });
+    }
 
-    if is_valid {
-        Ok(())
-    } else {
-        Err(PinError::IncorrectPin)
+    if let Some(limit) = pin_data.wipe_after {
+        if pin_data.failed_attempts >= limit {
+            wipe_key_files();
+            return Err(PinError::KeysWiped);
+        }
     }
+
+    Err(PinError::IncorrectPin)
+}
+
+/// Sets the duress PIN in the main PIN's format. It must differ from the main PIN,
+/// or it would never be reached.
+pub fn set_duress_pin(pin: &str) -> Result<(), PinError> {
+    let mut pin_data = load_pin_data().map_err(|_| PinError::PinNotSet)?;
+    pin_data.format().validate(pin)?;
+    if matches(pin, &pin_data.pin_hash, &pin_data.pin_salt)? {
+        return Err(PinError::DuressMatchesPin);
+    }
+
+    let (hash, salt) = new_pin_hash(pin)?;
+    pin_data.duress_hash = Some(hash);
+    pin_data.duress_salt = Some(salt);
+    save_pin_data(&pin_data)
+}
+
+pub fn clear_duress_pin() -> Result<(), PinError> {
+    let mut pin_data = load_pin_data().map_err(|_| PinError::PinNotSet)?;
+    pin_data.duress_hash = None;
+    pin_data.duress_salt = None;
+    save_pin_data(&pin_data)
+}
+
+pub fn has_duress_pin() -> bool {
+    load_pin_data().map(|d| d.duress_hash.is_some()).unwrap_or(false)
+}
+
+/// Current lockout state for the PIN screen: (consecutive failures, seconds left to wait).
+pub fn lockout_status() -> Option<(u32, Option<u64>)> {
+    let pin_data = load_pin_data().ok()?;
+    let now = chrono::Utc::now().timestamp();
+    Some((pin_data.failed_attempts, pin_data.lockout_remaining(now)))
 }
 
-pub fn change_pin(old_pin: &str, new_pin: &str) -> Result<(), PinError> {
-    verify_pin(old_pin)?;
-    set_pin(new_pin)?;
+pub fn set_wipe_policy(wipe_after: Option<u32>) -> Result<(), PinError> {
+    let mut pin_data = load_pin_data().map_err(|_| PinError::PinNotSet)?;
+    pin_data.wipe_after = wipe_after;
+    save_pin_data(&pin_data)
+}
+
+/// Deletes every encrypted key file, the master seed included, and marks the wallets watch-only,
+/// the same end state as purging keys from the balance views. Always the primary profile's:
+/// failures counted while the decoy is open, e.g. when changing the duress PIN, still guard
+/// the real keys.
+fn wipe_key_files() {
+    json_storage::in_profile(Profile::Primary, || {
+        let exists = |f: &str| json_storage::get_config_path(f).map(|p| p.exists()).unwrap_or(false);
+        for key_file in ["xrp_encrypt.json", "btc_encrypt.json", crate::master::MASTER_KEYSTORE] {
+            if exists(key_file) {
+                let _ = json_storage::remove_json(key_file);
+            }
+        }
+        for meta_file in ["xrp.json", "btc.json"] {
+            if exists(meta_file) {
+                let _ = json_storage::update_json(meta_file, |data: &mut serde_json::Value| {
+                    if let Some(obj) = data.as_object_mut() {
+                        obj.insert("private_key_deleted".to_string(), serde_json::Value::Bool(true));
+                    }
+                });
+            }
+        }
+    });
+
+    // The wallets on screen are the decoy's while it is open, and keep their keys
+    if json_storage::active_profile() != Profile::Primary {
+        return;
+    }
+    CHANNEL.wallet_balance_tx.send_modify(|(_, address, deleted)| {
+        if address.is_some() { *deleted = true; }
+    });
+    CHANNEL.bitcoin_wallet_tx.send_modify(|(_, address, deleted)| {
+        if address.is_some() { *deleted = true; }
+    });
+}
+
+/// Changes whichever PIN `old_pin` matches. Inside the decoy profile this rotates the
+/// duress PIN, so the flow behaves the same without touching the main PIN. The duress PIN
+/// shares the main PIN's format, so a format change is refused there.
+pub fn change_pin(old_pin: &str, new_pin: &str, format: UnlockFormat) -> Result<(), PinError> {
+    let verified = check_pin(old_pin);
+    if verified.is_err() {
+        audit_attempt(&verified, "change");
+    }
+    match verified? {
+        Profile::Primary => set_pin(new_pin, format)?,
+        Profile::Decoy => {
+            if format != load_format() {
+                return Err(PinError::FormatLocked);
+            }
+            set_duress_pin(new_pin)?
+        }
+    }
+    audit::record(AuditEvent::PinChanged, format.label());
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,42 @@
+// src/preferences.rs
+//
+// User-tunable security preferences, stored in preferences.json. Every field has a
+// default so older or partial files keep loading.
+
+use serde::{Deserialize, Serialize};
+use std::io;
+use crate::utils::json_storage;
+
+const PREFERENCES_FILE: &str = "preferences.json";
+
+/// Choices offered for the idle auto-lock, in minutes. `None` disables it.
+pub const IDLE_LOCK_OPTIONS: [Option<u32>; 5] = [None, Some(1), Some(5), Some(15), Some(30)];
+
+/// Choices offered for clearing copied addresses and secrets, in seconds. `None` disables it.
+pub const CLIPBOARD_CLEAR_OPTIONS: [Option<u32>; 5] = [None, Some(15), Some(30), Some(60), Some(120)];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Preferences {
+    pub idle_lock_minutes: Option<u32>,
+    pub lock_on_session_lock: bool,
+    pub clipboard_clear_secs: Option<u32>,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Preferences {
+            idle_lock_minutes: Some(5),
+            lock_on_session_lock: true,
+            clipboard_clear_secs: Some(30),
+        }
+    }
+}
+
+pub fn load() -> Preferences {
+    json_storage::read_json(PREFERENCES_FILE).unwrap_or_default()
+}
+
+pub fn update(update_fn: impl FnOnce(&mut Preferences)) -> io::Result<()> {
+    json_storage::update_json(PREFERENCES_FILE, update_fn)
+}
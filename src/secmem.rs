@@ -0,0 +1,159 @@
+// src/secmem.rs
+//
+// Keeps secret material out of swap and core dumps on Linux. `harden_process` runs once at
+// startup; `Locked` pins the heap buffer of a mnemonic, passphrase or derived key in RAM for as
+// long as it lives and wipes it before releasing the lock. Elsewhere both are best-effort no-ops.
+//
+// mlock works on whole pages and is not reference counted, so unlocking one buffer can also
+// unlock a neighbour on the same page. Secrets are short-lived, which keeps that window small.
+
+use std::fmt;
+use std::ops::Deref;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Heap-backed buffers whose allocation can be locked.
+pub trait SecretBuffer: Zeroize {
+    /// Start and length of the whole allocation, spare capacity included.
+    fn region(&self) -> (*const u8, usize);
+}
+
+impl SecretBuffer for Vec<u8> {
+    fn region(&self) -> (*const u8, usize) {
+        (self.as_ptr(), self.capacity())
+    }
+}
+
+impl SecretBuffer for String {
+    fn region(&self) -> (*const u8, usize) {
+        (self.as_ptr(), self.capacity())
+    }
+}
+
+/// A secret whose allocation is locked into RAM and zeroized on drop. Read-only, so the
+/// buffer is never reallocated behind the lock.
+pub struct Locked<T: SecretBuffer> {
+    inner: T,
+    locked: bool,
+}
+
+pub type SecretString = Locked<String>;
+pub type SecretBytes = Locked<Vec<u8>>;
+
+impl<T: SecretBuffer> Locked<T> {
+    pub fn new(inner: T) -> Self {
+        let (ptr, len) = inner.region();
+        let locked = len > 0 && sys::lock(ptr, len);
+        Locked { inner, locked }
+    }
+}
+
+impl SecretBytes {
+    /// `len` zero bytes, locked before anything secret is written into them.
+    pub fn zeroed(len: usize) -> Self {
+        Locked::new(vec![0u8; len])
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.inner.as_mut_slice()
+    }
+}
+
+impl From<Zeroizing<String>> for SecretString {
+    // Moves the allocation out of the guard instead of copying it
+    fn from(mut value: Zeroizing<String>) -> Self {
+        Locked::new(std::mem::take(&mut *value))
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Locked::new(value)
+    }
+}
+
+impl<T: SecretBuffer> Deref for Locked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: SecretBuffer> Drop for Locked<T> {
+    fn drop(&mut self) {
+        let (ptr, len) = self.inner.region();
+        self.inner.zeroize();
+        if self.locked {
+            sys::unlock(ptr, len);
+        }
+    }
+}
+
+impl<T: SecretBuffer> fmt::Debug for Locked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Locked(<redacted>)")
+    }
+}
+
+/// Disables core dumps and ptrace attach by other processes of the same user, and checks that
+/// secrets can be locked at all, since `Locked` quietly carries on when they cannot.
+pub fn harden_process() -> Result<(), String> {
+    sys::disable_core_dumps().map_err(|e| format!("Could not disable core dumps: {}", e))?;
+    sys::probe_lock().map_err(|e| format!("mlock failed, secrets may be swapped: {}", e))
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::io;
+
+    pub fn lock(ptr: *const u8, len: usize) -> bool {
+        // SAFETY: ptr/len describe a live allocation owned by the caller
+        unsafe { libc::mlock(ptr as *const libc::c_void, len) == 0 }
+    }
+
+    // RLIMIT_MEMLOCK can be small or zero; find out once instead of on every secret
+    pub fn probe_lock() -> io::Result<()> {
+        let probe = [0u8; 64];
+        if !lock(probe.as_ptr(), probe.len()) {
+            return Err(io::Error::last_os_error());
+        }
+        unlock(probe.as_ptr(), probe.len());
+        Ok(())
+    }
+
+    pub fn unlock(ptr: *const u8, len: usize) {
+        // SAFETY: same region that was passed to mlock
+        unsafe { libc::munlock(ptr as *const libc::c_void, len) };
+    }
+
+    pub fn disable_core_dumps() -> io::Result<()> {
+        let no_core = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+        // SAFETY: plain syscalls on this process with valid arguments
+        unsafe {
+            if libc::setrlimit(libc::RLIMIT_CORE, &no_core) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    pub fn lock(_ptr: *const u8, _len: usize) -> bool {
+        false
+    }
+
+    pub fn unlock(_ptr: *const u8, _len: usize) {}
+
+    pub fn disable_core_dumps() -> std::io::Result<()> {
+        Ok(())
+    }
+
+    pub fn probe_lock() -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+// src/signer/btc.rs
+//
+// Bitcoin spends from the wallet's account addresses: coins are selected from their unspent
+// outputs, change goes to a fresh address on the internal chain, and every input is signed with
+// the key of the address it pays to, the way its script type requires: a legacy sighash for
+// P2PKH, BIP143 for native and nested SegWit, and a BIP341 key-path signature for Taproot.
+
+use std::str::FromStr;
+use bitcoin::absolute::LockTime;
+use bitcoin::consensus::encode::serialize_hex;
+use bitcoin::key::{Keypair, TapTweak};
+use bitcoin::script::PushBytesBuf;
+use bitcoin::secp256k1::{Message, Secp256k1};
+use bitcoin::sighash::{EcdsaSighashType, Prevouts, SighashCache, TapSighashType};
+use bitcoin::transaction::Version;
+use bitcoin::{
+    ecdsa, taproot, Address, Amount, CompressedPublicKey, Denomination, Network, OutPoint,
+    PrivateKey, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Weight, Witness,
+};
+use crate::btcaccount::{self, AddressType, KeyChain};
+use crate::chain::esplora::Utxo;
+use super::SignedTx;
+
+/// A payment as the send form collected it, parsed and checked against mainnet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spend {
+    pub recipient: Address,
+    pub amount: Amount,
+    pub fee: Amount,
+    /// Coins picked by hand, all of which are spent; empty selects automatically.
+    pub inputs: Vec<OutPoint>,
+}
+
+impl Spend {
+    /// `amount` is in BTC, `fee_sats` is the absolute fee in satoshis.
+    pub fn parse(recipient: &str, amount: &str, fee_sats: &str) -> Result<Self, String> {
+        let recipient = Address::from_str(recipient.trim())
+            .map_err(|e| format!("Invalid recipient: {}", e))?
+            .require_network(Network::Bitcoin)
+            .map_err(|_| "Recipient is not a mainnet address".to_string())?;
+        let amount = Amount::from_str_in(amount.trim(), Denomination::Bitcoin)
+            .map_err(|e| format!("Invalid amount: {}", e))?;
+        let fee = fee_sats.trim().parse::<u64>()
+            .map(Amount::from_sat)
+            .map_err(|e| format!("Invalid fee: {}", e))?;
+        // Outputs below this are uneconomical to spend and non-standard to relay
+        if amount < recipient.script_pubkey().minimal_non_dust() {
+            return Err("Amount is below the dust limit".to_string());
+        }
+        Ok(Spend { recipient, amount, fee, inputs: Vec::new() })
+    }
+
+    /// Spends exactly the coins at `outpoints` (txid:vout) instead of selecting them.
+    pub fn with_inputs(mut self, outpoints: &[String]) -> Result<Self, String> {
+        self.inputs = outpoints.iter()
+            .map(|outpoint| OutPoint::from_str(outpoint).map_err(|e| format!("Invalid coin {}: {}", outpoint, e)))
+            .collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+}
+
+/// An unspent output of the wallet and the address whose key spends it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coin {
+    pub chain: KeyChain,
+    pub index: u32,
+    pub address: String,
+    pub utxo: Utxo,
+}
+
+// Confirmed coins first, largest first, until amount and fee are covered
+fn select(coins: &[Coin], target: Amount) -> Result<(Vec<&Coin>, Amount), String> {
+    let mut ordered: Vec<&Coin> = coins.iter().collect();
+    ordered.sort_by(|a, b| b.utxo.confirmed.cmp(&a.utxo.confirmed).then(b.utxo.value.cmp(&a.utxo.value)));
+
+    let mut selected = Vec::new();
+    let mut total = Amount::ZERO;
+    for coin in ordered {
+        if total >= target {
+            break;
+        }
+        total += coin.utxo.value;
+        selected.push(coin);
+    }
+
+    if total < target {
+        return Err(format!("Insufficient funds: {} available, {} needed", total, target));
+    }
+    Ok((selected, total))
+}
+
+// Every coin picked by hand, which must still be spendable and cover amount and fee
+fn chosen<'a>(coins: &'a [Coin], outpoints: &[OutPoint], target: Amount) -> Result<(Vec<&'a Coin>, Amount), String> {
+    let mut selected = Vec::new();
+    let mut total = Amount::ZERO;
+    for outpoint in outpoints {
+        let coin = coins.iter()
+            .find(|coin| coin.utxo.outpoint == *outpoint)
+            .ok_or_else(|| format!("Coin {} is frozen or already spent", outpoint))?;
+        total += coin.utxo.value;
+        selected.push(coin);
+    }
+
+    if total < target {
+        return Err(format!("Selected coins hold {}, {} needed", total, target));
+    }
+    Ok((selected, total))
+}
+
+// Spending weight of one input: outpoint, sequence and script sig length byte, plus its
+// signature and public key where the script type puts them
+fn input_weight(address_type: AddressType) -> Weight {
+    let ecdsa_witness = 1 + 1 + 72 + 1 + 33;
+    match address_type {
+        AddressType::Legacy => Weight::from_non_witness_data_size(41 + 1 + 72 + 1 + 33),
+        AddressType::NestedSegwit => Weight::from_non_witness_data_size(41 + 23) + Weight::from_witness_data_size(ecdsa_witness),
+        AddressType::NativeSegwit => Weight::from_non_witness_data_size(41) + Weight::from_witness_data_size(ecdsa_witness),
+        AddressType::Taproot => Weight::from_non_witness_data_size(41) + Weight::from_witness_data_size(1 + 1 + 64),
+    }
+}
+
+/// Virtual size of a transaction spending `inputs` coins of `address_type` to `outputs`, with
+/// signatures at their largest.
+pub fn estimate_vsize(address_type: AddressType, inputs: usize, outputs: &[ScriptBuf]) -> u64 {
+    // Version, locktime and the input and output counts
+    let mut weight = Weight::from_non_witness_data_size(4 + 4 + 1 + 1);
+    if address_type != AddressType::Legacy {
+        weight += Weight::from_witness_data_size(2);
+    }
+    weight += input_weight(address_type) * inputs as u64;
+    for script in outputs {
+        weight += Weight::from_non_witness_data_size(8 + 1 + script.len() as u64);
+    }
+    weight.to_vbytes_ceil()
+}
+
+// Key, public key and address of `index` on `chain`
+fn account_key(
+    seed: &[u8; 64],
+    address_type: AddressType,
+    chain: KeyChain,
+    index: u32,
+) -> Result<(PrivateKey, CompressedPublicKey, Address), String> {
+    let secp = Secp256k1::new();
+    let key = btcaccount::private_key(seed, address_type, chain, index)?;
+    let public_key = CompressedPublicKey(key.public_key(&secp).inner);
+    let address = address_type.address(&secp, &public_key);
+    Ok((key, public_key, address))
+}
+
+fn sign_ecdsa(key: &PrivateKey, sighash: Message) -> ecdsa::Signature {
+    let signature = Secp256k1::signing_only().sign_ecdsa(&sighash, &key.inner);
+    ecdsa::Signature { signature, sighash_type: EcdsaSighashType::All }
+}
+
+// Script sig and witness that spend `coin` as input `input`
+fn unlock(
+    key: &PrivateKey,
+    public_key: &CompressedPublicKey,
+    address_type: AddressType,
+    cache: &mut SighashCache<&Transaction>,
+    input: usize,
+    prevouts: &[TxOut],
+) -> Result<(ScriptBuf, Witness), String> {
+    let sighash_failed = |e: &dyn std::fmt::Display| format!("Sighash failed: {}", e);
+    let prevout = &prevouts[input];
+    match address_type {
+        AddressType::Legacy => {
+            let sighash = cache.legacy_signature_hash(input, &prevout.script_pubkey, EcdsaSighashType::All.to_u32())
+                .map_err(|e| sighash_failed(&e))?;
+            let signature = sign_ecdsa(key, Message::from(sighash));
+            let script_sig = ScriptBuf::builder()
+                .push_slice(signature.serialize())
+                .push_key(&(*public_key).into())
+                .into_script();
+            Ok((script_sig, Witness::new()))
+        }
+        AddressType::NestedSegwit | AddressType::NativeSegwit => {
+            // Nested SegWit signs the P2WPKH program its P2SH script wraps
+            let program = ScriptBuf::new_p2wpkh(&public_key.wpubkey_hash());
+            let sighash = cache.p2wpkh_signature_hash(input, &program, prevout.value, EcdsaSighashType::All)
+                .map_err(|e| sighash_failed(&e))?;
+            let signature = sign_ecdsa(key, Message::from(sighash));
+            let script_sig = match address_type {
+                AddressType::NestedSegwit => {
+                    let redeem_script = PushBytesBuf::try_from(program.into_bytes())
+                        .map_err(|e| format!("Invalid redeem script: {}", e))?;
+                    ScriptBuf::builder().push_slice(redeem_script).into_script()
+                }
+                _ => ScriptBuf::new(),
+            };
+            Ok((script_sig, Witness::p2wpkh(&signature, &public_key.0)))
+        }
+        AddressType::Taproot => {
+            let sighash = cache.taproot_key_spend_signature_hash(input, &Prevouts::All(prevouts), TapSighashType::Default)
+                .map_err(|e| sighash_failed(&e))?;
+            let secp = Secp256k1::new();
+            let mut keypair = Keypair::from_secret_key(&secp, &key.inner).tap_tweak(&secp, None).to_keypair();
+            let signature = secp.sign_schnorr_no_aux_rand(&Message::from(sighash), &keypair);
+            keypair.non_secure_erase();
+            let signature = taproot::Signature { signature, sighash_type: TapSighashType::Default };
+            Ok((ScriptBuf::new(), Witness::p2tr_key_spend(&signature)))
+        }
+    }
+}
+
+fn sign_input(
+    seed: &[u8; 64],
+    address_type: AddressType,
+    cache: &mut SighashCache<&Transaction>,
+    input: usize,
+    coin: &Coin,
+    prevouts: &[TxOut],
+) -> Result<(ScriptBuf, Witness), String> {
+    let (mut key, public_key, address) = account_key(seed, address_type, coin.chain, coin.index)?;
+    let result = if address.to_string() != coin.address {
+        Err(super::mismatch())
+    } else {
+        unlock(&key, &public_key, address_type, cache, input, prevouts)
+    };
+    key.inner.non_secure_erase();
+    result
+}
+
+/// An unsigned spend: the transaction, the coins its inputs spend and their outputs, in input
+/// order, and the change address if change was large enough to keep.
+pub struct Draft {
+    pub tx: Transaction,
+    pub inputs: Vec<Coin>,
+    pub prevouts: Vec<TxOut>,
+    pub change: Option<Address>,
+}
+
+/// Funds `spend` from `coins` and lays out the transaction, with any change paid to
+/// `change_address`.
+pub fn draft(spend: &Spend, coins: &[Coin], change_address: &Address) -> Result<Draft, String> {
+    let target = spend.amount.checked_add(spend.fee).ok_or("Amount overflow")?;
+    let (inputs, total) = if spend.inputs.is_empty() {
+        select(coins, target)?
+    } else {
+        chosen(coins, &spend.inputs, target)?
+    };
+
+    let mut output = vec![TxOut { value: spend.amount, script_pubkey: spend.recipient.script_pubkey() }];
+    // Change too small to spend later is left to the miner instead
+    let change = total - target;
+    let change = if change >= change_address.script_pubkey().minimal_non_dust() {
+        output.push(TxOut { value: change, script_pubkey: change_address.script_pubkey() });
+        Some(change_address.clone())
+    } else {
+        None
+    };
+
+    let tx = Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO,
+        input: inputs.iter().map(|coin| TxIn {
+            previous_output: coin.utxo.outpoint,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+        }).collect(),
+        output,
+    };
+
+    // Taproot sighashes commit to every output being spent
+    let prevouts = inputs.iter()
+        .map(|coin| Address::from_str(&coin.address)
+            .map(|address| TxOut { value: coin.utxo.value, script_pubkey: address.assume_checked().script_pubkey() })
+            .map_err(|e| format!("Invalid coin address: {}", e)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let inputs: Vec<Coin> = inputs.into_iter().cloned().collect();
+    Ok(Draft { tx, inputs, prevouts, change })
+}
+
+/// Absolute fee paying `rate` sat/vB for `spend` as `draft` would lay it out, and the estimated
+/// virtual size it was priced at. A higher fee can pull in another input, so the draft is
+/// repeated until the fee covers its own transaction.
+pub fn fee_for(
+    address_type: AddressType,
+    spend: &Spend,
+    coins: &[Coin],
+    change_address: &Address,
+    rate: f64,
+) -> Result<(Amount, u64), String> {
+    let mut spend = spend.clone();
+    spend.fee = Amount::ZERO;
+    for _ in 0..10 {
+        let Draft { tx, .. } = draft(&spend, coins, change_address)?;
+        let outputs: Vec<ScriptBuf> = tx.output.into_iter().map(|output| output.script_pubkey).collect();
+        let vsize = estimate_vsize(address_type, tx.input.len(), &outputs);
+        let needed = Amount::from_sat((rate * vsize as f64).ceil() as u64);
+        if needed <= spend.fee {
+            return Ok((spend.fee, vsize));
+        }
+        spend.fee = needed;
+    }
+    Err("Fee did not settle on a transaction size".to_string())
+}
+
+/// Builds and signs `spend` from `wallet`, an account of `address_type`, funded by `coins`, with
+/// any change paid to index `change_index` of the internal chain. Returns the change address
+/// alongside the transaction when the change was large enough to keep.
+pub fn sign(
+    seed: &[u8; 64],
+    address_type: AddressType,
+    wallet: &str,
+    spend: &Spend,
+    coins: &[Coin],
+    change_index: u32,
+) -> Result<(SignedTx, Option<String>), String> {
+    // The wallet is named by its first receive address; any other seed is the wrong one
+    let (mut key, _, own_address) = account_key(seed, address_type, KeyChain::Receive, 0)?;
+    key.inner.non_secure_erase();
+    if own_address.to_string() != wallet {
+        return Err(super::mismatch());
+    }
+
+    let (mut key, _, change_address) = account_key(seed, address_type, KeyChain::Change, change_index)?;
+    key.inner.non_secure_erase();
+    let Draft { mut tx, inputs, prevouts, change } = draft(spend, coins, &change_address)?;
+
+    let mut cache = SighashCache::new(&tx);
+    let unlocks = inputs.iter().enumerate()
+        .map(|(index, coin)| sign_input(seed, address_type, &mut cache, index, coin, &prevouts))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    for (input, (script_sig, witness)) in tx.input.iter_mut().zip(unlocks) {
+        input.script_sig = script_sig;
+        input.witness = witness;
+    }
+
+    let signed = SignedTx { blob: serialize_hex(&tx), hash: tx.compute_txid().to_string() };
+    Ok((signed, change.map(|address| address.to_string())))
+}
@@ -0,0 +1,55 @@
+// src/signer/issuers.rs
+//
+// The issuing account behind every token symbol the wallet trades. Payments, trustlines and
+// offers name a token by symbol only; the XRPL names it by currency code and issuer, and a
+// trustline to the wrong issuer holds a different token. Built-in entries are the accounts the
+// issuers themselves publish. `issuers.json` in the config directory adds symbols or replaces
+// an entry, as {"SYMBOL": "rIssuer..."}, without a new release.
+
+use std::collections::BTreeMap;
+use crate::utils::json_storage;
+
+pub const ISSUERS_FILE: &str = "issuers.json";
+
+/// A token symbol, its issuing account and who publishes that account.
+pub struct Issuer {
+    pub symbol: &'static str,
+    pub account: &'static str,
+    pub published_by: &'static str,
+}
+
+pub const KNOWN: &[Issuer] = &[
+    Issuer { symbol: "RLUSD", account: "rMxCKbEDwqr76QuheSUMdEGf4B9xJ8m5De", published_by: "Ripple (Standard Custody & Trust Company)" },
+    Issuer { symbol: "EUROP", account: "rMkEuRii9w9uBMQDnWV5AA43gvYZR9JxVK", published_by: "Schuman Financial" },
+    Issuer { symbol: "XSGD", account: "rK67JczCpaYXVtfw3qJVmqwpSfa1bYTptw", published_by: "StraitsX" },
+];
+
+// Classic addresses: 'r' then base58 in the XRPL alphabet, 25 to 35 characters in all
+fn is_classic_address(account: &str) -> bool {
+    const ALPHABET: &str = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+    account.starts_with('r')
+        && (25..=35).contains(&account.len())
+        && account.chars().all(|c| ALPHABET.contains(c))
+}
+
+fn configured() -> BTreeMap<String, String> {
+    json_storage::read_json::<BTreeMap<String, String>>(ISSUERS_FILE)
+        .map(|entries| entries.into_iter().map(|(symbol, account)| (symbol.to_uppercase(), account)).collect())
+        .unwrap_or_default()
+}
+
+/// Issuing account of `symbol`: the configured one if `issuers.json` lists it, else the
+/// built-in one.
+pub fn account(symbol: &str) -> Result<String, String> {
+    let symbol = symbol.to_uppercase();
+    if let Some(account) = configured().remove(&symbol) {
+        if !is_classic_address(&account) {
+            return Err(format!("Issuer configured for {} is not an XRPL address: {}", symbol, account));
+        }
+        return Ok(account);
+    }
+    KNOWN.iter()
+        .find(|issuer| issuer.symbol == symbol)
+        .map(|issuer| issuer.account.to_string())
+        .ok_or_else(|| format!("No issuer configured for {}. Add its issuing account to {}", symbol, ISSUERS_FILE))
+}
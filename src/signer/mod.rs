@@ -0,0 +1,246 @@
+// src/signer/mod.rs
+//
+// The one place a wallet secret becomes a signature. Auth forms hand their credentials over as
+// they were typed; the signer decrypts the keystore (or takes the typed mnemonic), derives the
+// account key, checks it belongs to the wallet being spent from, builds and signs the
+// transaction, and wipes every intermediate. Callers get back the signed blob and its hash,
+// which is all the ws task ever receives.
+
+pub mod btc;
+pub mod issuers;
+pub mod psbt;
+pub mod session;
+pub mod xrp;
+
+use std::str::FromStr;
+use bitcoin::bip32::Xpub;
+use bitcoin::psbt::Psbt;
+use zeroize::{Zeroize, Zeroizing};
+use crate::btcaccount::{self, AddressType, KeyChain};
+use crate::chain;
+use crate::clipboard::AddressChain;
+use crate::keystore;
+use crate::master;
+use crate::secmem::{SecretBytes, SecretString};
+use self::session::SessionLimits;
+
+enum KeySource {
+    Passphrase(SecretString),
+    Mnemonic(SecretString),
+    Session,
+}
+
+/// What an auth form collected, moved into locked memory.
+pub struct Credentials {
+    key: KeySource,
+    bip39: SecretString,
+    // Keep the seed unlocked for later transactions once this one is signed
+    session: Option<SessionLimits>,
+}
+
+impl Credentials {
+    /// Passphrase mode unlocks the stored keystore; seed mode signs from the typed mnemonic;
+    /// session mode reuses the seed an open signing session holds. The inputs that are not
+    /// used are wiped when their guards drop.
+    pub fn from_form(
+        mode: &str,
+        passphrase: Zeroizing<String>,
+        mnemonic: Zeroizing<String>,
+        bip39_pass: Zeroizing<String>,
+        session: Option<SessionLimits>,
+    ) -> Result<Self, String> {
+        let key = match mode {
+            "passphrase" if !passphrase.is_empty() => KeySource::Passphrase(SecretString::from(passphrase)),
+            "seed" if !mnemonic.trim().is_empty() => KeySource::Mnemonic(SecretString::from(mnemonic)),
+            "session" => KeySource::Session,
+            _ => return Err("No credentials provided".to_string()),
+        };
+        // A blank BIP39 field means no passphrase, not a passphrase of spaces
+        let bip39 = if bip39_pass.trim().is_empty() {
+            SecretString::from(String::new())
+        } else {
+            SecretString::from(bip39_pass)
+        };
+        let session = match key {
+            KeySource::Session => None,
+            _ => session,
+        };
+        Ok(Credentials { key, bip39, session })
+    }
+}
+
+/// A fully signed transaction, ready for submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTx {
+    pub blob: String,
+    pub hash: String,
+}
+
+// The 64-byte BIP39 seed behind `chain`'s wallet, in locked memory
+fn wallet_seed(chain: AddressChain, credentials: &Credentials) -> Result<SecretBytes, String> {
+    let mnemonic = match &credentials.key {
+        KeySource::Passphrase(passphrase) => {
+            let file = master::signing_keystore(chain)?;
+            keystore::open(file, passphrase)
+                .map_err(|_| "Decryption passphrase is incorrect".to_string())?
+        }
+        KeySource::Mnemonic(phrase) => SecretString::from(phrase.trim().to_string()),
+        KeySource::Session => {
+            return session::take(chain).ok_or_else(|| "Signing session has ended. Authorize again".to_string());
+        }
+    };
+
+    let mut seed = crate::mnemonic::parse_any(&mnemonic)?.to_seed(credentials.bip39.as_str());
+    let mut locked = SecretBytes::zeroed(seed.len());
+    locked.as_mut_slice().copy_from_slice(&seed);
+    seed.zeroize();
+    Ok(locked)
+}
+
+// Counts a signature against the session it came from, or opens the session the form asked for
+fn signed_with(chain: AddressChain, credentials: &Credentials, seed: &SecretBytes) {
+    match (&credentials.key, credentials.session) {
+        (KeySource::Session, _) => session::used(chain),
+        (_, Some(limits)) => session::start(chain, seed, limits),
+        _ => {}
+    }
+}
+
+fn seed_array(seed: &SecretBytes) -> &[u8; 64] {
+    seed.as_slice().try_into().expect("BIP39 seed is 64 bytes")
+}
+
+fn mismatch() -> String {
+    "Key does not match this wallet. Check the mnemonic and BIP39 passphrase".to_string()
+}
+
+/// Autofills `tx` from the ledger, then signs it for `wallet` off the async runtime.
+pub async fn sign_xrp(wallet: String, credentials: Credentials, tx: xrp::XrpTx) -> Result<SignedTx, String> {
+    let state = chain::xrpl::account_state(&wallet).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let seed = wallet_seed(AddressChain::Xrp, &credentials)?;
+        let signed = xrp::sign(seed_array(&seed), &wallet, &tx, &state)?;
+        signed_with(AddressChain::Xrp, &credentials, &seed);
+        Ok(signed)
+    })
+    .await
+    .map_err(|e| format!("Internal Thread Error: {}", e))?
+}
+
+// Unspent outputs of every used address on both chains, or of the wallet address alone until
+// the account xpub is known
+async fn wallet_coins(wallet: &str, account: &btcaccount::Account) -> Result<Vec<btc::Coin>, String> {
+    let mut owned: Vec<(KeyChain, u32, String)> = Vec::new();
+    for key_chain in [KeyChain::Receive, KeyChain::Change] {
+        owned.extend(account.addresses(key_chain).iter()
+            .filter(|t| t.used || (key_chain == KeyChain::Receive && t.index == 0))
+            .map(|t| (key_chain, t.index, t.address.clone())));
+    }
+    if owned.is_empty() {
+        owned.push((KeyChain::Receive, 0, wallet.to_string()));
+    }
+
+    let mut coins = Vec::new();
+    for (key_chain, index, address) in owned {
+        for utxo in chain::esplora::utxos(&address).await? {
+            coins.push(btc::Coin { chain: key_chain, index, address: address.clone(), utxo });
+        }
+    }
+    Ok(coins)
+}
+
+// The coins a spend may use; frozen ones stay where they are
+async fn btc_coins(wallet: &str, account: &btcaccount::Account) -> Result<Vec<btc::Coin>, String> {
+    let mut coins = wallet_coins(wallet, account).await?;
+    coins.retain(|coin| !account.coin(&coin.utxo.outpoint).frozen);
+    Ok(coins)
+}
+
+/// Every unspent output of the wallet, frozen ones included, for coin control. Covers the
+/// addresses discovery last found; `wallet::refresh_bitcoin` rescans.
+pub async fn btc_utxos(wallet: String) -> Result<Vec<btc::Coin>, String> {
+    let account = btcaccount::load();
+    let coins = wallet_coins(&wallet, &account).await?;
+    let unspent: Vec<_> = coins.iter().map(|coin| coin.utxo.outpoint).collect();
+    btcaccount::prune_coins(&unspent).map_err(|e| format!("Could not prune BTC coin labels: {}", e))?;
+    Ok(coins)
+}
+
+/// Absolute fee for `spend` at `rate` sat/vB, funded the way signing it would be.
+pub async fn btc_fee(wallet: String, spend: btc::Spend, rate: f64) -> Result<bitcoin::Amount, String> {
+    let account = btcaccount::load();
+    let coins = btc_coins(&wallet, &account).await?;
+    // Only the change script's size matters here; the wallet address has the same type
+    let change_address = match account.xpub.as_deref().and_then(|xpub| Xpub::from_str(xpub).ok()) {
+        Some(xpub) => btcaccount::address_at(&xpub, account.script_type, KeyChain::Change, account.next_change_index())?,
+        None => bitcoin::Address::from_str(&wallet)
+            .map_err(|e| format!("Invalid wallet address: {}", e))?
+            .assume_checked(),
+    };
+    btc::fee_for(account.script_type, &spend, &coins, &change_address, rate).map(|(fee, _)| fee)
+}
+
+/// Funds `spend` from the wallet's unspent outputs and signs every input.
+pub async fn sign_btc(wallet: String, credentials: Credentials, spend: btc::Spend) -> Result<SignedTx, String> {
+    let account = btcaccount::load();
+    let coins = btc_coins(&wallet, &account).await?;
+    let change_index = account.next_change_index();
+    let address_type = account.script_type;
+
+    tokio::task::spawn_blocking(move || {
+        let seed = wallet_seed(AddressChain::Btc, &credentials)?;
+        let (signed, change_address) = btc::sign(seed_array(&seed), address_type, &wallet, &spend, &coins, change_index)?;
+        btcaccount::adopt(seed_array(&seed))
+            .map_err(|e| format!("Could not record BTC account xpub: {}", e))?;
+        // Change the wallet does not know about would not be tracked, so this must not fail quietly
+        if let Some(address) = change_address {
+            btcaccount::reserve_change(change_index, address)
+                .map_err(|e| format!("Could not record BTC change address: {}", e))?;
+        }
+        signed_with(AddressChain::Btc, &credentials, &seed);
+        Ok(signed)
+    })
+    .await
+    .map_err(|e| format!("Internal Thread Error: {}", e))?
+}
+
+/// Lays `spend` out as an unsigned PSBT from the watch-only account, for a signer that holds
+/// the seed elsewhere. The change address is reserved as if the spend had been signed here.
+pub async fn create_psbt(wallet: String, spend: btc::Spend) -> Result<Psbt, String> {
+    let account = btcaccount::load();
+    let xpub = account.xpub.as_deref()
+        .ok_or_else(|| "No account xpub recorded for this wallet".to_string())
+        .and_then(|xpub| Xpub::from_str(xpub).map_err(|e| format!("Invalid account xpub: {}", e)))?;
+    let coins = btc_coins(&wallet, &account).await?;
+    let change_index = account.next_change_index();
+    let change_address = btcaccount::address_at(&xpub, account.script_type, KeyChain::Change, change_index)?;
+
+    let draft = btc::draft(&spend, &coins, &change_address)?;
+    let mut previous = Vec::new();
+    if account.script_type != AddressType::Taproot {
+        for coin in &draft.inputs {
+            previous.push(chain::esplora::transaction(&coin.utxo.outpoint.txid).await?);
+        }
+    }
+
+    let has_change = draft.change.is_some();
+    let psbt = psbt::create(&account, draft, change_index, &previous)?;
+    if has_change {
+        btcaccount::reserve_change(change_index, change_address.to_string())
+            .map_err(|e| format!("Could not record BTC change address: {}", e))?;
+    }
+    Ok(psbt)
+}
+
+/// Signs every input of `psbt` with the BTC wallet's seed, off the async runtime.
+pub async fn sign_psbt(credentials: Credentials, mut psbt: Psbt) -> Result<Psbt, String> {
+    tokio::task::spawn_blocking(move || {
+        let seed = wallet_seed(AddressChain::Btc, &credentials)?;
+        psbt::sign(seed_array(&seed), &mut psbt)?;
+        signed_with(AddressChain::Btc, &credentials, &seed);
+        Ok(psbt)
+    })
+    .await
+    .map_err(|e| format!("Internal Thread Error: {}", e))?
+}
@@ -0,0 +1,381 @@
+// src/signer/psbt.rs
+//
+// BIP174 PSBTs for air-gapped signing. The online, watch-only install lays a spend out from the
+// account xpub and exports it with each input's previous output and key origin; the offline
+// install signs it from the seed; the online install finalizes the signed copy into the
+// transaction it broadcasts. Files hold the binary encoding, QR codes and pasted text base64.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use bitcoin::bip32::{DerivationPath, Fingerprint, KeySource, Xpriv, Xpub};
+use bitcoin::consensus::encode::serialize_hex;
+use bitcoin::psbt::{Input, Output, Psbt, SigningKeys};
+use bitcoin::script::PushBytesBuf;
+use bitcoin::secp256k1::{PublicKey, Secp256k1, Signing};
+use bitcoin::{Address, Amount, CompressedPublicKey, Network, OutPoint, ScriptBuf, Transaction, Txid, Witness};
+use crate::btcaccount::{self, Account, AddressType, KeyChain};
+use crate::utils::json_storage;
+use super::btc::Draft;
+use super::SignedTx;
+
+pub const PSBT_EXTENSION: &str = "psbt";
+
+// Leading bytes of the binary encoding
+const MAGIC: &[u8] = b"psbt\xff";
+
+/// One input as the review screen shows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PsbtInput {
+    pub outpoint: OutPoint,
+    pub address: String,
+    pub value: Amount,
+    pub signed: bool,
+}
+
+/// One output as the review screen shows it. `change` outputs carry a key origin of the wallet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PsbtOutput {
+    pub address: String,
+    pub value: Amount,
+    pub change: bool,
+}
+
+/// What a PSBT spends, pays and leaves to the miner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub txid: Txid,
+    pub inputs: Vec<PsbtInput>,
+    pub outputs: Vec<PsbtOutput>,
+    pub fee: Amount,
+}
+
+impl Summary {
+    pub fn fully_signed(&self) -> bool {
+        self.inputs.iter().all(|input| input.signed)
+    }
+}
+
+fn address_of(script: &ScriptBuf) -> String {
+    Address::from_script(script, Network::Bitcoin)
+        .map(|address| address.to_string())
+        .unwrap_or_else(|_| script.to_hex_string())
+}
+
+fn input_origin(input: &mut Input, address_type: AddressType, public_key: &CompressedPublicKey, origin: KeySource) {
+    match address_type {
+        AddressType::Taproot => {
+            let (internal_key, _) = public_key.0.x_only_public_key();
+            input.tap_internal_key = Some(internal_key);
+            input.tap_key_origins.insert(internal_key, (Vec::new(), origin));
+        }
+        _ => {
+            input.bip32_derivation.insert(public_key.0, origin);
+            if address_type == AddressType::NestedSegwit {
+                input.redeem_script = Some(ScriptBuf::new_p2wpkh(&public_key.wpubkey_hash()));
+            }
+        }
+    }
+}
+
+fn output_origin(output: &mut Output, address_type: AddressType, public_key: &CompressedPublicKey, origin: KeySource) {
+    match address_type {
+        AddressType::Taproot => {
+            let (internal_key, _) = public_key.0.x_only_public_key();
+            output.tap_internal_key = Some(internal_key);
+            output.tap_key_origins.insert(internal_key, (Vec::new(), origin));
+        }
+        _ => {
+            output.bip32_derivation.insert(public_key.0, origin);
+            if address_type == AddressType::NestedSegwit {
+                output.redeem_script = Some(ScriptBuf::new_p2wpkh(&public_key.wpubkey_hash()));
+            }
+        }
+    }
+}
+
+/// Unsigned PSBT for `draft`, laid out from the watch-only `account` with change at
+/// `change_index`. `previous` holds the funding transactions of every input that is not
+/// Taproot: BIP174 requires them for legacy inputs, and SegWit v0 signatures do not commit to
+/// the other inputs' amounts, so the signer needs them to check the fee (CVE-2020-14199).
+pub fn create(account: &Account, draft: Draft, change_index: u32, previous: &[Transaction]) -> Result<Psbt, String> {
+    let xpub = account.xpub.as_deref()
+        .ok_or_else(|| "No account xpub recorded for this wallet".to_string())
+        .and_then(|xpub| Xpub::from_str(xpub).map_err(|e| format!("Invalid account xpub: {}", e)))?;
+    let address_type = account.script_type;
+    let secp = Secp256k1::verification_only();
+
+    let Draft { tx, inputs, prevouts, change } = draft;
+    let mut psbt = Psbt::from_unsigned_tx(tx).map_err(|e| format!("Invalid transaction: {}", e))?;
+
+    for ((input, coin), prevout) in psbt.inputs.iter_mut().zip(&inputs).zip(prevouts) {
+        let public_key = btcaccount::public_key_at(&xpub, coin.chain, coin.index)?;
+        if address_type.address(&secp, &public_key).to_string() != coin.address {
+            return Err("Account xpub does not match the wallet's addresses".to_string());
+        }
+        if address_type != AddressType::Taproot {
+            let funding = previous.iter()
+                .find(|tx| tx.compute_txid() == coin.utxo.outpoint.txid)
+                .ok_or_else(|| format!("Funding transaction {} is missing", coin.utxo.outpoint.txid))?;
+            input.non_witness_utxo = Some(funding.clone());
+        }
+        if address_type != AddressType::Legacy {
+            input.witness_utxo = Some(prevout);
+        }
+        input_origin(input, address_type, &public_key, account.key_origin(coin.chain, coin.index)?);
+    }
+
+    // Change carries its origin too, so the offline signer can tell it apart and check it
+    if let Some(change) = change {
+        let script = change.script_pubkey();
+        let position = psbt.unsigned_tx.output.iter().position(|output| output.script_pubkey == script);
+        if let Some(position) = position {
+            let public_key = btcaccount::public_key_at(&xpub, KeyChain::Change, change_index)?;
+            let origin = account.key_origin(KeyChain::Change, change_index)?;
+            output_origin(&mut psbt.outputs[position], address_type, &public_key, origin);
+        }
+    }
+
+    Ok(psbt)
+}
+
+pub fn summarize(psbt: &Psbt) -> Result<Summary, String> {
+    let funding = psbt.iter_funding_utxos()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("PSBT is missing a previous output: {}", e))?;
+
+    let inputs = psbt.unsigned_tx.input.iter().zip(&psbt.inputs).zip(funding)
+        .map(|((txin, input), utxo)| PsbtInput {
+            outpoint: txin.previous_output,
+            address: address_of(&utxo.script_pubkey),
+            value: utxo.value,
+            signed: input.final_script_sig.is_some()
+                || input.final_script_witness.is_some()
+                || !input.partial_sigs.is_empty()
+                || input.tap_key_sig.is_some(),
+        })
+        .collect();
+
+    let outputs = psbt.unsigned_tx.output.iter().zip(&psbt.outputs)
+        .map(|(txout, output)| PsbtOutput {
+            address: address_of(&txout.script_pubkey),
+            value: txout.value,
+            change: !output.bip32_derivation.is_empty() || !output.tap_key_origins.is_empty(),
+        })
+        .collect();
+
+    let fee = psbt.fee().map_err(|e| format!("Invalid PSBT amounts: {}", e))?;
+    Ok(Summary { txid: psbt.unsigned_tx.compute_txid(), inputs, outputs, fee })
+}
+
+// Public key at `path` below `master`, with the derived private key erased
+fn derive_public<C: Signing>(secp: &Secp256k1<C>, master: &Xpriv, path: &DerivationPath) -> Result<PublicKey, String> {
+    let mut child = master.derive_priv(secp, path).map_err(|e| format!("Derivation failed: {}", e))?;
+    let public_key = child.private_key.public_key(secp);
+    child.private_key.non_secure_erase();
+    Ok(public_key)
+}
+
+// Origins exported without a fingerprint become ours when the path derives the listed key
+fn claim_origins<C: Signing>(secp: &Secp256k1<C>, psbt: &mut Psbt, master: &Xpriv, fingerprint: Fingerprint) {
+    let unknown = Fingerprint::default();
+    for input in psbt.inputs.iter_mut() {
+        for (public_key, (origin, path)) in input.bip32_derivation.iter_mut() {
+            if *origin == unknown && derive_public(secp, master, path).ok() == Some(*public_key) {
+                *origin = fingerprint;
+            }
+        }
+        for (internal_key, (_, (origin, path))) in input.tap_key_origins.iter_mut() {
+            if *origin == unknown && derive_public(secp, master, path).ok().map(|k| k.x_only_public_key().0) == Some(*internal_key) {
+                *origin = fingerprint;
+            }
+        }
+    }
+    for output in psbt.outputs.iter_mut() {
+        for (public_key, (origin, path)) in output.bip32_derivation.iter_mut() {
+            if *origin == unknown && derive_public(secp, master, path).ok() == Some(*public_key) {
+                *origin = fingerprint;
+            }
+        }
+        for (internal_key, (_, (origin, path))) in output.tap_key_origins.iter_mut() {
+            if *origin == unknown && derive_public(secp, master, path).ok().map(|k| k.x_only_public_key().0) == Some(*internal_key) {
+                *origin = fingerprint;
+            }
+        }
+    }
+}
+
+// Every output shown as change must be paid to a key this seed holds
+fn verify_change<C: Signing>(secp: &Secp256k1<C>, psbt: &Psbt, master: &Xpriv, fingerprint: Fingerprint) -> Result<(), String> {
+    let verifier = Secp256k1::verification_only();
+    for (index, (txout, output)) in psbt.unsigned_tx.output.iter().zip(&psbt.outputs).enumerate() {
+        let origins = output.bip32_derivation.values()
+            .chain(output.tap_key_origins.values().map(|(_, origin)| origin));
+        for (origin, path) in origins {
+            let pays_us = *origin == fingerprint && {
+                let public_key = CompressedPublicKey(derive_public(secp, master, path)?);
+                AddressType::ALL.iter().any(|t| t.address(&verifier, &public_key).script_pubkey() == txout.script_pubkey)
+            };
+            if !pays_us {
+                return Err(format!("Output {} is marked as change but does not pay this wallet", index));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Every input but a Taproot one must carry its funding transaction, and a witness UTXO must be
+// the output of it the input spends. Otherwise an online install could understate an input's
+// amount and have the fee paid twice over (CVE-2020-14199).
+fn verify_previous(psbt: &Psbt) -> Result<(), String> {
+    for (index, (txin, input)) in psbt.unsigned_tx.input.iter().zip(&psbt.inputs).enumerate() {
+        let outpoint = txin.previous_output;
+        let Some(funding) = &input.non_witness_utxo else {
+            match &input.witness_utxo {
+                Some(utxo) if utxo.script_pubkey.is_p2tr() => continue,
+                _ => return Err(format!("Input {} is missing its funding transaction", index)),
+            }
+        };
+        if funding.compute_txid() != outpoint.txid {
+            return Err(format!("Input {} carries the wrong funding transaction", index));
+        }
+        let spent = funding.output.get(outpoint.vout as usize)
+            .ok_or_else(|| format!("Input {} spends an output its funding transaction lacks", index))?;
+        if input.witness_utxo.as_ref().is_some_and(|utxo| utxo != spent) {
+            return Err(format!("Input {} witness UTXO does not match its funding transaction", index));
+        }
+    }
+    Ok(())
+}
+
+/// Adds a signature to every input of `psbt`, which must all belong to the wallet of `seed`.
+pub fn sign(seed: &[u8; 64], psbt: &mut Psbt) -> Result<(), String> {
+    verify_previous(psbt)?;
+    let secp = Secp256k1::new();
+    let mut master = Xpriv::new_master(Network::Bitcoin, seed)
+        .map_err(|e| format!("Failed to create master key: {}", e))?;
+    let fingerprint = master.fingerprint(&secp);
+
+    claim_origins(&secp, psbt, &master, fingerprint);
+    // A PSBT from another wallet names no key of this seed
+    let ours = psbt.inputs.iter().all(|input| {
+        input.bip32_derivation.values().any(|(origin, _)| *origin == fingerprint)
+            || input.tap_key_origins.values().any(|(_, (origin, _))| *origin == fingerprint)
+    });
+    if !ours {
+        master.private_key.non_secure_erase();
+        return Err(super::mismatch());
+    }
+    let result = verify_change(&secp, psbt, &master, fingerprint)
+        .and_then(|_| psbt.sign(&master, &secp).map_err(|(_, errors)| {
+            errors.iter().next()
+                .map(|(index, e)| format!("Input {} could not be signed: {}", index, e))
+                .unwrap_or_default()
+        }));
+    master.private_key.non_secure_erase();
+
+    // Origins can name a key of this seed the PSBT's scripts do not use
+    let used = result?;
+    let all_signed = (0..psbt.inputs.len()).all(|index| match used.get(&index) {
+        Some(SigningKeys::Ecdsa(keys)) => !keys.is_empty(),
+        Some(SigningKeys::Schnorr(keys)) => !keys.is_empty(),
+        None => false,
+    });
+    if !all_signed {
+        return Err(super::mismatch());
+    }
+    Ok(())
+}
+
+/// Turns a fully signed PSBT into the transaction to broadcast.
+pub fn finalize(mut psbt: Psbt) -> Result<SignedTx, String> {
+    let scripts = psbt.iter_funding_utxos()
+        .map(|utxo| utxo.map(|txout| txout.script_pubkey.clone()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("PSBT is missing a previous output: {}", e))?;
+
+    for (index, (input, script)) in psbt.inputs.iter_mut().zip(scripts).enumerate() {
+        let unsigned = || format!("Input {} is not signed", index);
+        let (script_sig, witness) = if script.is_p2tr() {
+            let signature = input.tap_key_sig.ok_or_else(unsigned)?;
+            (None, Some(Witness::p2tr_key_spend(&signature)))
+        } else {
+            let (public_key, signature) = input.partial_sigs.iter().next().ok_or_else(unsigned)?;
+            if script.is_p2pkh() {
+                let script_sig = ScriptBuf::builder()
+                    .push_slice(signature.serialize())
+                    .push_key(public_key)
+                    .into_script();
+                (Some(script_sig), None)
+            } else if script.is_p2wpkh() {
+                (None, Some(Witness::p2wpkh(signature, &public_key.inner)))
+            } else if script.is_p2sh() {
+                let redeem_script = input.redeem_script.clone()
+                    .ok_or_else(|| format!("Input {} has no redeem script", index))?;
+                let redeem_script = PushBytesBuf::try_from(redeem_script.into_bytes())
+                    .map_err(|e| format!("Invalid redeem script: {}", e))?;
+                let script_sig = ScriptBuf::builder().push_slice(redeem_script).into_script();
+                (Some(script_sig), Some(Witness::p2wpkh(signature, &public_key.inner)))
+            } else {
+                return Err(format!("Input {} has an unsupported script type", index));
+            }
+        };
+
+        // A finalized input keeps only what the transaction needs
+        *input = Input {
+            non_witness_utxo: input.non_witness_utxo.take(),
+            witness_utxo: input.witness_utxo.take(),
+            final_script_sig: script_sig,
+            final_script_witness: witness,
+            ..Input::default()
+        };
+    }
+
+    let tx = psbt.extract_tx().map_err(|e| format!("Could not extract transaction: {}", e))?;
+    Ok(SignedTx { blob: serialize_hex(&tx), hash: tx.compute_txid().to_string() })
+}
+
+pub fn to_base64(psbt: &Psbt) -> String {
+    BASE64.encode(psbt.serialize())
+}
+
+/// Reads a PSBT in the binary encoding or as base64 text, however it was delivered.
+pub fn decode(data: &[u8]) -> Result<Psbt, String> {
+    let bytes = if data.starts_with(MAGIC) {
+        data.to_vec()
+    } else {
+        let text: String = String::from_utf8_lossy(data).split_whitespace().collect();
+        BASE64.decode(text).map_err(|_| "Not a PSBT: expected a .psbt file or base64 text".to_string())?
+    };
+    Psbt::deserialize(&bytes).map_err(|e| format!("Invalid PSBT: {}", e))
+}
+
+pub fn read_file(path: &Path) -> Result<Psbt, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Could not read PSBT: {}", e))?;
+    decode(&data)
+}
+
+/// Refuses to replace an existing file, e.g. the unsigned PSBT a signed one is saved next to.
+pub fn write_file(path: &Path, psbt: &Psbt) -> Result<(), String> {
+    let mut file = json_storage::create_new_file(path, 0o644).map_err(|e| match e.kind() {
+        io::ErrorKind::AlreadyExists => format!("{} already exists. Choose another file name", path.display()),
+        _ => format!("Could not write PSBT: {}", e),
+    })?;
+    file.write_all(&psbt.serialize())
+        .and_then(|_| file.sync_all())
+        .map_err(|e| format!("Could not write PSBT: {}", e))
+}
+
+/// Suggested location: ~/dannesk-<txid prefix>-<unsigned|signed>.psbt
+pub fn default_path(summary: &Summary) -> String {
+    let txid = summary.txid.to_string();
+    let state = if summary.fully_signed() { "signed" } else { "unsigned" };
+    let name = format!("dannesk-{}-{}.{}", &txid[..8], state, PSBT_EXTENSION);
+    dirs::home_dir()
+        .map(|home| home.join(&name))
+        .unwrap_or_else(|| PathBuf::from(&name))
+        .to_string_lossy()
+        .to_string()
+}
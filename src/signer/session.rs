@@ -0,0 +1,154 @@
+// src/signer/session.rs
+//
+// Opt-in signing session. After a successful signature the chain's BIP39 seed can stay in locked
+// memory so the next transactions skip the passphrase, until the chosen window runs out, the
+// transaction budget is spent, the user ends it, or the app locks or exits. Ending a session
+// drops its seed, which wipes it.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use crate::audit::{self, AuditEvent};
+use crate::channel::{CHANNEL, SigningSession};
+use crate::clipboard::AddressChain;
+use crate::secmem::SecretBytes;
+
+pub const MINUTE_CHOICES: [u64; 3] = [5, 15, 30];
+
+/// Transactions a session may sign after the one that opened it; None is no limit.
+pub const TRANSACTION_CHOICES: [Option<u32>; 4] = [None, Some(3), Some(10), Some(25)];
+
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a session stays open, whichever limit is reached first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionLimits {
+    pub minutes: u64,
+    pub transactions: Option<u32>,
+}
+
+struct Session {
+    chain: AddressChain,
+    seed: SecretBytes,
+    expires: Instant,
+    remaining: Option<u32>,
+}
+
+static SESSIONS: Mutex<Vec<Session>> = Mutex::new(Vec::new());
+
+fn sessions() -> MutexGuard<'static, Vec<Session>> {
+    SESSIONS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn copy_of(seed: &SecretBytes) -> SecretBytes {
+    let mut copy = SecretBytes::zeroed(seed.len());
+    copy.as_mut_slice().copy_from_slice(seed);
+    copy
+}
+
+// The UI only ever sees which chains are open and until when
+fn publish(list: &[Session]) {
+    let _ = CHANNEL.signing_sessions_tx.send(list.iter().map(|s| SigningSession {
+        chain: s.chain,
+        expires: s.expires,
+        remaining: s.remaining,
+    }).collect());
+}
+
+// Removes the session at `index` and returns its chain. The end is recorded by the caller
+// once the lock is released, since recording writes and syncs the audit log.
+fn end_at(list: &mut Vec<Session>, index: usize) -> AddressChain {
+    let session = list.remove(index);
+    publish(list);
+    session.chain
+}
+
+fn record_ended(ended: &[AddressChain], reason: &str) {
+    for chain in ended {
+        audit::record(AuditEvent::SigningSessionEnded, format!("{} {}", chain.label(), reason));
+    }
+}
+
+/// Keeps a copy of `seed` for `chain`, replacing any session already open for it.
+pub(super) fn start(chain: AddressChain, seed: &SecretBytes, limits: SessionLimits) {
+    if limits.transactions == Some(0) {
+        return;
+    }
+
+    {
+        let mut list = sessions();
+        list.retain(|s| s.chain != chain);
+        list.push(Session {
+            chain,
+            seed: copy_of(seed),
+            expires: Instant::now() + Duration::from_secs(limits.minutes * 60),
+            remaining: limits.transactions,
+        });
+        publish(&list);
+    }
+
+    let budget = limits.transactions.map(|n| format!(", {} tx", n)).unwrap_or_default();
+    audit::record(AuditEvent::SigningSessionStarted, format!("{} for {} min{}", chain.label(), limits.minutes, budget));
+}
+
+/// A copy of `chain`'s seed for one signature. It only counts against the session's budget
+/// once `used` reports the signature was made.
+pub(super) fn take(chain: AddressChain) -> Option<SecretBytes> {
+    let mut list = sessions();
+    let index = list.iter().position(|s| s.chain == chain)?;
+    if list[index].expires > Instant::now() {
+        return Some(copy_of(&list[index].seed));
+    }
+    let ended = end_at(&mut list, index);
+    drop(list);
+    record_ended(&[ended], "expired");
+    None
+}
+
+/// Counts a signature made with `chain`'s session seed, ending the session once its budget
+/// is spent.
+pub(super) fn used(chain: AddressChain) {
+    let mut list = sessions();
+    let Some(index) = list.iter().position(|s| s.chain == chain) else { return };
+    let session = &mut list[index];
+    session.remaining = session.remaining.map(|n| n.saturating_sub(1));
+    if session.remaining != Some(0) {
+        publish(&list);
+        return;
+    }
+    let ended = end_at(&mut list, index);
+    drop(list);
+    record_ended(&[ended], "transaction limit reached");
+}
+
+pub fn is_active(chain: AddressChain) -> bool {
+    sessions().iter().any(|s| s.chain == chain && s.expires > Instant::now())
+}
+
+/// Ends every open session. Called for "end session now", on lock and at exit.
+pub fn end_all(reason: &str) {
+    let ended: Vec<AddressChain> = {
+        let mut list = sessions();
+        let chains = list.iter().map(|s| s.chain).collect();
+        list.clear();
+        publish(&list);
+        chains
+    };
+    record_ended(&ended, reason);
+}
+
+/// Wipes sessions as soon as their window closes, not just on the next signing attempt.
+pub async fn run_expiry_monitor() {
+    loop {
+        tokio::time::sleep(EXPIRY_CHECK_INTERVAL).await;
+        let ended: Vec<AddressChain> = {
+            let mut list = sessions();
+            let now = Instant::now();
+            let mut ended = Vec::new();
+            while let Some(index) = list.iter().position(|s| s.expires <= now) {
+                ended.push(end_at(&mut list, index));
+            }
+            ended
+        };
+        record_ended(&ended, "expired");
+    }
+}
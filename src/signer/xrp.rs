@@ -0,0 +1,176 @@
+// src/signer/xrp.rs
+//
+// XRPL transactions are assembled as rippled JSON, serialized with the binary codec and signed
+// with the Ed25519 key of the wallet's BIP39 seed.
+
+use bitcoin::hashes::{sha512, Hash};
+use bitcoin::hex::{DisplayHex, FromHex};
+use serde_json::{json, Map, Value};
+use xrpl::core::binarycodec::{encode, encode_for_signing};
+use xrpl::core::keypairs;
+use zeroize::Zeroize;
+use crate::chain::xrpl::AccountState;
+use super::{issuers, SignedTx};
+
+// Prefix rippled hashes a signed transaction under ("TXN\0")
+const TXN_PREFIX: [u8; 4] = [0x54, 0x58, 0x4E, 0x00];
+
+const TF_SET_NO_RIPPLE: u32 = 0x0002_0000;
+
+/// The transactions the wallet signs. Values are decimal strings as the forms collected them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum XrpTx {
+    Payment { destination: String, value: String, asset: String },
+    TrustSet { asset: String, limit: String },
+    /// `taker_pays` / `taker_gets` are (value, asset) as in `WSCommand`.
+    OfferCreate { taker_pays: (String, String), taker_gets: (String, String), flags: Vec<String> },
+}
+
+impl XrpTx {
+    fn fields(&self) -> Result<Map<String, Value>, String> {
+        let mut fields = Map::new();
+        match self {
+            XrpTx::Payment { destination, value, asset } => {
+                fields.insert("TransactionType".into(), json!("Payment"));
+                fields.insert("Destination".into(), json!(destination));
+                fields.insert("Amount".into(), amount(value, asset)?);
+                fields.insert("Flags".into(), json!(0));
+            }
+            XrpTx::TrustSet { asset, limit } => {
+                if asset.eq_ignore_ascii_case("XRP") {
+                    return Err("XRP does not need a trustline".to_string());
+                }
+                fields.insert("TransactionType".into(), json!("TrustSet"));
+                fields.insert("LimitAmount".into(), amount(limit, asset)?);
+                fields.insert("Flags".into(), json!(TF_SET_NO_RIPPLE));
+            }
+            XrpTx::OfferCreate { taker_pays, taker_gets, flags } => {
+                fields.insert("TransactionType".into(), json!("OfferCreate"));
+                fields.insert("TakerPays".into(), amount(&taker_pays.0, &taker_pays.1)?);
+                fields.insert("TakerGets".into(), amount(&taker_gets.0, &taker_gets.1)?);
+                fields.insert("Flags".into(), json!(offer_flags(flags)?));
+            }
+        }
+        Ok(fields)
+    }
+}
+
+fn offer_flags(names: &[String]) -> Result<u32, String> {
+    names.iter().try_fold(0u32, |acc, name| {
+        let bit = match name.as_str() {
+            "tfPassive" => 0x0001_0000,
+            "tfImmediateOrCancel" => 0x0002_0000,
+            "tfFillOrKill" => 0x0004_0000,
+            "tfSell" => 0x0008_0000,
+            other => return Err(format!("Unknown offer flag {}", other)),
+        };
+        Ok(acc | bit)
+    })
+}
+
+fn positive(value: &str) -> Result<f64, String> {
+    match value.trim().parse::<f64>() {
+        Ok(v) if v.is_finite() && v > 0.0 => Ok(v),
+        _ => Err(format!("Invalid amount: {}", value)),
+    }
+}
+
+// Drops in one XRP, and the most XRP that will ever exist
+const DROPS_PER_XRP: u64 = 1_000_000;
+const MAX_XRP: u64 = 100_000_000_000;
+
+// Decimal XRP to whole drops, digit by digit so no value is rounded on the way
+fn drops(value: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid amount: {}", value);
+    let (whole, fraction) = value.trim().split_once('.').unwrap_or((value.trim(), ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    if fraction.len() > 6 {
+        return Err(format!("XRP has at most 6 decimals: {}", value));
+    }
+
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };
+    if whole > MAX_XRP {
+        return Err(invalid());
+    }
+    let fraction: u64 = format!("{:0<6}", fraction).parse().map_err(|_| invalid())?;
+    let drops = whole * DROPS_PER_XRP + fraction;
+    if drops == 0 {
+        return Err(format!("Amount below one drop: {}", value));
+    }
+    Ok(drops)
+}
+
+// XRP amounts are whole drops as a string; tokens are {currency, issuer, value}
+fn amount(value: &str, asset: &str) -> Result<Value, String> {
+    if asset.eq_ignore_ascii_case("XRP") {
+        return Ok(json!(drops(value)?.to_string()));
+    }
+
+    let v = positive(value)?;
+    let issuer = issuers::account(asset)?;
+    Ok(json!({ "currency": currency_code(asset), "issuer": issuer, "value": token_value(v) }))
+}
+
+// Three-letter codes are used as-is, longer symbols as 20 bytes of zero-padded hex
+fn currency_code(symbol: &str) -> String {
+    let symbol = symbol.to_uppercase();
+    if symbol.len() == 3 {
+        return symbol;
+    }
+    let mut bytes = [0u8; 20];
+    bytes[..symbol.len().min(20)].copy_from_slice(&symbol.as_bytes()[..symbol.len().min(20)]);
+    bytes.to_upper_hex_string()
+}
+
+// Token amounts carry at most 15 significant digits; f64 products like 10.2 * 3 have more
+fn token_value(v: f64) -> String {
+    let decimals = (14 - v.log10().floor() as i32).clamp(0, 80) as usize;
+    let text = format!("{:.*}", decimals, v);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+/// Builds `tx` for `account` with the autofilled `state` and signs it with the key of `seed`.
+pub fn sign(seed: &[u8; 64], account: &str, tx: &XrpTx, state: &AccountState) -> Result<SignedTx, String> {
+    let mut fields = tx.fields()?;
+
+    let mut wallet = crate::master::xrp_wallet(seed)?;
+    wallet.seed.zeroize();
+    if wallet.classic_address != account {
+        wallet.private_key.zeroize();
+        return Err(super::mismatch());
+    }
+
+    fields.insert("Account".into(), json!(account));
+    fields.insert("Fee".into(), json!(state.fee_drops.to_string()));
+    fields.insert("Sequence".into(), json!(state.sequence));
+    fields.insert("LastLedgerSequence".into(), json!(state.last_ledger_sequence));
+    fields.insert("SigningPubKey".into(), json!(wallet.public_key.to_uppercase()));
+    let mut tx_json = Value::Object(fields);
+
+    let signature = encode_for_signing(&tx_json)
+        .map_err(|e| format!("Serialization failed: {}", e))
+        .and_then(|hex| Vec::<u8>::from_hex(&hex).map_err(|e| format!("Serialization failed: {}", e)))
+        .and_then(|message| keypairs::sign(&message, &wallet.private_key).map_err(|e| format!("Signing failed: {}", e)));
+    wallet.private_key.zeroize();
+
+    tx_json["TxnSignature"] = json!(signature?.to_uppercase());
+    let blob = encode(&tx_json).map_err(|e| format!("Serialization failed: {}", e))?.to_uppercase();
+    let hash = transaction_hash(&blob)?;
+    Ok(SignedTx { blob, hash })
+}
+
+// SHA-512Half of the prefixed blob, the id rippled and explorers show
+fn transaction_hash(blob: &str) -> Result<String, String> {
+    let mut preimage = TXN_PREFIX.to_vec();
+    preimage.extend(Vec::<u8>::from_hex(blob).map_err(|e| format!("Invalid blob: {}", e))?);
+    let digest = sha512::Hash::hash(&preimage);
+    Ok(digest.as_byte_array()[..32].to_upper_hex_string())
+}
@@ -0,0 +1,579 @@
+// src/slip39/mod.rs
+//
+// SLIP-39 Shamir backup: splits a master secret into groups of mnemonic shares and combines
+// enough of them back into the secret. Implements the published spec (RS1024 checksum,
+// 4-round Feistel encryption with PBKDF2-HMAC-SHA256, GF(256) interpolation with a digest
+// share), so shares interoperate with other SLIP-39 tools.
+//
+// Dannesk wallets are derived from a BIP39 mnemonic, so the master secret we split is the
+// BIP39 entropy. Recovery rebuilds the same mnemonic and the normal import flow takes over.
+
+mod wordlist;
+#[cfg(test)]
+mod tests;
+
+use bip39::{Language, Mnemonic};
+use bitcoin::hashes::{hmac, sha256, Hash, HashEngine};
+use rand::{rng, Rng};
+use zeroize::Zeroizing;
+use self::wordlist::WORDLIST;
+
+const RADIX_BITS: usize = 10;
+const ID_BITS: usize = 15;
+const ITERATION_EXP_BITS: usize = 4;
+const CHECKSUM_WORDS: usize = 3;
+const METADATA_WORDS: usize = 4 + CHECKSUM_WORDS;
+const MIN_STRENGTH_BYTES: usize = 16;
+const MIN_MNEMONIC_WORDS: usize = METADATA_WORDS + (MIN_STRENGTH_BYTES * 8).div_ceil(RADIX_BITS);
+const MAX_SHARE_COUNT: usize = 16;
+const DIGEST_LENGTH: usize = 4;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+const BASE_ITERATION_COUNT: u32 = 10000;
+const ROUND_COUNT: u8 = 4;
+
+/// Iteration exponent used for new shares; each step doubles the PBKDF2 work.
+pub const DEFAULT_ITERATION_EXPONENT: u8 = 1;
+
+/// Member threshold and count for one group, e.g. 2-of-3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupSpec {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+/// A decoded share. Indices are zero-based, thresholds and counts are the real values.
+#[derive(Debug, Clone)]
+pub struct Share {
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    fn shares_params_with(&self, other: &Share) -> bool {
+        self.identifier == other.identifier
+            && self.extendable == other.extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+            && self.value.len() == other.value.len()
+    }
+}
+
+// --- RS1024 checksum ---
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable { b"shamir_extendable" } else { b"shamir" }
+}
+
+fn rs1024_polymod(values: impl IntoIterator<Item = u32>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009,
+        0x1C0C2412, 0x38086C24, 0x3090FC48, 0x21B1F890, 0x3F3F120,
+    ];
+    let mut chk: u32 = 1;
+    for v in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xFFFFF) << 10) ^ v;
+        for (i, g) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_checksum(data: &[u16], extendable: bool) -> [u16; CHECKSUM_WORDS] {
+    let values = customization(extendable).iter().map(|&c| c as u32)
+        .chain(data.iter().map(|&w| w as u32))
+        .chain([0; CHECKSUM_WORDS]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [
+        ((polymod >> 20) & 1023) as u16,
+        ((polymod >> 10) & 1023) as u16,
+        (polymod & 1023) as u16,
+    ]
+}
+
+fn rs1024_verify(data: &[u16], extendable: bool) -> bool {
+    let values = customization(extendable).iter().map(|&c| c as u32)
+        .chain(data.iter().map(|&w| w as u32));
+    rs1024_polymod(values) == 1
+}
+
+// --- Encryption (Feistel network over the master secret) ---
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut engine = hmac::HmacEngine::<sha256::Hash>::new(key);
+    engine.input(data);
+    hmac::Hmac::<sha256::Hash>::from_engine(engine).to_byte_array()
+}
+
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    let keyed = hmac::HmacEngine::<sha256::Hash>::new(password);
+    for (block, chunk) in out.chunks_mut(32).enumerate() {
+        let mut engine = keyed.clone();
+        engine.input(salt);
+        engine.input(&(block as u32 + 1).to_be_bytes());
+        let mut u = hmac::Hmac::<sha256::Hash>::from_engine(engine).to_byte_array();
+        let mut t = u;
+        for _ in 1..iterations {
+            let mut engine = keyed.clone();
+            engine.input(&u);
+            u = hmac::Hmac::<sha256::Hash>::from_engine(engine).to_byte_array();
+            t.iter_mut().zip(u.iter()).for_each(|(a, b)| *a ^= b);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
+fn round_function(round: u8, passphrase: &[u8], exponent: u8, salt: &[u8], r: &[u8]) -> Zeroizing<Vec<u8>> {
+    let mut password = Zeroizing::new(Vec::with_capacity(passphrase.len() + 1));
+    password.push(round);
+    password.extend_from_slice(passphrase);
+
+    let mut full_salt = salt.to_vec();
+    full_salt.extend_from_slice(r);
+
+    let iterations = (BASE_ITERATION_COUNT << exponent) / ROUND_COUNT as u32;
+    let mut out = Zeroizing::new(vec![0u8; r.len()]);
+    pbkdf2_sha256(&password, &full_salt, iterations, &mut out);
+    out
+}
+
+fn salt_prefix(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        Vec::new()
+    } else {
+        let mut salt = b"shamir".to_vec();
+        salt.extend_from_slice(&identifier.to_be_bytes());
+        salt
+    }
+}
+
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    rounds: impl Iterator<Item = u8>,
+) -> Zeroizing<Vec<u8>> {
+    let half = input.len() / 2;
+    let mut l = Zeroizing::new(input[..half].to_vec());
+    let mut r = Zeroizing::new(input[half..].to_vec());
+    let salt = salt_prefix(identifier, extendable);
+
+    for round in rounds {
+        let f = round_function(round, passphrase, exponent, &salt, &r);
+        let next_r: Vec<u8> = l.iter().zip(f.iter()).map(|(a, b)| a ^ b).collect();
+        l = r;
+        r = Zeroizing::new(next_r);
+    }
+
+    let mut out = Zeroizing::new(Vec::with_capacity(input.len()));
+    out.extend_from_slice(&r);
+    out.extend_from_slice(&l);
+    out
+}
+
+fn encrypt(secret: &[u8], passphrase: &[u8], exponent: u8, identifier: u16, extendable: bool) -> Zeroizing<Vec<u8>> {
+    feistel(secret, passphrase, exponent, identifier, extendable, 0..ROUND_COUNT)
+}
+
+fn decrypt(secret: &[u8], passphrase: &[u8], exponent: u8, identifier: u16, extendable: bool) -> Zeroizing<Vec<u8>> {
+    feistel(secret, passphrase, exponent, identifier, extendable, (0..ROUND_COUNT).rev())
+}
+
+// --- Shamir secret sharing over GF(256) ---
+
+type IndexedValue = (u8, Zeroizing<Vec<u8>>);
+
+struct Gf256 {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+impl Gf256 {
+    fn new() -> Self {
+        let mut exp = [0u8; 255];
+        let mut log = [0u8; 256];
+        let mut poly: u16 = 1;
+        for (i, e) in exp.iter_mut().enumerate() {
+            *e = poly as u8;
+            log[poly as usize] = i as u8;
+            // Multiply by the generator x + 1, reducing by the Rijndael polynomial
+            poly = (poly << 1) ^ poly;
+            if poly & 0x100 != 0 {
+                poly ^= 0x11B;
+            }
+        }
+        Gf256 { exp, log }
+    }
+
+    /// Lagrange interpolation of `shares` evaluated at `x`.
+    fn interpolate(&self, shares: &[(u8, &[u8])], x: u8) -> Result<Zeroizing<Vec<u8>>, String> {
+        if let Some((_, value)) = shares.iter().find(|(sx, _)| *sx == x) {
+            return Ok(Zeroizing::new(value.to_vec()));
+        }
+        let len = shares.first().map(|(_, v)| v.len()).ok_or("No shares to interpolate")?;
+        if shares.iter().any(|(_, v)| v.len() != len) {
+            return Err("Share values have different lengths".to_string());
+        }
+
+        let log_prod: i32 = shares.iter().map(|(sx, _)| self.log[(sx ^ x) as usize] as i32).sum();
+        let mut result = Zeroizing::new(vec![0u8; len]);
+
+        for (sx, value) in shares {
+            let others: i32 = shares.iter().map(|(ox, _)| self.log[(sx ^ ox) as usize] as i32).sum();
+            let log_basis = (log_prod - self.log[(sx ^ x) as usize] as i32 - others).rem_euclid(255);
+            for (out, &v) in result.iter_mut().zip(value.iter()) {
+                if v != 0 {
+                    *out ^= self.exp[((self.log[v as usize] as i32 + log_basis) % 255) as usize];
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn random_bytes(len: usize) -> Zeroizing<Vec<u8>> {
+    let mut bytes = Zeroizing::new(vec![0u8; len]);
+    rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+fn split_secret(gf: &Gf256, threshold: u8, count: u8, secret: &[u8]) -> Result<Vec<IndexedValue>, String> {
+    if threshold == 0 || threshold > count || count as usize > MAX_SHARE_COUNT {
+        return Err("Invalid threshold or share count".to_string());
+    }
+    if threshold == 1 {
+        return Ok((0..count).map(|i| (i, Zeroizing::new(secret.to_vec()))).collect());
+    }
+
+    let random_count = threshold - 2;
+    let mut shares: Vec<IndexedValue> = (0..random_count)
+        .map(|i| (i, random_bytes(secret.len())))
+        .collect();
+
+    let random_part = random_bytes(secret.len() - DIGEST_LENGTH);
+    let mut digest_share = Zeroizing::new(hmac_sha256(&random_part, secret)[..DIGEST_LENGTH].to_vec());
+    digest_share.extend_from_slice(&random_part);
+
+    let mut base: Vec<(u8, &[u8])> = shares.iter().map(|(i, v)| (*i, v.as_slice())).collect();
+    base.push((DIGEST_INDEX, &digest_share));
+    base.push((SECRET_INDEX, secret));
+
+    let mut derived = Vec::new();
+    for i in random_count..count {
+        derived.push((i, gf.interpolate(&base, i)?));
+    }
+    shares.extend(derived);
+    Ok(shares)
+}
+
+fn recover_secret(gf: &Gf256, threshold: u8, shares: &[(u8, &[u8])]) -> Result<Zeroizing<Vec<u8>>, String> {
+    if threshold == 1 {
+        return shares.first()
+            .map(|(_, v)| Zeroizing::new(v.to_vec()))
+            .ok_or_else(|| "No shares provided".to_string());
+    }
+
+    let secret = gf.interpolate(shares, SECRET_INDEX)?;
+    let digest_share = gf.interpolate(shares, DIGEST_INDEX)?;
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH);
+    if hmac_sha256(random_part, &secret)[..DIGEST_LENGTH] != *digest {
+        return Err("Share digest mismatch: shares do not belong together".to_string());
+    }
+    Ok(secret)
+}
+
+// --- Mnemonic encoding ---
+
+fn word_index(word: &str) -> Option<u16> {
+    let word = word.to_lowercase();
+    WORDLIST.binary_search(&word.as_str()).ok().map(|i| i as u16)
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u32, count: usize) {
+    for i in (0..count).rev() {
+        bits.push((value >> i) & 1 == 1);
+    }
+}
+
+fn bits_to_words(bits: &[bool]) -> Vec<u16> {
+    bits.chunks(RADIX_BITS)
+        .map(|chunk| chunk.iter().fold(0u16, |acc, &b| (acc << 1) | b as u16))
+        .collect()
+}
+
+fn encode_share(share: &Share) -> Zeroizing<String> {
+    let mut bits = Vec::new();
+    push_bits(&mut bits, share.identifier as u32, ID_BITS);
+    push_bits(&mut bits, share.extendable as u32, 1);
+    push_bits(&mut bits, share.iteration_exponent as u32, ITERATION_EXP_BITS);
+    push_bits(&mut bits, share.group_index as u32, 4);
+    push_bits(&mut bits, (share.group_threshold - 1) as u32, 4);
+    push_bits(&mut bits, (share.group_count - 1) as u32, 4);
+    push_bits(&mut bits, share.member_index as u32, 4);
+    push_bits(&mut bits, (share.member_threshold - 1) as u32, 4);
+
+    // The value is left-padded with zero bits to a whole number of words
+    let value_bits = share.value.len() * 8;
+    push_bits(&mut bits, 0, (RADIX_BITS - value_bits % RADIX_BITS) % RADIX_BITS);
+    for &byte in share.value.iter() {
+        push_bits(&mut bits, byte as u32, 8);
+    }
+
+    let mut words = bits_to_words(&bits);
+    bits.iter_mut().for_each(|b| *b = false);
+    let checksum = rs1024_checksum(&words, share.extendable);
+    words.extend_from_slice(&checksum);
+
+    let mnemonic = Zeroizing::new(
+        words.iter().map(|&w| WORDLIST[w as usize]).collect::<Vec<_>>().join(" "),
+    );
+    words.iter_mut().for_each(|w| *w = 0);
+    mnemonic
+}
+
+/// Parses and checksums one share mnemonic.
+pub fn decode_share(mnemonic: &str) -> Result<Share, String> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if words.len() < MIN_MNEMONIC_WORDS {
+        return Err(format!("Share must have at least {} words", MIN_MNEMONIC_WORDS));
+    }
+
+    let indices = words.iter()
+        .map(|w| word_index(w).ok_or_else(|| format!("'{}' is not a SLIP-39 word", w)))
+        .collect::<Result<Vec<u16>, String>>()?;
+
+    let id_exp = ((indices[0] as u32) << 10) | indices[1] as u32;
+    let identifier = (id_exp >> (1 + ITERATION_EXP_BITS)) as u16;
+    let extendable = (id_exp >> ITERATION_EXP_BITS) & 1 == 1;
+    let iteration_exponent = (id_exp & 0xF) as u8;
+
+    if !rs1024_verify(&indices, extendable) {
+        return Err("Invalid share checksum".to_string());
+    }
+
+    let params = ((indices[2] as u32) << 10) | indices[3] as u32;
+    let nibble = |shift: u32| ((params >> shift) & 0xF) as u8;
+    let group_index = nibble(16);
+    let group_threshold = nibble(12) + 1;
+    let group_count = nibble(8) + 1;
+    let member_index = nibble(4);
+    let member_threshold = nibble(0) + 1;
+
+    if group_threshold > group_count {
+        return Err("Group threshold exceeds group count".to_string());
+    }
+
+    let value_words = &indices[4..indices.len() - CHECKSUM_WORDS];
+    let padding = (RADIX_BITS * value_words.len()) % 16;
+    if padding > 8 {
+        return Err("Invalid share length".to_string());
+    }
+
+    let mut bits = Vec::with_capacity(value_words.len() * RADIX_BITS);
+    for &w in value_words {
+        push_bits(&mut bits, w as u32, RADIX_BITS);
+    }
+    if bits[..padding].iter().any(|&b| b) {
+        return Err("Invalid share padding".to_string());
+    }
+    let value = Zeroizing::new(
+        bits[padding..].chunks(8)
+            .map(|chunk| chunk.iter().fold(0u8, |acc, &b| (acc << 1) | b as u8))
+            .collect::<Vec<u8>>(),
+    );
+    bits.iter_mut().for_each(|b| *b = false);
+
+    Ok(Share {
+        identifier,
+        extendable,
+        iteration_exponent,
+        group_index,
+        group_threshold,
+        group_count,
+        member_index,
+        member_threshold,
+        value,
+    })
+}
+
+// --- Public API ---
+
+/// Splits `master_secret` into `groups` of shares, any `group_threshold` of which recover it.
+/// Returns one list of share mnemonics per group.
+pub fn generate(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    group_threshold: u8,
+    groups: &[GroupSpec],
+    iteration_exponent: u8,
+) -> Result<Vec<Vec<Zeroizing<String>>>, String> {
+    if master_secret.len() < MIN_STRENGTH_BYTES || !master_secret.len().is_multiple_of(2) {
+        return Err("Master secret must be at least 128 bits and an even number of bytes".to_string());
+    }
+    if iteration_exponent > 15 {
+        return Err("Iteration exponent must be at most 15".to_string());
+    }
+    if groups.is_empty() || groups.len() > MAX_SHARE_COUNT {
+        return Err(format!("Between 1 and {} groups are required", MAX_SHARE_COUNT));
+    }
+    if group_threshold == 0 || group_threshold as usize > groups.len() {
+        return Err("Group threshold must be between 1 and the number of groups".to_string());
+    }
+    for g in groups {
+        if g.member_threshold == 0 || g.member_threshold > g.member_count || g.member_count as usize > MAX_SHARE_COUNT {
+            return Err(format!("Invalid group {}-of-{}", g.member_threshold, g.member_count));
+        }
+        if g.member_threshold == 1 && g.member_count > 1 {
+            return Err("A group with threshold 1 must have exactly one share".to_string());
+        }
+    }
+
+    let mut id_bytes = [0u8; 2];
+    rng().fill_bytes(&mut id_bytes);
+    let identifier = u16::from_be_bytes(id_bytes) & ((1 << ID_BITS) - 1);
+    let extendable = true;
+
+    let gf = Gf256::new();
+    let encrypted = encrypt(master_secret, passphrase, iteration_exponent, identifier, extendable);
+    let group_secrets = split_secret(&gf, group_threshold, groups.len() as u8, &encrypted)?;
+
+    let mut result = Vec::with_capacity(groups.len());
+    for ((group_index, group_secret), spec) in group_secrets.iter().zip(groups) {
+        let members = split_secret(&gf, spec.member_threshold, spec.member_count, group_secret)?;
+        let mnemonics = members.into_iter()
+            .map(|(member_index, value)| encode_share(&Share {
+                identifier,
+                extendable,
+                iteration_exponent,
+                group_index: *group_index,
+                group_threshold,
+                group_count: groups.len() as u8,
+                member_index,
+                member_threshold: spec.member_threshold,
+                value,
+            }))
+            .collect();
+        result.push(mnemonics);
+    }
+    Ok(result)
+}
+
+/// Recovers the master secret from a set of share mnemonics.
+pub fn combine(mnemonics: &[&str], passphrase: &[u8]) -> Result<Zeroizing<Vec<u8>>, String> {
+    let shares = mnemonics.iter()
+        .enumerate()
+        .map(|(i, m)| decode_share(m).map_err(|e| format!("Share {}: {}", i + 1, e)))
+        .collect::<Result<Vec<Share>, String>>()?;
+
+    let first = shares.first().ok_or("No shares provided")?;
+    if shares.iter().any(|s| !s.shares_params_with(first)) {
+        return Err("Shares belong to different backups".to_string());
+    }
+
+    // Collect members per group, rejecting conflicting duplicates
+    let mut groups: Vec<(u8, u8, Vec<&Share>)> = Vec::new();
+    for share in &shares {
+        let group = match groups.iter_mut().find(|(index, _, _)| *index == share.group_index) {
+            Some(group) => group,
+            None => {
+                groups.push((share.group_index, share.member_threshold, Vec::new()));
+                groups.last_mut().expect("just pushed")
+            }
+        };
+        if group.1 != share.member_threshold {
+            return Err(format!("Group {} has inconsistent member thresholds", share.group_index + 1));
+        }
+        match group.2.iter().find(|s| s.member_index == share.member_index) {
+            Some(existing) if existing.value != share.value => {
+                return Err(format!("Group {} has conflicting copies of one share", share.group_index + 1));
+            }
+            Some(_) => {}
+            None => group.2.push(share),
+        }
+    }
+
+    let gf = Gf256::new();
+    let mut group_secrets: Vec<IndexedValue> = Vec::new();
+    for (index, threshold, members) in &groups {
+        if members.len() < *threshold as usize {
+            continue;
+        }
+        let points: Vec<(u8, &[u8])> = members.iter()
+            .take(*threshold as usize)
+            .map(|s| (s.member_index, s.value.as_slice()))
+            .collect();
+        group_secrets.push((*index, recover_secret(&gf, *threshold, &points)?));
+    }
+
+    if group_secrets.len() < first.group_threshold as usize {
+        return Err(format!(
+            "Need {} complete group(s), have {}",
+            first.group_threshold, group_secrets.len()
+        ));
+    }
+
+    let points: Vec<(u8, &[u8])> = group_secrets.iter()
+        .take(first.group_threshold as usize)
+        .map(|(i, v)| (*i, v.as_slice()))
+        .collect();
+    let encrypted = recover_secret(&gf, first.group_threshold, &points)?;
+
+    Ok(decrypt(&encrypted, passphrase, first.iteration_exponent, first.identifier, first.extendable))
+}
+
+/// True if `text` looks like SLIP-39 shares (one per line) rather than a BIP39 phrase.
+pub fn looks_like_shares(text: &str) -> bool {
+    let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    !lines.is_empty()
+        && lines.iter().all(|l| l.split_whitespace().count() >= MIN_MNEMONIC_WORDS)
+        && lines.iter().all(|l| l.split_whitespace().all(|w| word_index(w).is_some()))
+}
+
+/// Splits a BIP39 mnemonic's entropy into SLIP-39 shares.
+pub fn split_mnemonic(
+    mnemonic: &str,
+    group_threshold: u8,
+    groups: &[GroupSpec],
+) -> Result<Vec<Vec<Zeroizing<String>>>, String> {
+    let mnemonic = Mnemonic::parse_in(Language::English, mnemonic)
+        .map_err(|e| format!("Invalid mnemonic: {}", e))?;
+    let entropy = Zeroizing::new(mnemonic.to_entropy());
+    generate(&entropy, b"", group_threshold, groups, DEFAULT_ITERATION_EXPONENT)
+}
+
+/// Rebuilds the BIP39 mnemonic from newline-separated SLIP-39 shares.
+pub fn recover_mnemonic(shares: &str) -> Result<Zeroizing<String>, String> {
+    let lines: Vec<&str> = shares.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    let entropy = combine(&lines, b"")?;
+    let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
+        .map_err(|e| format!("Recovered secret is not a valid wallet seed: {}", e))?;
+    Ok(Zeroizing::new(mnemonic.to_string()))
+}
+
+/// Parses a group layout like "1/1, 2/3, 3/5" (threshold/count per group).
+pub fn parse_groups(spec: &str) -> Result<Vec<GroupSpec>, String> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (t, n) = part.split_once('/').ok_or_else(|| format!("'{}' is not threshold/count", part))?;
+            let member_threshold = t.trim().parse::<u8>().map_err(|_| format!("Bad threshold in '{}'", part))?;
+            let member_count = n.trim().parse::<u8>().map_err(|_| format!("Bad count in '{}'", part))?;
+            Ok(GroupSpec { member_threshold, member_count })
+        })
+        .collect()
+}
@@ -0,0 +1,233 @@
+// src/slip39/tests.rs
+//
+// Cases from the SLIP-39 reference vectors (vectors.json in trezor/python-shamir-mnemonic),
+// numbered as there. All of them are combined with the passphrase "TREZOR". The table below
+// pins the expected error text; `every_reference_vector` runs the vendored file unchanged.
+
+use bitcoin::bip32::Xpriv;
+use bitcoin::hex::DisplayHex;
+use bitcoin::Network;
+use super::*;
+
+enum Expect {
+    Secret(&'static str),
+    /// Combining fails, with an error containing this text.
+    Error(&'static str),
+}
+
+const PASSPHRASE: &[u8] = b"TREZOR";
+
+const VECTORS: &[(&str, &[&str], Expect)] = &[
+    (
+        "1. Valid mnemonic without sharing (128 bits)",
+        &["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard"],
+        Expect::Secret("bb54aac4b89dc868ba37d9cc21b2cece"),
+    ),
+    (
+        "2. Mnemonic with invalid checksum (128 bits)",
+        &["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney"],
+        Expect::Error("Invalid share checksum"),
+    ),
+    (
+        "3. Mnemonic with invalid padding (128 bits)",
+        &["duckling enlarge academic academic email result length solution fridge kidney coal piece deal husband erode duke ajar music cargo fitness"],
+        Expect::Error("Invalid share padding"),
+    ),
+    (
+        "4. Basic sharing 2-of-3 (128 bits)",
+        &[
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ],
+        Expect::Secret("b43ceb7e57a0ea8766221624d01b0864"),
+    ),
+    (
+        "5. Basic sharing 2-of-3 (128 bits), one share only",
+        &["shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed"],
+        Expect::Error("Need 1 complete group(s), have 0"),
+    ),
+    (
+        "6. Mnemonics with different identifiers (128 bits)",
+        &[
+            "adequate smoking academic acid debut wine petition glen cluster slow rhyme slow simple epidemic rumor junk tracks treat olympic tolerate",
+            "adequate stay academic agency agency formal party ting frequent learn upstairs remember smear leaf damage anatomy ladle market hush corner",
+        ],
+        Expect::Error("Shares belong to different backups"),
+    ),
+    (
+        "7. Mnemonics with different iteration exponents (128 bits)",
+        &[
+            "peasant leaves academic acid desert exact olympic math alive axle trial tackle drug deny decent smear dominant desert bucket remind",
+            "peasant leader academic agency cultural blessing percent network envelope medal junk primary human pumps jacket fragment payroll ticket evoke voice",
+        ],
+        Expect::Error("Shares belong to different backups"),
+    ),
+    (
+        "9. Mnemonics with mismatching group counts (128 bits)",
+        &[
+            "average senior academic leaf broken teacher expect surface hour capture obesity desire negative dynamic dominant pistol mineral mailman iris aide",
+            "average senior academic agency curious pants blimp spew clothes slice script dress wrap firm shaft regular slavery negative theater roster",
+        ],
+        Expect::Error("Shares belong to different backups"),
+    ),
+    (
+        "10. Mnemonics with greater group threshold than group counts (128 bits)",
+        &[
+            "music husband acrobat acid artist finance center either graduate swimming object bike medical clothes station aspect spider maiden bulb welcome",
+            "music husband acrobat agency advance hunting bike corner density careful material civil evil tactics remind hawk discuss hobo voice rainbow",
+            "music husband beard academic black tricycle clock mayor estimate level photo episode exclude ecology papa source amazing salt verify divorce",
+        ],
+        Expect::Error("Group threshold exceeds group count"),
+    ),
+    (
+        "11. Mnemonics with duplicate member indices (128 bits)",
+        &[
+            "device stay academic always dive coal antenna adult black exceed stadium herald advance soldier busy dryer daughter evaluate minister laser",
+            "device stay academic always dwarf afraid robin gravity crunch adjust soul branch walnut coastal dream costume scholar mortgage mountain pumps",
+        ],
+        Expect::Error("conflicting copies of one share"),
+    ),
+    (
+        "12. Mnemonics with mismatching member thresholds (128 bits)",
+        &[
+            "hour painting academic academic device formal evoke guitar random modern justice filter withdraw trouble identify mailman insect general cover oven",
+            "hour painting academic agency artist again daisy capital beaver fiber much enjoy suitable symbolic identify photo editor romp float echo",
+        ],
+        Expect::Error("inconsistent member thresholds"),
+    ),
+    (
+        "13. Mnemonics giving an invalid digest (128 bits)",
+        &[
+            "guilt walnut academic acid deliver remove equip listen vampire tactics nylon rhythm failure husband fatigue alive blind enemy teaspoon rebound",
+            "guilt walnut academic agency brave hamster hobo declare herd taste alpha slim criminal mild arcade formal romp branch pink ambition",
+        ],
+        Expect::Error("Share digest mismatch"),
+    ),
+    (
+        "14. Insufficient number of groups (128 bits, case 1)",
+        &["eraser senior beard romp adorn nuclear spill corner cradle style ancient family general leader ambition exchange unusual garlic promise voice"],
+        Expect::Error("Need 2 complete group(s), have 1"),
+    ),
+    (
+        "15. Insufficient number of groups (128 bits, case 2)",
+        &[
+            "eraser senior ceramic snake clay various huge numb argue hesitate auction category timber browser greatest hanger petition script leaf pickup",
+            "eraser senior ceramic shaft dynamic become junior wrist silver peasant force math alto coal amazing segment yelp velvet image paces",
+        ],
+        Expect::Error("Need 2 complete group(s), have 0"),
+    ),
+    (
+        "16. Threshold number of groups, but insufficient number of members in one group (128 bits)",
+        &[
+            "eraser senior decision shadow artist work morning estate greatest pipeline plan ting petition forget hormone flexible general goat admit surface",
+            "eraser senior beard romp adorn nuclear spill corner cradle style ancient family general leader ambition exchange unusual garlic promise voice",
+        ],
+        Expect::Error("Need 2 complete group(s), have 1"),
+    ),
+    (
+        "21. Valid mnemonic without sharing (256 bits)",
+        &["theory painting academic academic armed sweater year military elder discuss acne wildlife boring employer fused large satoshi bundle carbon diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel lips brave detect luck"],
+        Expect::Secret("989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92"),
+    ),
+    (
+        "40. Mnemonic with insufficient length",
+        &["junk necklace academic academic acne isolate join hesitate lunar roster dough calcium chemical ladybug amount mobile glasses verify cylinder"],
+        Expect::Error("at least 20 words"),
+    ),
+    (
+        "41. Mnemonic with invalid master secret length",
+        &["fraction necklace academic academic award teammate mouse regular testify coding building member verdict purchase blind camera duration email prepare spirit quarter"],
+        Expect::Error("Invalid share length"),
+    ),
+    (
+        "43. Valid extendable mnemonic without sharing (128 bits)",
+        &["testify swimming academic academic column loyalty smear include exotic bedroom exotic wrist lobe cover grief golden smart junior estimate learn"],
+        Expect::Secret("1679b4516e0ee5954351d288a838f45e"),
+    ),
+    (
+        "44. Extendable basic sharing 2-of-3 (128 bits)",
+        &[
+            "enemy favorite academic acid cowboy phrase havoc level response walnut budget painting inside trash adjust froth kitchen learn tidy punish",
+            "enemy favorite academic always academic sniff script carpet romp kind promise scatter center unfair training emphasis evening belong fake enforce",
+        ],
+        Expect::Secret("48b1a4b80b8c209ad42c33672bdaa428"),
+    ),
+    (
+        "45. Valid extendable mnemonic without sharing (256 bits)",
+        &["impulse calcium academic academic alcohol sugar lyrics pajamas column facility finance tension extend space birthday rainbow swimming purple syndrome facility trial warn duration snapshot shadow hormone rhyme public spine counter easy hawk album"],
+        Expect::Secret("8340611602fe91af634a5f4608377b5235fa2d757c51d720c0c7656249a3035f"),
+    ),
+];
+
+#[test]
+fn reference_vectors() {
+    for (description, mnemonics, expect) in VECTORS {
+        let result = combine(mnemonics, PASSPHRASE);
+        match expect {
+            Expect::Secret(secret) => match result {
+                Ok(recovered) => assert_eq!(recovered.to_lower_hex_string(), *secret, "{}", description),
+                Err(e) => panic!("{}: {}", description, e),
+            },
+            Expect::Error(text) => match result {
+                Ok(_) => panic!("{}: combined an invalid set", description),
+                Err(e) => assert!(e.contains(text), "{}: expected '{}', got '{}'", description, text, e),
+            },
+        }
+    }
+}
+
+// Each entry is [description, mnemonics, secret hex, BIP32 master xprv]; invalid sets have an
+// empty secret.
+const VECTORS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/slip39/vectors.json");
+
+#[test]
+fn every_reference_vector() {
+    let raw = std::fs::read_to_string(VECTORS_FILE)
+        .unwrap_or_else(|e| panic!("{} must be vendored from trezor/python-shamir-mnemonic: {}", VECTORS_FILE, e));
+    let vectors: Vec<(String, Vec<String>, String, String)> = serde_json::from_str(&raw).unwrap();
+    assert!(vectors.len() >= 45, "truncated vectors.json");
+
+    for (description, mnemonics, secret, xprv) in &vectors {
+        let mnemonics: Vec<&str> = mnemonics.iter().map(String::as_str).collect();
+        match combine(&mnemonics, PASSPHRASE) {
+            Ok(recovered) => {
+                assert_eq!(recovered.to_lower_hex_string(), *secret, "{}", description);
+                let master = Xpriv::new_master(Network::Bitcoin, &recovered).unwrap();
+                assert_eq!(master.to_string(), *xprv, "{}", description);
+            }
+            Err(e) => assert!(secret.is_empty(), "{}: {}", description, e),
+        }
+    }
+}
+
+#[test]
+fn extendable_flag_is_decoded() {
+    for (description, mnemonics, _) in VECTORS {
+        let extendable = description.to_lowercase().contains("extendable");
+        for mnemonic in mnemonics.iter() {
+            if let Ok(share) = decode_share(mnemonic) {
+                assert_eq!(share.extendable, extendable, "{}", description);
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_groups_combine() {
+    let secret = [7u8; 32];
+    let groups = parse_groups("1/1, 2/3, 3/5").unwrap();
+    let shares = generate(&secret, b"pw", 2, &groups, 0).unwrap();
+    let picks = [shares[1][0].as_str(), shares[2][4].as_str(), shares[1][2].as_str(), shares[2][1].as_str(), shares[2][0].as_str()];
+    assert_eq!(combine(&picks, b"pw").unwrap().as_slice(), &secret);
+    assert!(combine(&picks[..4], b"pw").is_err());
+}
+
+#[test]
+fn mnemonic_survives_split_and_recovery() {
+    let mnemonic = Mnemonic::from_entropy(&[3u8; 32]).unwrap().to_string();
+    let shares = split_mnemonic(&mnemonic, 1, &[GroupSpec { member_threshold: 2, member_count: 3 }]).unwrap();
+    let text = format!("{}\n{}\n", shares[0][2].as_str(), shares[0][0].as_str());
+    assert!(looks_like_shares(&text));
+    assert!(!looks_like_shares(&mnemonic));
+    assert_eq!(recover_mnemonic(&text).unwrap().as_str(), mnemonic);
+}
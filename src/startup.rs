@@ -1,4 +1,4 @@
-use crate::channel::CHANNEL;
+use crate::channel::{CHANNEL, ProgressState};
 use reqwest::Client;
 use serde::Deserialize;
 use std::time::Duration;
@@ -27,6 +27,23 @@ pub fn init_globals() {
 pub fn init_startup(handle: &tokio::runtime::Handle) {
     let handle_clone = handle.clone();
     
+    // Auto-lock watchers (idle timeout, desktop session lock)
+    handle_clone.spawn(crate::lock::run_idle_monitor());
+    handle_clone.spawn(crate::lock::run_session_lock_monitor());
+
+    // Wipes signing sessions the moment their window closes
+    handle_clone.spawn(crate::signer::session::run_expiry_monitor());
+
+    // First run sizes Argon2 for this machine, so no unlock has to wait for it
+    handle_clone.spawn_blocking(|| {
+        if let Err(e) = crate::keystore::ensure_calibrated() {
+            let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                progress: 1.0,
+                message: format!("Error: {}", e),
+            }));
+        }
+    });
+
     handle_clone.spawn(async move {
         match tokio::time::timeout(Duration::from_secs(5), fetch_version()).await {
             Ok(Ok(data)) => {
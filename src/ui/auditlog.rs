@@ -0,0 +1,151 @@
+use dioxus_native::prelude::*;
+use crate::audit::{self, AuditReport, Integrity};
+use crate::context::GlobalContext;
+use crate::channel::SideBarView;
+use crate::utils::styles::{terminal_action, previous_icon_button};
+
+fn status_label(status: &Integrity) -> String {
+    match status {
+        Integrity::Intact => "OK".to_string(),
+        Integrity::Modified => "MODIFIED".to_string(),
+        Integrity::BrokenLink => "CHAIN_BROKEN".to_string(),
+        Integrity::Gap { missing } => format!("GAP_{}_MISSING", missing),
+        Integrity::Unreadable => "UNREADABLE".to_string(),
+    }
+}
+
+#[component]
+pub fn view() -> Element {
+    let mut global = use_context::<GlobalContext>();
+
+    // Verified on open and on demand; the log is small enough to re-walk every time
+    let mut report = use_signal(|| audit::verify().map_err(|e| e.to_string()));
+
+    let on_back_click = move |_| {
+        global.sidebar_view.with_mut(|v| *v = SideBarView::None);
+    };
+
+    let (summary, summary_color, tail_issue, rows) = match &*report.read() {
+        Ok(r) => {
+            let summary = if r.is_intact() {
+                format!("CHAIN_INTACT // {} ENTRIES", r.entries.len())
+            } else {
+                format!("TAMPER_DETECTED // {} OF {} ENTRIES FLAGGED", r.flagged(), r.entries.len())
+            };
+            let color = if r.is_intact() { "var(--status-ok)" } else { "var(--status-warn)" };
+            (summary, color, r.tail_issue.clone(), rows_newest_first(r))
+        }
+        Err(e) => (format!("LOG_UNREADABLE // {}", e), "var(--status-warn)", None, Vec::new()),
+    };
+
+    rsx! {
+        style { {r#"
+            .audit-outer-viewport {
+                display: flex;
+                flex-direction: row;
+                width: 100%;
+                flex: 1;
+                overflow-y: auto;
+            }
+            .back-button-container {
+                position: absolute;
+                top: 1.25rem;
+                left: 1.25rem;
+                cursor: pointer;
+                z-index: 100;
+            }
+            .audit-main-container {
+                display: flex;
+                flex-direction: column;
+                width: 100%;
+                max-width: 900px;
+                margin: 0 auto;
+                padding: 3.5rem 2rem 2rem 2rem;
+                font-family: 'JetBrains Mono', monospace;
+            }
+            .audit-header {
+                display: flex;
+                justify-content: space-between;
+                align-items: flex-end;
+                border-bottom: 1px solid var(--border);
+                padding-bottom: 0.5rem;
+                margin-bottom: 1rem;
+            }
+            .audit-label { font-size: 0.7rem; color: var(--text-secondary); letter-spacing: 0.25rem; font-weight: 600; }
+            .audit-summary { font-size: 0.75rem; font-weight: 700; letter-spacing: 1px; margin-bottom: 0.5rem; }
+            .audit-tail { font-size: 0.65rem; color: var(--status-warn); margin-bottom: 0.5rem; }
+            .audit-row {
+                display: grid;
+                grid-template-columns: 3.5rem 10rem 11rem 1fr 7rem;
+                gap: 0.75rem;
+                padding: 0.4rem 0.5rem;
+                border-bottom: 1px solid var(--border);
+                font-size: 0.65rem;
+                color: var(--text);
+            }
+            .audit-row.flagged { background: rgba(var(--status-warn-rgb), 0.1); color: var(--status-warn); }
+            .audit-detail { word-break: break-all; color: var(--text-secondary); }
+        "#} }
+
+        div { class: "audit-outer-viewport",
+            div {
+                class: "back-button-container",
+                onclick: on_back_click,
+                previous_icon_button { text_color: "var(--text)".to_string() }
+            }
+
+            div { class: "audit-main-container",
+                div { class: "audit-header",
+                    div { class: "audit-label", "SECURITY_AUDIT_LOG" }
+                    {terminal_action("VERIFY", false, move |_| report.set(audit::verify().map_err(|e| e.to_string())))}
+                }
+
+                div { class: "audit-summary", style: "color: {summary_color};", "{summary}" }
+                if let Some(issue) = tail_issue {
+                    div { class: "audit-tail", ">> {issue}" }
+                }
+
+                for row in rows {
+                    div {
+                        key: "{row.line}",
+                        class: "{row.class}",
+                        span { "#{row.seq}" }
+                        span { "{row.time}" }
+                        span { "{row.event}" }
+                        span { class: "audit-detail", "{row.detail}" }
+                        span { "{row.status}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Row {
+    line: usize,
+    seq: String,
+    time: String,
+    event: String,
+    detail: String,
+    status: String,
+    class: &'static str,
+}
+
+fn rows_newest_first(report: &AuditReport) -> Vec<Row> {
+    report.entries.iter().rev().map(|v| {
+        let (seq, time, event, detail) = match &v.entry {
+            Some(e) => (e.seq.to_string(), e.time_label(), e.event.clone(), e.detail.clone()),
+            None => ("?".to_string(), "-".to_string(), "-".to_string(), format!("line {}", v.line)),
+        };
+        Row {
+            line: v.line,
+            seq,
+            time,
+            event,
+            detail,
+            status: status_label(&v.status),
+            class: if v.status == Integrity::Intact { "audit-row" } else { "audit-row flagged" },
+        }
+    }).collect()
+}
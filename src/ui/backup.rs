@@ -0,0 +1,329 @@
+use dioxus_native::prelude::*;
+use zeroize::Zeroizing;
+use crate::archive::ArchiveContents;
+use crate::ui::backuplogic::BackupLogic;
+use crate::utils::styles::{terminal_action, previous_icon_button};
+use crate::context::GlobalContext;
+use crate::channel::SideBarView;
+
+#[derive(Clone, Copy, PartialEq)]
+enum BackupMode {
+    Export,
+    Restore,
+}
+
+#[component]
+pub fn view() -> Element {
+    let mut global = use_context::<GlobalContext>();
+    let ws_tx = global.ws_tx.clone();
+
+    let mut mode = use_signal(|| BackupMode::Export);
+    let mut path = use_signal(BackupLogic::default_path);
+    let mut passphrase = use_signal(String::new);
+    let mut confirm_pass = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut is_processing = use_signal(|| false);
+    let mut inspected = use_signal(|| None::<ArchiveContents>);
+    let mut overwrite_confirmed = use_signal(|| false);
+
+    let mut reset = move |next: BackupMode| {
+        mode.set(next);
+        passphrase.set(String::new());
+        confirm_pass.set(String::new());
+        error.set(None);
+        inspected.set(None);
+        overwrite_confirmed.set(false);
+    };
+
+    let mut on_export = move |_| {
+        if path().trim().is_empty() {
+            error.set(Some("ARCHIVE_PATH_REQUIRED".to_string()));
+            return;
+        }
+        if passphrase().len() < 10 {
+            error.set(Some("PASSPHRASE_MIN_10_CHARS".to_string()));
+            return;
+        }
+        if passphrase() != confirm_pass() {
+            error.set(Some("BUFFER_MISMATCH".to_string()));
+            return;
+        }
+
+        tokio::spawn(BackupLogic::export(path().trim().to_string(), Zeroizing::new(passphrase())));
+        passphrase.set(String::new());
+        confirm_pass.set(String::new());
+        error.set(None);
+    };
+
+    let mut on_inspect = move |_| {
+        if *is_processing.read() { return; }
+        if path().trim().is_empty() || passphrase().is_empty() {
+            error.set(Some("ARCHIVE_PATH_AND_PASSPHRASE_REQUIRED".to_string()));
+            return;
+        }
+        is_processing.set(true);
+        error.set(None);
+
+        let archive_path = path().trim().to_string();
+        let pass = Zeroizing::new(passphrase());
+        spawn(async move {
+            match BackupLogic::inspect(archive_path, pass).await {
+                Ok(contents) => {
+                    passphrase.set(String::new());
+                    inspected.set(Some(contents));
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            is_processing.set(false);
+        });
+    };
+
+    let on_restore = move |_| {
+        let Some(contents) = inspected() else { return; };
+        if contents.clobbers_wallet() && !overwrite_confirmed() {
+            error.set(Some("CONFIRM_OVERWRITE_OF_EXISTING_WALLET_OR_PIN".to_string()));
+            return;
+        }
+        tokio::spawn(BackupLogic::restore(contents, overwrite_confirmed(), ws_tx.clone()));
+        inspected.set(None);
+        overwrite_confirmed.set(false);
+    };
+
+    let conflicts = inspected.read().as_ref().map(|c| c.conflicts()).unwrap_or_default();
+    let clobbers_wallet = inspected.read().as_ref().map(|c| c.clobbers_wallet()).unwrap_or(false);
+
+    rsx! {
+        style { {r#"
+            .backup-outer-viewport {
+                display: flex;
+                flex-direction: row;
+                width: 100%;
+                flex: 1;
+                align-items: center;
+            }
+
+            .back-button-container {
+                position: absolute;
+                top: 1.25rem;
+                left: 1.25rem;
+                cursor: pointer;
+                z-index: 100;
+            }
+
+            .backup-main-container {
+                display: flex;
+                flex-direction: column;
+                width: 100%;
+                max-width: 800px;
+                margin: 0 auto;
+                padding-left: 2rem;
+                padding-right: 2rem;
+                font-family: 'JetBrains Mono', monospace;
+            }
+
+            .backup-header {
+                display: flex;
+                justify-content: space-between;
+                align-items: flex-end;
+                border-bottom: 1px solid var(--border);
+                padding-bottom: 0.5rem;
+                margin-bottom: 2rem;
+            }
+
+            .backup-label {
+                font-size: 0.7rem;
+                color: var(--text-secondary);
+                letter-spacing: 0.25rem;
+                font-weight: 600;
+                text-transform: uppercase;
+            }
+
+            .mode-row { display: flex; gap: 1rem; margin-bottom: 2rem; }
+
+            .input-section { margin-bottom: 1.5rem; }
+
+            .input-label {
+                font-size: 0.65rem;
+                color: var(--accent);
+                border-left: 2px solid var(--accent);
+                padding-left: 8px;
+                margin-bottom: 0.75rem;
+                letter-spacing: 1px;
+            }
+
+            .terminal-input-wrapper {
+                display: flex;
+                align-items: center;
+                background: var(--bg-grid);
+                border: 1px solid var(--border);
+                padding: 0.8rem 1rem;
+                max-width: 560px;
+            }
+
+            .bracket { color: var(--text-secondary); opacity: 0.4; font-weight: bold; }
+
+            .inner-input {
+                flex: 1; background: transparent; border: none; outline: none;
+                color: var(--text); font-family: inherit; font-size: 0.9rem; padding: 0 1rem;
+            }
+
+            .manifest-box {
+                border: 1px solid var(--border);
+                background: var(--bg-secondary);
+                padding: 1rem;
+                margin-bottom: 1.5rem;
+                max-width: 560px;
+                font-size: 0.7rem;
+            }
+
+            .manifest-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 0.25rem 0;
+                color: var(--text);
+            }
+
+            .manifest-meta { color: var(--text-secondary); margin-bottom: 0.75rem; }
+
+            .conflict { color: var(--status-warn); }
+
+            .error-box {
+                border-left: 3px solid var(--status-warn);
+                padding: 0.75rem 1rem;
+                font-size: 0.75rem;
+                color: var(--status-warn);
+                max-width: 560px;
+            }
+
+            .footer-actions {
+                margin-top: 1rem;
+                display: flex;
+                align-items: center;
+                gap: 2rem;
+            }
+        "#} }
+
+        div { class: "backup-outer-viewport",
+
+            div {
+                class: "back-button-container",
+                onclick: move |_| {
+                    global.sidebar_view.with_mut(|v| *v = SideBarView::None);
+                },
+                previous_icon_button { text_color: "var(--text)".to_string() }
+            }
+
+            div { class: "backup-main-container",
+
+                div { class: "backup-header",
+                    div { class: "backup-label", "SECURITY // PROFILE_ARCHIVE" }
+                }
+
+                div { class: "mode-row",
+                    {terminal_action("EXPORT", mode() == BackupMode::Export, move |_| reset(BackupMode::Export))}
+                    {terminal_action("RESTORE", mode() == BackupMode::Restore, move |_| reset(BackupMode::Restore))}
+                }
+
+                div { class: "input-section",
+                    div { class: "input-label", "ARCHIVE_PATH" }
+                    div { class: "terminal-input-wrapper",
+                        span { class: "bracket", "[" }
+                        input {
+                            class: "inner-input",
+                            value: "{path}",
+                            oninput: move |e| {
+                                path.set(e.value());
+                                inspected.set(None);
+                            },
+                        }
+                        span { class: "bracket", "]" }
+                    }
+                }
+
+                if inspected.read().is_none() {
+                    div { class: "input-section",
+                        div { class: "input-label", "ARCHIVE_PASSPHRASE" }
+                        div { class: "terminal-input-wrapper",
+                            span { class: "bracket", "[" }
+                            input {
+                                class: "inner-input",
+                                r#type: "password",
+                                value: "{passphrase}",
+                                oninput: move |e| passphrase.set(e.value()),
+                            }
+                            span { class: "bracket", "]" }
+                        }
+                    }
+                }
+
+                if mode() == BackupMode::Export {
+                    div { class: "input-section",
+                        div { class: "input-label", "CONFIRM_PASSPHRASE" }
+                        div { class: "terminal-input-wrapper",
+                            span { class: "bracket", "[" }
+                            input {
+                                class: "inner-input",
+                                r#type: "password",
+                                value: "{confirm_pass}",
+                                oninput: move |e| confirm_pass.set(e.value()),
+                                onkeydown: move |e| if e.key() == Key::Enter { on_export(()); }
+                            }
+                            span { class: "bracket", "]" }
+                        }
+                    }
+                }
+
+                if let Some(contents) = inspected() {
+                    div { class: "manifest-box",
+                        div { class: "manifest-meta",
+                            "CREATED: {contents.manifest.created_at} // APP_v{contents.manifest.app_version}"
+                        }
+                        for entry in contents.manifest.files.iter() {
+                            div { class: "manifest-row",
+                                span {
+                                    class: if conflicts.contains(&entry.name) { "conflict" },
+                                    "{entry.name}"
+                                }
+                                span {
+                                    class: if conflicts.contains(&entry.name) { "conflict" },
+                                    if conflicts.contains(&entry.name) { "REPLACES_LOCAL // {entry.size} B" } else { "NEW // {entry.size} B" }
+                                }
+                            }
+                        }
+                    }
+
+                    if clobbers_wallet {
+                        div { class: "footer-actions",
+                            {terminal_action("OVERWRITE_EXISTING_WALLET_AND_PIN", overwrite_confirmed(), move |_| {
+                                let next = !overwrite_confirmed();
+                                overwrite_confirmed.set(next);
+                            })}
+                        }
+                    }
+                }
+
+                if let Some(err) = error() {
+                    div { class: "error-box", "SIGNAL_INTERRUPT: {err}" }
+                }
+
+                div { class: "footer-actions",
+                    match mode() {
+                        BackupMode::Export => terminal_action("EXECUTE", true, move |_| on_export(())),
+                        BackupMode::Restore if inspected.read().is_some() => terminal_action("RESTORE", true, on_restore),
+                        BackupMode::Restore => terminal_action(
+                            if *is_processing.read() { "VERIFYING..." } else { "INSPECT" },
+                            !*is_processing.read(),
+                            move |_| on_inspect(()),
+                        ),
+                    }
+                    div {
+                        style: "font-size: 0.6rem; color: var(--text-secondary); opacity: 0.4; line-height: 1.4;",
+                        "AES-256-GCM"
+
+                        "SHA256_MANIFEST"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+use tokio::sync::mpsc::Sender;
+use zeroize::Zeroizing;
+use crate::archive::{self, ArchiveContents};
+use crate::channel::{CHANNEL, ProgressState, SideBarView, WSCommand};
+
+pub struct BackupLogic;
+
+impl BackupLogic {
+    /// Suggested archive location: ~/dannesk-backup-YYYYMMDD.dnsk
+    pub fn default_path() -> String {
+        let name = format!(
+            "dannesk-backup-{}.{}",
+            chrono::Local::now().format("%Y%m%d"),
+            archive::ARCHIVE_EXTENSION,
+        );
+        dirs::home_dir()
+            .map(|home| home.join(&name))
+            .unwrap_or_else(|| PathBuf::from(&name))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    pub async fn export(path: String, passphrase: Zeroizing<String>) {
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.3,
+            message: "Encrypting profile archive...".to_string(),
+        }));
+
+        let result = tokio::task::spawn_blocking(move || {
+            archive::export_profile(&PathBuf::from(path), passphrase, crate::VERSION)
+        }).await;
+
+        match result {
+            Ok(Ok(manifest)) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Backup complete: {} files archived", manifest.files.len()),
+                }));
+                let _ = CHANNEL.sidebar_view_tx.send(SideBarView::None);
+            }
+            Ok(Err(e)) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+            }
+            Err(_) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: "Internal thread error".to_string(),
+                }));
+            }
+        }
+    }
+
+    /// Decrypts and verifies an archive so the view can show its manifest before restoring.
+    pub async fn inspect(path: String, passphrase: Zeroizing<String>) -> Result<ArchiveContents, String> {
+        tokio::task::spawn_blocking(move || archive::inspect(&PathBuf::from(path), passphrase))
+            .await
+            .map_err(|_| "Internal thread error".to_string())?
+    }
+
+    pub async fn restore(contents: ArchiveContents, overwrite: bool, ws_tx: Sender<WSCommand>) {
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.3,
+            message: "Restoring profile...".to_string(),
+        }));
+
+        let result = tokio::task::spawn_blocking(move || archive::restore(&contents, overwrite)).await;
+
+        match result {
+            Ok(Ok(count)) => {
+                // Pick up the restored wallets without a restart
+                let _ = tokio::task::spawn_blocking(move || crate::wallet::load_wallets(ws_tx)).await;
+
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Restore complete: {} files written", count),
+                }));
+                let _ = CHANNEL.sidebar_view_tx.send(SideBarView::None);
+            }
+            Ok(Err(e)) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+            }
+            Err(_) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: "Internal thread error".to_string(),
+                }));
+            }
+        }
+    }
+}
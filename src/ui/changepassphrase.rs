@@ -0,0 +1,234 @@
+use dioxus_native::prelude::*;
+use zeroize::Zeroizing;
+use crate::ui::passphraselogic::PassphraseLogic;
+use crate::utils::json_storage;
+use crate::utils::styles::{terminal_action, previous_icon_button};
+use crate::context::GlobalContext;
+use crate::channel::SideBarView;
+
+fn keystore_exists(chain: &str) -> bool {
+    json_storage::get_config_path(PassphraseLogic::keystore_file(chain))
+        .map(|path| path.exists())
+        .unwrap_or(false)
+}
+
+#[component]
+pub fn view() -> Element {
+    let mut global = use_context::<GlobalContext>();
+
+    let has_xrp = use_memo(|| keystore_exists("XRP"));
+    let has_btc = use_memo(|| keystore_exists("BTC"));
+
+    let mut chain = use_signal(|| if !has_xrp() && has_btc() { "BTC".to_string() } else { "XRP".to_string() });
+    let mut old_pass = use_signal(String::new);
+    let mut new_pass = use_signal(String::new);
+    let mut confirm_pass = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut on_submit = move |_| {
+        let selected = chain();
+        if !keystore_exists(&selected) {
+            error.set(Some(format!("NO_{}_KEY_STORED", selected)));
+            return;
+        }
+        if old_pass().is_empty() {
+            error.set(Some("CURRENT_PASSPHRASE_REQUIRED".to_string()));
+            return;
+        }
+        if new_pass().len() < 10 {
+            error.set(Some("NEW_PASSPHRASE_MIN_10_CHARS".to_string()));
+            return;
+        }
+        if new_pass() != confirm_pass() {
+            error.set(Some("BUFFER_MISMATCH".to_string()));
+            return;
+        }
+
+        tokio::spawn(PassphraseLogic::change_passphrase(
+            selected,
+            Zeroizing::new(old_pass()),
+            Zeroizing::new(new_pass()),
+        ));
+
+        old_pass.set(String::new());
+        new_pass.set(String::new());
+        confirm_pass.set(String::new());
+        error.set(None);
+    };
+
+    rsx! {
+        style { {r#"
+            .pass-outer-viewport {
+                display: flex;
+                flex-direction: row;
+                width: 100%;
+                flex: 1;
+                align-items: center;
+            }
+
+            .back-button-container {
+                position: absolute;
+                top: 1.25rem;
+                left: 1.25rem;
+                cursor: pointer;
+                z-index: 100;
+            }
+
+            .pass-main-container {
+                display: flex;
+                flex-direction: column;
+                width: 100%;
+                max-width: 800px;
+                margin: 0 auto;
+                padding-left: 2rem;
+                padding-right: 2rem;
+                font-family: 'JetBrains Mono', monospace;
+            }
+
+            .pass-header {
+                display: flex;
+                justify-content: space-between;
+                align-items: flex-end;
+                border-bottom: 1px solid var(--border);
+                padding-bottom: 0.5rem;
+                margin-bottom: 2rem;
+            }
+
+            .pass-label {
+                font-size: 0.7rem;
+                color: var(--text-secondary);
+                letter-spacing: 0.25rem;
+                font-weight: 600;
+                text-transform: uppercase;
+            }
+
+            .chain-row { display: flex; gap: 1rem; margin-bottom: 2rem; }
+
+            .input-section { margin-bottom: 2rem; }
+
+            .input-label {
+                font-size: 0.65rem;
+                color: var(--accent);
+                border-left: 2px solid var(--accent);
+                padding-left: 8px;
+                margin-bottom: 0.75rem;
+                letter-spacing: 1px;
+            }
+
+            .terminal-input-wrapper {
+                display: flex;
+                align-items: center;
+                background: var(--bg-grid);
+                border: 1px solid var(--border);
+                padding: 0.8rem 1rem;
+                max-width: 350px;
+            }
+
+            .bracket { color: var(--text-secondary); opacity: 0.4; font-weight: bold; }
+
+            .inner-input {
+                flex: 1; background: transparent; border: none; outline: none;
+                color: var(--text); font-family: inherit; font-size: 1rem; padding: 0 1rem;
+            }
+
+            .error-box {
+                border-left: 3px solid var(--status-warn);
+                padding: 0.75rem 1rem;
+                font-size: 0.75rem;
+                color: var(--status-warn);
+                max-width: 350px;
+            }
+
+            .footer-actions {
+                margin-top: 1rem;
+                display: flex;
+                align-items: center;
+                gap: 2rem;
+            }
+        "#} }
+
+        div { class: "pass-outer-viewport",
+
+            div {
+                class: "back-button-container",
+                onclick: move |_| {
+                    global.sidebar_view.with_mut(|v| *v = SideBarView::None);
+                },
+                previous_icon_button { text_color: "var(--text)".to_string() }
+            }
+
+            div { class: "pass-main-container",
+
+                div { class: "pass-header",
+                    div { class: "pass-label", "SECURITY // WALLET_PASSPHRASE" }
+                }
+
+                div { class: "chain-row",
+                    if has_xrp() {
+                        {terminal_action("XRP", chain() == "XRP", move |_| chain.set("XRP".to_string()))}
+                    }
+                    if has_btc() {
+                        {terminal_action("BTC", chain() == "BTC", move |_| chain.set("BTC".to_string()))}
+                    }
+                }
+
+                div { class: "input-section",
+                    div { class: "input-label", "CURRENT_PASSPHRASE" }
+                    div { class: "terminal-input-wrapper",
+                        span { class: "bracket", "[" }
+                        input {
+                            class: "inner-input",
+                            r#type: "password",
+                            value: "{old_pass}",
+                            oninput: move |e| old_pass.set(e.value()),
+                        }
+                        span { class: "bracket", "]" }
+                    }
+                }
+
+                div { class: "input-section",
+                    div { class: "input-label", "NEW_PASSPHRASE" }
+                    div { class: "terminal-input-wrapper",
+                        span { class: "bracket", "[" }
+                        input {
+                            class: "inner-input",
+                            r#type: "password",
+                            value: "{new_pass}",
+                            oninput: move |e| new_pass.set(e.value()),
+                        }
+                        span { class: "bracket", "]" }
+                    }
+                }
+
+                div { class: "input-section",
+                    div { class: "input-label", "CONFIRM_NEW_PASSPHRASE" }
+                    div { class: "terminal-input-wrapper",
+                        span { class: "bracket", "[" }
+                        input {
+                            class: "inner-input",
+                            r#type: "password",
+                            value: "{confirm_pass}",
+                            oninput: move |e| confirm_pass.set(e.value()),
+                            onkeydown: move |e| if e.key() == Key::Enter { on_submit(()); }
+                        }
+                        span { class: "bracket", "]" }
+                    }
+                }
+
+                if let Some(err) = error() {
+                    div { class: "error-box", "SIGNAL_INTERRUPT: {err}" }
+                }
+
+                div { class: "footer-actions",
+                    {terminal_action("EXECUTE", true, move |_| on_submit(()))}
+                    div {
+                        style: "font-size: 0.6rem; color: var(--text-secondary); opacity: 0.4; line-height: 1.4;",
+                        "AES-256-GCM"
+
+                        "FRESH_SALT_IV"
+                    }
+                }
+            }
+        }
+    }
+}
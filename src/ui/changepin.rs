@@ -3,6 +3,7 @@ use crate::ui::pinlogic::PinLogic;
 use crate::utils::styles::{terminal_action, previous_icon_button};
 use crate::context::GlobalContext;         
 use crate::channel::SideBarView;          
+use crate::utils::json_storage::{self, Profile};
 
 #[component]
 pub fn view() -> Element {
@@ -11,12 +12,108 @@ pub fn view() -> Element {
     let mut old_pin = use_signal(String::new);
     let mut new_pin = use_signal(String::new);
     let mut confirm_pin = use_signal(String::new);
+    let current_format = crate::pin::load_format();
+    let mut new_format = use_signal(move || current_format);
+    let mut form_error = use_signal(|| None::<String>);
+
+    // Cycle 6/8/10/12 digits -> passphrase for the new secret
+    let on_format_toggle = move |_| {
+        let options = crate::pin::UnlockFormat::OPTIONS;
+        let idx = options.iter().position(|o| *o == new_format()).unwrap_or(0);
+        new_format.set(options[(idx + 1) % options.len()]);
+        form_error.set(None);
+    };
+    let mut wipe_after = use_signal(|| crate::pin::load_pin_data().ok().and_then(|d| d.wipe_after));
+
+    // Cycle OFF -> 10 -> 20 -> OFF; the policy is saved immediately
+    let on_wipe_toggle = move |_| {
+        let options = crate::pin::WIPE_POLICY_OPTIONS;
+        let idx = options.iter().position(|o| *o == wipe_after()).unwrap_or(0);
+        let next = options[(idx + 1) % options.len()];
+        if crate::pin::set_wipe_policy(next).is_ok() {
+            wipe_after.set(next);
+        }
+    };
+    let wipe_label = match wipe_after() {
+        Some(n) => format!("WIPE_KEYS_AFTER_{}_FAILS", n),
+        None => "WIPE_KEYS // OFF".to_string(),
+    };
+
+    let mut idle_lock = use_signal(|| crate::preferences::load().idle_lock_minutes);
+
+    // Cycle OFF -> 1 -> 5 -> 15 -> 30 minutes
+    let on_idle_toggle = move |_| {
+        let options = crate::preferences::IDLE_LOCK_OPTIONS;
+        let idx = options.iter().position(|o| *o == idle_lock()).unwrap_or(0);
+        let next = options[(idx + 1) % options.len()];
+        if crate::preferences::update(|p| p.idle_lock_minutes = next).is_ok() {
+            idle_lock.set(next);
+        }
+    };
+    let idle_label = match idle_lock() {
+        Some(n) => format!("AUTO_LOCK_AFTER_{}M_IDLE", n),
+        None => "AUTO_LOCK // OFF".to_string(),
+    };
+
+    let mut clipboard_clear = use_signal(|| crate::preferences::load().clipboard_clear_secs);
+
+    // Cycle OFF -> 15 -> 30 -> 60 -> 120 seconds
+    let on_clipboard_toggle = move |_| {
+        let options = crate::preferences::CLIPBOARD_CLEAR_OPTIONS;
+        let idx = options.iter().position(|o| *o == clipboard_clear()).unwrap_or(0);
+        let next = options[(idx + 1) % options.len()];
+        if crate::preferences::update(|p| p.clipboard_clear_secs = next).is_ok() {
+            clipboard_clear.set(next);
+        }
+    };
+    let clipboard_label = match clipboard_clear() {
+        Some(n) => format!("CLEAR_CLIPBOARD_AFTER_{}S", n),
+        None => "CLEAR_CLIPBOARD // OFF".to_string(),
+    };
+
+    // Duress settings are only visible from the primary profile
+    let show_duress = json_storage::active_profile() == Profile::Primary;
+    let mut duress_pin = use_signal(String::new);
+    let mut has_duress = use_signal(crate::pin::has_duress_pin);
+    let mut duress_status = use_signal(|| None::<String>);
+
+    let on_duress_set = move |_| {
+        let pin = duress_pin();
+        if pin.is_empty() { return; }
+        duress_pin.set(String::new());
+        spawn(async move {
+            let result = tokio::task::spawn_blocking(move || crate::pin::set_duress_pin(&pin)).await;
+            match result {
+                Ok(Ok(())) => {
+                    has_duress.set(true);
+                    duress_status.set(Some("DURESS_PIN_ARMED".to_string()));
+                }
+                Ok(Err(e)) => duress_status.set(Some(format!("ERR: {}", e))),
+                Err(_) => duress_status.set(Some("ERR: INTERNAL_THREAD".to_string())),
+            }
+        });
+    };
+
+    let on_duress_clear = move |_| {
+        if crate::pin::clear_duress_pin().is_ok() {
+            has_duress.set(false);
+            duress_status.set(Some("DURESS_PIN_REMOVED".to_string()));
+        }
+    };
 
     let mut on_submit = move |_| {
         if new_pin().is_empty() || old_pin().is_empty() { return; }
-        if new_pin() != confirm_pin() { return; }
-        
-        tokio::spawn(PinLogic::change_pin(old_pin(), new_pin()));
+        if new_pin() != confirm_pin() {
+            form_error.set(Some("BUFFER_MISMATCH".to_string()));
+            return;
+        }
+        if new_format().validate(&new_pin()).is_err() {
+            form_error.set(Some(format!("NEW_SECRET_MUST_BE_{}", new_format().label())));
+            return;
+        }
+        form_error.set(None);
+
+        tokio::spawn(PinLogic::change_pin(old_pin(), new_pin(), new_format()));
 
         old_pin.set(String::new());
         new_pin.set(String::new());
@@ -138,6 +235,16 @@ pub fn view() -> Element {
                     }
                 }
 
+                div { class: "input-section",
+                    div { class: "input-label", "NEW_SECRET_FORMAT" }
+                    {terminal_action(&new_format().label(), new_format() != current_format, on_format_toggle)}
+                    if show_duress && has_duress() && new_format() != current_format {
+                        div { style: "font-size: 0.65rem; color: var(--status-warn); margin-top: 0.5rem;",
+                            "FORMAT_CHANGE_CLEARS_DURESS_PIN"
+                        }
+                    }
+                }
+
                 div { class: "input-section",
                     div { class: "input-label", "NEW_PIN" }
                     div { class: "terminal-input-wrapper",
@@ -167,6 +274,48 @@ pub fn view() -> Element {
                     }
                 }
 
+                div { class: "input-section",
+                    div { class: "input-label", "LOCKOUT_POLICY" }
+                    {terminal_action(&wipe_label, wipe_after().is_some(), on_wipe_toggle)}
+                    {terminal_action(&idle_label, idle_lock().is_some(), on_idle_toggle)}
+                    {terminal_action(&clipboard_label, clipboard_clear().is_some(), on_clipboard_toggle)}
+                }
+
+                if show_duress {
+                    div { class: "input-section",
+                        div { class: "input-label", "DURESS_PIN // OPENS_DECOY_PROFILE" }
+                        div { class: "terminal-input-wrapper",
+                            span { class: "bracket", "[" }
+                            input {
+                                class: "inner-input",
+                                r#type: "password",
+                                value: "{duress_pin}",
+                                placeholder: if has_duress() { "ARMED" } else { "NOT_SET" },
+                                oninput: move |e| {
+                                    let val = e.value();
+                                    if current_format.accepts_partial(&val) {
+                                        duress_pin.set(val);
+                                    }
+                                },
+                            }
+                            span { class: "bracket", "]" }
+                        }
+                        div { style: "display: flex; gap: 1rem; margin-top: 0.5rem;",
+                            {terminal_action("SET_DURESS", true, on_duress_set)}
+                            if has_duress() {
+                                {terminal_action("CLEAR_DURESS", false, on_duress_clear)}
+                            }
+                        }
+                        if let Some(status) = duress_status() {
+                            div { style: "font-size: 0.65rem; color: var(--text-secondary); margin-top: 0.5rem;", "{status}" }
+                        }
+                    }
+                }
+
+                if let Some(err) = form_error() {
+                    div { style: "font-size: 0.65rem; color: var(--status-warn); margin-bottom: 0.5rem;", "ERR: {err}" }
+                }
+
                 div { class: "footer-actions",
                     {terminal_action("EXECUTE", true, move |_| on_submit(()))}
                     div { 
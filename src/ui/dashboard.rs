@@ -1,6 +1,6 @@
 use dioxus_native::prelude::*;
 use crate::channel::{Tab, SideBarView};     
-use crate::ui::{balance, managebtc, managexrp, progressbar::ProgressBar, sidebar, ticker, networkstatus, changepin}; 
+use crate::ui::{balance, managebtc, managexrp, progressbar::ProgressBar, sidebar, ticker, networkstatus, changepin, changepassphrase, backup, auditlog}; 
 use crate::context::GlobalContext;
 
 pub fn render_dashboard() -> Element {
@@ -27,11 +27,16 @@ pub fn render_dashboard() -> Element {
                     gap: 0.5rem;
                     z-index: 1000;
                 ",
+                sidebar::render_session_indicator {}
                 sidebar::render_balance_toggle {}
                 sidebar::render_pin_button {}
+                sidebar::render_passphrase_button {}
+                sidebar::render_backup_button {}
                 sidebar::render_rates_button {}
                 sidebar::render_network_button {} 
+                sidebar::render_audit_button {}
                 sidebar::render_theme_toggle {}
+                sidebar::render_lock_button {}
             }
 
             // Progress > Security > Normal Dashboard
@@ -40,8 +45,11 @@ pub fn render_dashboard() -> Element {
                 None => match *global.sidebar_view.read() {
                     // ← Security takes over whole screen
                     SideBarView ::ChangePin => rsx! { changepin::view {} },
+                    SideBarView::ChangePassphrase => rsx! { changepassphrase::view {} },
+                    SideBarView::Backup => rsx! { backup::view {} },
                     SideBarView::ExchangeRates => rsx! { ticker::view {} },
                     SideBarView::NetworkStatus=> rsx! { networkstatus::view {} },
+                    SideBarView::AuditLog => rsx! { auditlog::view {} },
 
 
 
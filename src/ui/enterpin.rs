@@ -1,6 +1,7 @@
 // src/ui/pin.rs
 use dioxus_native::prelude::*;
-use crate::utils::json_storage;
+use crate::utils::json_storage::{self, Profile};
+use crate::pin::UnlockFormat;
 
 #[derive(Clone, Copy, PartialEq)]
 enum PinState {
@@ -10,14 +11,31 @@ enum PinState {
 }
 
 #[component]
-pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
+pub fn PinScreen(on_unlock: EventHandler<Profile>) -> Element {
     let mut input = use_signal(|| String::new());
     let mut error_msg = use_signal(|| None::<String>);
     let mut stored_pin_for_confirmation = use_signal(|| String::new());
-    let mut attempts_left = use_signal(|| 5);
     let mut is_processing = use_signal(|| false);
+    // Seconds until another attempt is accepted; persisted in pin.json so restarts don't reset it
+    let mut lockout_secs = use_signal(|| {
+        crate::pin::lockout_status().and_then(|(_, remaining)| remaining).unwrap_or(0)
+    });
+
+    // Count the lockout down once per second
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            let left = *lockout_secs.peek();
+            if left > 0 {
+                lockout_secs.set(left - 1);
+                if left == 1 { error_msg.set(None); }
+            }
+        }
+    });
 
     let pin_exists = use_memo(|| json_storage::read_json::<crate::pin::PinData>("pin.json").is_ok());
+    // Stored format when a PIN exists; chosen by the user during first setup otherwise
+    let mut format = use_signal(crate::pin::load_format);
 
     let state = use_memo(move || {
         if !pin_exists() {
@@ -28,27 +46,35 @@ pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
     });
 
     let mut run_submit = move || {
-        if *is_processing.read() || input.read().is_empty() { return; }
+        if *is_processing.read() || input.read().is_empty() || *lockout_secs.read() > 0 { return; }
         is_processing.set(true);
 
         let pin = input.read().clone();
         let current_state = state();
+        let fmt = format();
         let stored_pin = stored_pin_for_confirmation.read().clone();
 
         spawn(async move {
-            // Short breath to ensure the 6th digit renders
+            // Short breath to ensure the last digit renders
             tokio::time::sleep(std::time::Duration::from_millis(20)).await;
             
             match current_state {
                 PinState::SetPin => {
-                    stored_pin_for_confirmation.set(pin);
                     input.set(String::new());
-                    error_msg.set(None);
+                    match fmt.validate(&pin) {
+                        Ok(()) => {
+                            stored_pin_for_confirmation.set(pin);
+                            error_msg.set(None);
+                        }
+                        Err(_) => error_msg.set(Some(format!(
+                            "ERR: MIN_{}_CHARS", UnlockFormat::MIN_PASSPHRASE
+                        ))),
+                    }
                 }
                 PinState::ConfirmPin => {
                     if pin == stored_pin {
-                        if crate::pin::set_pin(&pin).is_ok() {
-                            on_unlock.call(());
+                        if crate::pin::set_pin(&pin, fmt).is_ok() {
+                            on_unlock.call(Profile::Primary);
                         } else {
                             error_msg.set(Some("ERR: STORAGE_IO_FAILURE".to_string()));
                             input.set(String::new());
@@ -60,17 +86,24 @@ pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
                     }
                 }
                 PinState::EnterPin => {
-                    if crate::pin::verify_pin(&pin).is_ok() {
-                        on_unlock.call(());
-                    } else {
-                        let left = *attempts_left.peek() - 1;
-                        attempts_left.set(left);
-                        input.set(String::new());
-                        error_msg.set(Some(if left == 0 { 
-                            "CRITICAL: SYSTEM_LOCKOUT".into() 
-                        } else { 
-                            format!("AUTH_ERR: {} ATTEMPTS REMAINING", left) 
-                        }));
+                    match crate::pin::verify_pin(&pin) {
+                        Ok(profile) => on_unlock.call(profile),
+                        Err(e) => {
+                            input.set(String::new());
+                            let status = crate::pin::lockout_status();
+                            let failures = status.map(|(n, _)| n).unwrap_or(0);
+                            let wait = status.and_then(|(_, w)| w).unwrap_or(0);
+                            lockout_secs.set(wait);
+                            error_msg.set(Some(match e {
+                                crate::pin::PinError::KeysWiped => "CRITICAL: KEYS_WIPED // WATCH_ONLY".into(),
+                                crate::pin::PinError::LockedOut(secs) => {
+                                    lockout_secs.set(secs);
+                                    format!("SYSTEM_LOCKOUT: RETRY IN {}s", secs)
+                                }
+                                _ if wait > 0 => format!("AUTH_ERR: {} FAILED // LOCKED {}s", failures, wait),
+                                _ => format!("AUTH_ERR: {} FAILED ATTEMPTS", failures),
+                            }));
+                        }
                     }
                 }
             }
@@ -79,14 +112,24 @@ pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
     };
 
     let mut add_digit = move |digit: char| {
-        if input.read().len() < 6 && !*is_processing.read() {
+        let fmt = format();
+        if input.read().len() < fmt.length && !*is_processing.read() && *lockout_secs.read() == 0 {
             input.with_mut(|s| s.push(digit));
-            if input.read().len() == 6 {
+            if fmt.is_complete(&input.read()) {
                 run_submit();
             }
         }
     };
 
+    // First setup only: cycle 6/8/10/12 digits -> passphrase
+    let on_format_toggle = move |_| {
+        let options = UnlockFormat::OPTIONS;
+        let idx = options.iter().position(|o| *o == format()).unwrap_or(0);
+        format.set(options[(idx + 1) % options.len()]);
+        input.set(String::new());
+        error_msg.set(None);
+    };
+
     rsx! {
         style { {r#"
             .pin-page {
@@ -217,6 +260,19 @@ pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
                 color: var(--bg-primary); /* Inverse text color so it remains visible */
             }
 
+            .format-toggle {
+                width: 100%;
+                margin-top: 0.75rem;
+                background: transparent;
+                border: 1px dashed var(--border);
+                color: var(--text-secondary);
+                font-family: inherit;
+                font-size: 0.6rem;
+                letter-spacing: 1px;
+                padding: 0.4rem;
+                cursor: pointer;
+            }
+
             .special-key { 
                 font-size: 0.65rem; 
                 color: var(--text-secondary); /* Give special keys a muted look */
@@ -233,6 +289,8 @@ pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
                             span { class: "bracket", ">>" }
                             if *is_processing.read() {
                                 span { class: "loading-text", "DECRYPTING_HASH..." }
+                            } else if *lockout_secs.read() > 0 {
+                                span { class: "loading-text", "LOCKED // {lockout_secs}s" }
                             } else {
                                 input {
                                     class: "pin-field",
@@ -241,40 +299,54 @@ pub fn PinScreen(on_unlock: EventHandler<()>) -> Element {
                                     value: "{input}",
                                     oninput: move |evt| {
                                         let val = evt.value();
-                                        if val.len() <= 6 && val.chars().all(|c| c.is_numeric()) {
+                                        let fmt = format();
+                                        if fmt.accepts_partial(&val) {
                                             input.set(val.clone());
-                                            if val.len() == 6 { run_submit(); }
+                                            if fmt.is_complete(&val) { run_submit(); }
                                         }
+                                    },
+                                    // Passphrases have no fixed length, so they submit on Enter
+                                    onkeydown: move |evt| {
+                                        if evt.key() == Key::Enter && !format().is_numeric() { run_submit(); }
                                     }
                                 }
                             }
                         }
                         div { class: "status-msg", "{error_msg.read().clone().unwrap_or_default()}" }
+                        if state() == PinState::SetPin {
+                            button {
+                                class: "format-toggle",
+                                onclick: on_format_toggle,
+                                "FORMAT // {format().label()}"
+                            }
+                        }
                     }
 
-                    div { 
-                        class: if *is_processing.read() { "keypad processing" } else { "keypad" },
-                        for n in ["1", "2", "3", "4", "5", "6", "7", "8", "9"] {
+                    if format().is_numeric() {
+                        div { 
+                            class: if *is_processing.read() || *lockout_secs.read() > 0 { "keypad processing" } else { "keypad" },
+                            for n in ["1", "2", "3", "4", "5", "6", "7", "8", "9"] {
+                                button { 
+                                    class: "num-key", 
+                                    onclick: move |_| add_digit(n.chars().next().unwrap()), 
+                                    "{n}" 
+                                }
+                            }
+                            button { 
+                                class: "num-key special-key", 
+                                onclick: move |_| input.set(String::new()), 
+                                "CLR" 
+                            }
                             button { 
                                 class: "num-key", 
-                                onclick: move |_| add_digit(n.chars().next().unwrap()), 
-                                "{n}" 
+                                onclick: move |_| add_digit('0'), 
+                                "0" 
+                            }
+                            button { 
+                                class: "num-key special-key", 
+                                onclick: move |_| { input.with_mut(|s| { s.pop(); }); }, 
+                                "DEL" 
                             }
-                        }
-                        button { 
-                            class: "num-key special-key", 
-                            onclick: move |_| input.set(String::new()), 
-                            "CLR" 
-                        }
-                        button { 
-                            class: "num-key", 
-                            onclick: move |_| add_digit('0'), 
-                            "0" 
-                        }
-                        button { 
-                            class: "num-key special-key", 
-                            onclick: move |_| { input.with_mut(|s| { s.pop(); }); }, 
-                            "DEL" 
                         }
                     }
                 }
@@ -6,6 +6,7 @@ use tokio::sync::mpsc::Sender;
 use tokio::time::sleep;
 use crate::utils::json_storage::{self, remove_json, get_config_path};
 use crate::channel::{CHANNEL, WSCommand, ProgressState, BTCTransactionState};
+use crate::clipboard::AddressChain;
 
 pub struct BitcoinWalletOperations;
 
@@ -17,24 +18,31 @@ impl BitcoinWalletOperations {
             message: "Starting key deletion...".to_string(),
         }));
 
-        // Check if file exists before trying to delete
+        // btc_encrypt.json, or the master seed once XRP no longer uses it
         let mut delete_success = true;
-        if let Ok(path) = get_config_path("btc_encrypt.json") {
-            if path.exists() {
-                if let Err(e) = remove_json("btc_encrypt.json") {
-                    delete_success = false;
-                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
-                        progress: 0.3,
-                        message: format!("Warning: Could not delete encrypted file: {}", e),
-                    }));
-                }
-            } else {
+        let unified = crate::master::is_unified(AddressChain::Btc);
+        match crate::master::release_key(AddressChain::Btc) {
+            Ok(true) => {}
+            Ok(false) if unified && crate::master::master_exists() => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 0.2,
+                    message: "Master seed kept while XRP still uses it; BTC marked watch-only.".to_string(),
+                }));
+            }
+            Ok(false) => {
                 // Skip if already deleted
                 let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                     progress: 0.2,
                     message: "Key already removed from storage.".to_string(),
                 }));
             }
+            Err(e) => {
+                delete_success = false;
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 0.3,
+                    message: format!("Warning: Could not delete encrypted file: {}", e),
+                }));
+            }
         }
 
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
@@ -61,10 +69,12 @@ impl BitcoinWalletOperations {
         let (current_balance, _, _) = *CHANNEL.bitcoin_wallet_rx.borrow();
         let _ = CHANNEL.bitcoin_wallet_tx.send((
             current_balance,
-            Some(wallet_address),
+            Some(wallet_address.clone()),
             true, // key deleted
         ));
 
+        crate::audit::record(crate::audit::AuditEvent::KeyPurged, format!("BTC {}", wallet_address));
+
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
             progress: 1.0,
             message: if delete_success { "Key deletion complete".to_string() } else { "Deletion finished with errors".to_string() },
@@ -79,12 +89,8 @@ impl BitcoinWalletOperations {
             message: "Removing Bitcoin wallet...".to_string(),
         }));
 
-        // 1. Delete the encrypted sensitive data (if it exists)
-        if let Ok(path) = get_config_path("btc_encrypt.json") {
-            if path.exists() {
-                let _ = remove_json("btc_encrypt.json");
-            }
-        }
+        // 1. Delete the encrypted sensitive data (if it exists and XRP doesn't share it)
+        let _ = crate::master::release_key(AddressChain::Btc);
 
         // 2. Delete the wallet metadata (btc.json)
         if let Ok(path) = get_config_path("btc.json") {
@@ -112,16 +118,15 @@ impl BitcoinWalletOperations {
             wallet: Some(wallet_address.clone()),
             recipient: None,
             amount: None,
-            passphrase: None,
             trustline_limit: None,
             fee: None,
             tx_type: None,
             taker_pays: None,
             taker_gets: None,
-            seed: None,
             flags: None,
             wallet_type: None,
-            bip39: None,
+            signed_tx: None,
+            tx_hash: None,
         };
 
         let _ = ws_tx.try_send(command);
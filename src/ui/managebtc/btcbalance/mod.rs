@@ -18,11 +18,24 @@ pub fn view() -> Element {
     let mut btc_sign_tx = btc_ctx.btc_sign_transaction;
 
     // DATA
-    let (btc_amount, address, key_is_deleted) = btc_ctx.bitcoin_wallet.read().clone();
+    let (reported, address, key_is_deleted) = btc_ctx.bitcoin_wallet.read().clone();
+    // Summed over every account address once discovery has run
+    let account = btc_ctx.btc_account.read().clone();
+    let btc_amount = account.balance_or(address.as_deref(), reported);
+    // A failed scan leaves the last total, which may be missing coins
+    let scan_failed = account.error.is_some() && account.wallet.is_some() && account.wallet == address;
     
     // --- CALCULATE UI VALUES BEFORE LAYOUT ---
-    let status_color = if key_is_deleted { "var(--text-secondary)" } else { "var(--text-secondary)" };
-    let status_text = if key_is_deleted { "PURGED // KEY_OFF_DEVICE" } else { "ACTIVE // KEY_ON_DEVICE" };
+    // Nag until the mnemonic backup has been checked; irrelevant once the key is off the device
+    let backup_unverified = !key_is_deleted && !*btc_ctx.backup_verified.read();
+    let status_color = if backup_unverified || scan_failed { "var(--status-warn)" } else { "var(--text-secondary)" };
+    let status_text = if key_is_deleted {
+        "PURGED // KEY_OFF_DEVICE"
+    } else if backup_unverified {
+        "ACTIVE // BACKUP_UNVERIFIED // RECHECK_WRITTEN_SEED"
+    } else {
+        "ACTIVE // KEY_ON_DEVICE"
+    };
 
     let rates = global.rates.read();
     let btc_usd_rate = rates.get("BTC/USD").copied().unwrap_or(0.0) as f64;
@@ -59,7 +72,10 @@ pub fn view() -> Element {
                 recipient: None, 
                 amount: None,
                 fee: "".to_string(),
-                asset: "BTC".to_string()
+                fee_rate: "".to_string(),
+                fee_target: None,
+                asset: "BTC".to_string(),
+                inputs: Vec::new(),
             });
         });
     });
@@ -98,7 +114,7 @@ pub fn view() -> Element {
         frac_part,
         formatted_raw_btc,
         status_color.to_string(),
-        status_text.to_string(),
+        if scan_failed { format!("{} // ACCOUNT_SCAN_FAILED", status_text) } else { status_text.to_string() },
         "BITCOIN // CORE_MAINNET".to_string(),
         send_btn,
         receive_btn,
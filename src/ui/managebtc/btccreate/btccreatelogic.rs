@@ -1,31 +1,20 @@
 // src/ui/managebtc/btccreate/btccreatelogic.rs
 
-use std::str::FromStr;
 use tokio::sync::mpsc::Sender;
-use bitcoin::bip32::{Xpriv, DerivationPath};
-use bitcoin::{Network, CompressedPublicKey};
+use bitcoin::bip32::{Fingerprint, Xpriv, Xpub};
+use bitcoin::Network;
 use bitcoin::secp256k1::Secp256k1;
-use bitcoin::address::Address;
 use bip39::{Language, Mnemonic};
 // Removed: keyring::Entry
-use arboard::Clipboard;
 use zeroize::{Zeroize, Zeroizing};
-use serde::Serialize;
 
-use crate::encrypt::encrypt_data;
-use crate::utils::json_storage::write_json;
+use crate::btcaccount::{AddressType, KeyChain};
+use crate::keystore::{self, KeystoreRecord};
+use crate::secmem::SecretString;
 use crate::channel::{
     CHANNEL, WSCommand, ProgressState, BTCModalState, BTCActiveView, BTCWalletProcessState
 };
 
-#[derive(Serialize)]
-struct EncryptedWalletData {
-    address: String,
-    encrypted_phrase: String,
-    salt: String,
-    iv: String,
-}
-
 pub struct BTCCreateLogic;
 
 impl BTCCreateLogic {
@@ -33,6 +22,7 @@ impl BTCCreateLogic {
         mnemonic_phrase: Zeroizing<String>,
         bip39_pass: Zeroizing<String>,
         encryption_pass: Zeroizing<String>,
+        address_type: AddressType,
         ws_tx: Sender<WSCommand>,
     ) {
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
@@ -40,20 +30,17 @@ impl BTCCreateLogic {
             message: "Finalizing wallet creation...".to_string(),
         }));
 
-        let m_thread = mnemonic_phrase.clone();
-        let b_thread = bip39_pass.clone();
-        let e_thread = encryption_pass.clone();
+        // A single locked copy of each secret, moved into the worker thread
+        let m_thread = SecretString::from(mnemonic_phrase);
+        let b_thread = SecretString::from(bip39_pass);
+        let e_thread = SecretString::from(encryption_pass);
 
-        let crypto_result = tokio::task::spawn_blocking(move || -> Result<(String, String, String, String), String> {
+        let crypto_result = tokio::task::spawn_blocking(move || -> Result<(KeystoreRecord, Xpub, Fingerprint), String> {
             let mnemonic = Mnemonic::parse_in(Language::English, m_thread.as_str())
                 .map_err(|e| format!("Invalid generated mnemonic: {}", e))?;
 
-            let (enc, salt, iv) = encrypt_data(e_thread, m_thread)
-                .map_err(|e| format!("Encryption failed: {}", e))?;
-
             let mut seed = mnemonic.to_seed(b_thread.as_str());
             let network = Network::Bitcoin;
-            let secp = Secp256k1::new();
 
             let xpriv = Xpriv::new_master(network, &seed)
                 .map_err(|e| {
@@ -62,37 +49,28 @@ impl BTCCreateLogic {
                 })?;
 
             seed.zeroize();
+            let fingerprint = xpriv.fingerprint(&Secp256k1::new());
 
-            let derivation_path = DerivationPath::from_str("m/84'/0'/0'/0/0")
-                .map_err(|_| "Invalid derivation path".to_string())?;
-            
-            let child_xpriv = xpriv.derive_priv(&secp, &derivation_path)
-                .map_err(|e| format!("Derivation failed: {}", e))?;
+            // Later receive addresses are derived from the account xpub, no passphrase needed
+            let account_xpub = crate::btcaccount::account_xpub(&xpriv, address_type)?;
+            let address = crate::btcaccount::address_at(&account_xpub, address_type, KeyChain::Receive, 0)?;
 
-            let public_key = child_xpriv.to_priv().public_key(&secp);
-            let compressed_pubkey = CompressedPublicKey(public_key.inner);
-            let address = Address::p2wpkh(&compressed_pubkey, network);
-
-            Ok((address.to_string(), enc, salt, iv))
+            // Seal the mnemonic into a versioned keystore record
+            keystore::seal(address.to_string(), &e_thread, &m_thread)
+                .map(|record| (record, account_xpub, fingerprint))
+                .map_err(|e| format!("Encryption failed: {}", e))
         }).await;
 
         match crypto_result {
-            Ok(Ok((address, encrypted, salt, iv))) => {
+            Ok(Ok((record, account_xpub, fingerprint))) => {
+                let address = record.address.clone();
                 let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                     progress: 0.5,
                     message: "Saving encrypted credentials...".to_string(),
                 }));
 
-                // --- DETERMINISTIC STORAGE ---
-                let wallet_data = EncryptedWalletData {
-                    address: address.clone(),
-                    encrypted_phrase: encrypted,
-                    salt,
-                    iv,
-                };
-
                 // Recommendation: use btc_encrypt.json to avoid collision with other assets
-                if let Err(e) = write_json("btc_encrypt.json", &wallet_data) {
+                if let Err(e) = keystore::save("btc_encrypt.json", &record) {
                     let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                         progress: 1.0,
                         message: format!("File System Error: {}", e),
@@ -100,26 +78,40 @@ impl BTCCreateLogic {
                     return; 
                 }
 
+                // Step 2 already checked the written backup
+                if let Err(e) = crate::wallet::set_backup_verified("btc.json", &address) {
+                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                        progress: 1.0,
+                        message: format!("Error: Could not record backup verification: {}", e),
+                    }));
+                }
+                let _ = CHANNEL.btc_backup_verified_tx.send(true);
+
+                if let Err(e) = crate::btcaccount::track(&account_xpub, fingerprint, address_type) {
+                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                        progress: 1.0,
+                        message: format!("Error: Could not record BTC account xpub: {}", e),
+                    }));
+                }
+                crate::wallet::spawn_bitcoin_refresh(address.clone());
+
                 let _ = ws_tx.try_send(WSCommand {
                     command: "import_bitcoin_wallet".to_string(),
                     wallet: Some(address),
                     recipient: None,
                     amount: None,
-                    passphrase: None,
                     trustline_limit: None,
                     fee: None,
                     tx_type: None,
                     taker_pays: None,
                     taker_gets: None,
-                    seed: None,
                     flags: None,
                     wallet_type: None,
-                    bip39: None,
+                    signed_tx: None,
+                    tx_hash: None,
                 });
 
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::clipboard::clear();
 
                 let _ = CHANNEL.btc_wallet_process_tx.send(BTCWalletProcessState {
                     import_wallet: None,
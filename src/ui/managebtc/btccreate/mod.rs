@@ -3,10 +3,10 @@ use dioxus_native::prelude::*;
 use crate::context::BtcContext;
 use crate::channel::{BTCActiveView}; 
 use crate::utils::styles;
-use arboard::Clipboard;  
 
 pub mod step1;
 pub mod step2;
+pub mod step3;
 pub mod btccreatelogic; 
 
 #[component]
@@ -20,17 +20,17 @@ pub fn view() -> Element {
     let modal_state = btc_wallet_process.read();
     
     let on_back_click = move |_| {
-        if let Ok(mut ctx) = Clipboard::new() {
-            let _ = ctx.set_text("");
-        }
+        crate::clipboard::clear();
 
         // 1. Update the wallet data (Step back or Clear)
         btc_wallet_process.with_mut(|state| {
             if let Some(ref mut create) = state.create_wallet {
-                if create.step == 1 {
+                // Nothing to step back to without a seed, e.g. when deriving from the master seed
+                if create.step == 1 || create.seed.is_none() {
                     state.create_wallet = None; 
                 } else {
-                    create.step = 1;
+                    create.step -= 1;
+                    create.error = None;
                 }
             }
         });
@@ -80,6 +80,7 @@ pub fn view() -> Element {
                     match create_state.step {
                         1 => rsx! { step1::view {} },
                         2 => rsx! { step2::view {} },
+                        3 => rsx! { step3::view {} },
                         _ => rsx! {}
                     }
                 }
@@ -1,11 +1,15 @@
 //src/ui/managebtc/btccreate/step1.rs
-//dependent upon src/utils/create_seed_layout.rs
+//dependent upon src/utils/create_seed_layout.rs and src/utils/slip39_layout.rs
 
 use dioxus_native::prelude::*;
 use crate::context::{BtcContext, GlobalContext};
 use crate::channel::BTCWalletProcessState;
 use crate::utils::create_seed_layout::CreateSeedForm; // The new centralized layout
-use arboard::Clipboard;
+use crate::utils::slip39_layout::Slip39SplitForm;
+use crate::utils::seed_entropy_layout::SeedEntropyPanel;
+use crate::entropy::{self, EntropySource, MixReport};
+use crate::clipboard::AddressChain;
+use zeroize::Zeroizing;
 
 #[component]
 pub fn view() -> Element {
@@ -16,6 +20,19 @@ pub fn view() -> Element {
     let _ = global.theme_user.read().0;
     
     let mut btc_wallet_process = btc_ctx.btc_wallet_process;
+
+    // Start in SLIP-39 mode when coming back to shares that were already generated
+    let mut slip39_mode = use_signal(|| {
+        btc_wallet_process.peek().create_wallet.as_ref().map(|c| c.shares.is_some()).unwrap_or(false)
+    });
+    let group_threshold = use_signal(|| "1".to_string());
+    let group_spec = use_signal(|| "2/3".to_string());
+    let print_path = use_signal(|| crate::paper::default_path(AddressChain::Btc));
+    let print_entropy = use_signal(|| false);
+    let mut print_status = use_signal(|| None::<String>);
+    let entropy_source = use_signal(EntropySource::default);
+    let entropy_rolls = use_signal(String::new);
+    let mut mix_report = use_signal(|| None::<MixReport>);
     
     // Extract words from Bitcoin-specific context
     let words: Vec<String> = btc_wallet_process.read().create_wallet
@@ -24,32 +41,159 @@ pub fn view() -> Element {
         .map(|s| s.split_whitespace().map(|w| w.to_string()).collect())
         .unwrap_or_default();
 
+    let word_count = words.len();
+
+    let shares: Vec<String> = btc_wallet_process.read().create_wallet
+        .as_ref()
+        .and_then(|s| s.shares.as_ref())
+        .map(|s| s.to_vec())
+        .unwrap_or_default();
+
     // Local Logic: Bitcoin State Transition
-    let on_next_click = move |_| {
+    let on_continue = move |_| {
         btc_wallet_process.with_mut(|state: &mut BTCWalletProcessState| {
             if let Some(ref mut create) = state.create_wallet {
+                create.error = None;
                 create.step = 2;
             }
         });
     };
 
     // Local Logic: Clipboard Handling
-    let on_copy_click = move |_| {
-        if let Ok(mut ctx) = Clipboard::new() {
-            if let Some(ref create) = btc_wallet_process.read().create_wallet {
-                if let Some(ref seed) = create.seed {
-                    let _ = ctx.set_text(seed.to_string());
-                }
+    let on_copy = move |_| {
+        if let Some(ref create) = btc_wallet_process.read().create_wallet {
+            if let Some(ref seed) = create.seed {
+                crate::clipboard::copy_secret(seed);
             }
         }
     };
 
+    let on_print = move |_| {
+        let (seed, address_type) = btc_wallet_process.read().create_wallet.as_ref()
+            .map(|c| (c.seed.clone(), c.address_type))
+            .unwrap_or_default();
+        let Some(seed) = seed else { return; };
+        let path = print_path().trim().to_string();
+        if path.is_empty() {
+            print_status.set(Some("ERR: OUTPUT_PATH_REQUIRED".to_string()));
+            return;
+        }
+
+        let include_entropy = print_entropy();
+        print_status.set(Some("WRITING...".to_string()));
+        spawn(async move {
+            let target = path.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                crate::paper::write_svg(std::path::Path::new(&target), AddressChain::Btc, address_type, &seed, include_entropy)
+            }).await;
+            print_status.set(Some(match result {
+                Ok(Ok(())) => format!("SHEET_WRITTEN: {} // PRINT_THEN_DELETE_THE_FILE", path),
+                Ok(Err(e)) => format!("ERR: {}", e),
+                Err(_) => "ERR: Internal thread error".to_string(),
+            }));
+        });
+    };
+
+    // Swaps in a freshly generated phrase; anything derived from the old one is dropped
+    let mut replace_seed = move |result: Result<(Zeroizing<String>, Option<MixReport>), String>| {
+        btc_wallet_process.with_mut(|state| {
+            if let Some(ref mut create) = state.create_wallet {
+                match result {
+                    Ok((seed, report)) => {
+                        create.seed = Some(seed);
+                        create.shares = None;
+                        create.error = None;
+                        mix_report.set(report);
+                        print_status.set(None);
+                    }
+                    Err(e) => create.error = Some(format!("ERR: {}", e)),
+                }
+            }
+        });
+    };
+
+    let on_word_count = move |count: usize| {
+        replace_seed(entropy::mixed_mnemonic(count, None));
+    };
+
+    let on_mix = move |_| {
+        let rolls = entropy_rolls();
+        replace_seed(entropy::mixed_mnemonic(word_count, Some((entropy_source(), &rolls))));
+    };
+
+    let on_generate = move |_| {
+        let seed = btc_wallet_process.read().create_wallet.as_ref().and_then(|c| c.seed.clone());
+        let Some(seed) = seed else { return; };
+
+        let result = group_threshold().trim().parse::<u8>()
+            .map_err(|_| "GROUPS_REQUIRED must be a number".to_string())
+            .and_then(|threshold| Ok((threshold, crate::slip39::parse_groups(&group_spec())?)))
+            .and_then(|(threshold, groups)| crate::slip39::split_mnemonic(&seed, threshold, &groups));
+
+        btc_wallet_process.with_mut(|state| {
+            if let Some(ref mut create) = state.create_wallet {
+                match result {
+                    Ok(groups) => {
+                        let flat: Vec<String> = groups.into_iter().flatten().map(|s| s.to_string()).collect();
+                        create.shares = Some(Zeroizing::new(flat));
+                        create.error = None;
+                    }
+                    Err(e) => create.error = Some(format!("ERR: {}", e)),
+                }
+            }
+        });
+    };
+
+    let on_use_bip39 = move |_| {
+        slip39_mode.set(false);
+        btc_wallet_process.with_mut(|state| {
+            if let Some(ref mut create) = state.create_wallet {
+                create.shares = None;
+                create.error = None;
+            }
+        });
+    };
+
+    let network_label = if btc_wallet_process.read().create_wallet.as_ref().map(|c| c.unified).unwrap_or(false) {
+        "UNIFIED_XRP_BTC"
+    } else {
+        "BITCOIN_MAINNET"
+    };
+    let current_error = btc_wallet_process.read().create_wallet.as_ref().and_then(|c| c.error.clone());
+
     rsx! {
-        CreateSeedForm {
-            network_label: "BITCOIN_MAINNET".to_string(),
-            words: words,
-            on_copy: on_copy_click,
-            on_continue: on_next_click,
+        if slip39_mode() {
+            Slip39SplitForm {
+                network_label: network_label.to_string(),
+                group_threshold,
+                group_spec,
+                shares,
+                current_error,
+                on_generate,
+                on_use_bip39,
+                on_continue,
+            }
+        } else {
+            CreateSeedForm {
+                network_label: network_label.to_string(),
+                words: words,
+                on_copy: on_copy,
+                on_slip39: move |_| slip39_mode.set(true),
+                print_path,
+                print_entropy,
+                print_status: print_status(),
+                on_print,
+                on_continue: on_continue,
+                SeedEntropyPanel {
+                    word_count,
+                    on_word_count,
+                    source: entropy_source,
+                    rolls: entropy_rolls,
+                    report: mix_report(),
+                    current_error: current_error.clone(),
+                    on_mix,
+                }
+            }
         }
     }
-}
\ No newline at end of file
+}
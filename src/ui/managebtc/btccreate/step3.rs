@@ -0,0 +1,98 @@
+//src/ui/managebtc/btccreate/step3.rs
+//dependent upon src/utils/wallet_security_layout.rs
+
+use dioxus_native::prelude::*;
+use crate::context::{GlobalContext, BtcContext};
+use crate::ui::managebtc::btccreate::btccreatelogic::BTCCreateLogic;
+use crate::ui::unifiedlogic::UnifiedLogic;
+use crate::utils::wallet_security_layout::WalletSecurityForm;
+use crate::utils::address_type_layout::AddressTypePicker;
+use crate::clipboard::AddressChain;
+use zeroize::Zeroizing;
+
+#[component]
+pub fn view() -> Element {
+    let global = use_context::<GlobalContext>();
+    let mut btc_ctx = use_context::<BtcContext>();
+    let _ = global.theme_user.read().0;
+    
+    let mut bip39_buffer = use_signal(|| String::new());
+    let mut encryption_buffer = use_signal(|| String::new());
+
+    let on_create_click = move |_| {
+        let b_pass = Zeroizing::new(bip39_buffer().trim().to_string());
+        let e_pass = Zeroizing::new(encryption_buffer().trim().to_string());
+        
+        let (seed_opt, unified, address_type) = btc_ctx.btc_wallet_process.read()
+            .create_wallet.as_ref()
+            .map(|w| (w.seed.clone(), w.unified, w.address_type))
+            .unwrap_or_default();
+
+        // A unified flow without a seed re-derives this chain from the existing master seed
+        if seed_opt.is_some() || unified {
+            if e_pass.len() < 10 {
+                 btc_ctx.btc_wallet_process.with_mut(|state| {
+                    if let Some(ref mut create) = state.create_wallet {
+                        create.error = Some("ERR: MIN_10_CHARS_REQUIRED".to_string());
+                    }
+                });
+                return;
+            }
+            let ws_tx = global.ws_tx.clone();
+            match seed_opt {
+                Some(seed_guard) if unified => { tokio::spawn(UnifiedLogic::create(seed_guard, b_pass, e_pass, ws_tx)); }
+                Some(seed_guard) => { tokio::spawn(BTCCreateLogic::process(seed_guard, b_pass, e_pass, address_type, ws_tx)); }
+                None if crate::master::master_exists() => {
+                    tokio::spawn(UnifiedLogic::attach(AddressChain::Btc, b_pass, e_pass, ws_tx));
+                }
+                None => {}
+            }
+        }
+
+        bip39_buffer.set(String::new());
+        encryption_buffer.set(String::new());
+        
+        btc_ctx.btc_wallet_process.with_mut(|state| {
+            if let Some(ref mut create) = state.create_wallet {
+                create.seed = None; 
+                create.error = None;
+            }
+        });
+    };
+
+    let create_state = btc_ctx.btc_wallet_process.read();
+    let current_error = create_state.create_wallet.as_ref().and_then(|i| i.error.clone());
+    let (network_label, action_label) = match create_state.create_wallet.as_ref().map(|c| (c.unified, c.seed.is_some())) {
+        Some((true, true)) => ("UNIFIED_XRP_BTC", "INIT_UNIFIED_CREATION"),
+        Some((true, false)) => ("MASTER_SEED // BITCOIN_MAINNET", "DERIVE_BTC_FROM_MASTER"),
+        _ => ("BITCOIN_MAINNET", "INIT_BTC_CREATION"),
+    };
+
+    // Unified wallets derive the native SegWit account alongside XRP
+    let unified = create_state.create_wallet.as_ref().is_some_and(|c| c.unified);
+    let address_type = create_state.create_wallet.as_ref().map(|c| c.address_type).unwrap_or_default();
+    let picker = rsx! {
+        if !unified {
+            AddressTypePicker {
+                address_type,
+                on_select: move |choice| btc_ctx.btc_wallet_process.with_mut(|state| {
+                    if let Some(ref mut create) = state.create_wallet { create.address_type = choice; }
+                }),
+            }
+        }
+    };
+
+    rsx! {
+        WalletSecurityForm {
+            flow_label: "CREATION".to_string(),
+            step: 3,
+            network_label: network_label.to_string(),
+            action_label: action_label.to_string(),
+            bip39_buffer,
+            encryption_buffer,
+            current_error,
+            on_action_click: on_create_click,
+            extra: picker,
+        }
+    }
+}
\ No newline at end of file
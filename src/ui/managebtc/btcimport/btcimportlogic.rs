@@ -1,32 +1,19 @@
 // src/ui/managebtc/btcimport/btcimportlogic.rs
 
-use std::str::FromStr;
 use tokio::sync::mpsc::Sender;
-use bitcoin::bip32::{Xpriv, DerivationPath};
-use bitcoin::{Network, CompressedPublicKey};
+use bitcoin::bip32::{Fingerprint, Xpriv, Xpub};
+use bitcoin::Network;
 use bitcoin::secp256k1::Secp256k1;
-use bitcoin::address::Address;
-use bip39::{Language, Mnemonic};
 // Removed: keyring::Entry
-use arboard::Clipboard;
 use zeroize::{Zeroize, Zeroizing};
-use serde::Serialize;
 
-use crate::encrypt::encrypt_data;
-use crate::utils::json_storage::write_json; // Import your utility
+use crate::btcaccount::{AddressType, KeyChain};
+use crate::keystore::{self, KeystoreRecord};
+use crate::secmem::SecretString;
 use crate::channel::{
     CHANNEL, WSCommand, ProgressState, BTCModalState, BTCActiveView, BTCWalletProcessState
 };
 
-// Define a struct to keep the JSON structure clean
-#[derive(Serialize)]
-struct EncryptedWalletData {
-    address: String,
-    encrypted_phrase: String,
-    salt: String,
-    iv: String,
-}
-
 pub struct BTCImportLogic;
 
 impl BTCImportLogic {
@@ -34,6 +21,7 @@ impl BTCImportLogic {
         mnemonic_phrase: Zeroizing<String>,
         bip39_pass: Zeroizing<String>,
         encryption_pass: Zeroizing<String>,
+        address_type: AddressType,
         ws_tx: Sender<WSCommand>,
     ) {
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
@@ -41,21 +29,24 @@ impl BTCImportLogic {
             message: "Starting Bitcoin wallet import...".to_string(),
         }));
 
-        let m_thread = mnemonic_phrase.clone();
-        let b_thread = bip39_pass.clone();
-        let e_thread = encryption_pass.clone();
+        // A single locked copy of each secret, moved into the worker thread
+        let m_thread = SecretString::from(mnemonic_phrase);
+        let b_thread = SecretString::from(bip39_pass);
+        let e_thread = SecretString::from(encryption_pass);
 
-        let crypto_result = tokio::task::spawn_blocking(move || -> Result<(String, String, String, String), String> {
-            let mnemonic = Mnemonic::parse_in(Language::English, m_thread.as_str())
-                .map_err(|e| format!("Invalid mnemonic: {}", e))?;
+        let crypto_result = tokio::task::spawn_blocking(move || -> Result<(KeystoreRecord, Xpub, Fingerprint), String> {
+            // Shares entered in SLIP-39 mode are combined back into the original mnemonic first
+            let m_thread = if crate::slip39::looks_like_shares(&m_thread) {
+                SecretString::from(crate::slip39::recover_mnemonic(&m_thread)?)
+            } else {
+                m_thread
+            };
 
-            // Your AES-256 logic
-            let (enc, salt, iv) = encrypt_data(e_thread, m_thread)
-                .map_err(|e| format!("Encryption failed: {}", e))?;
+            // Any BIP39 language the import form recognised
+            let mnemonic = crate::mnemonic::parse_any(m_thread.as_str())?;
 
             let mut seed = mnemonic.to_seed(b_thread.as_str());
             let network = Network::Bitcoin;
-            let secp = Secp256k1::new();
 
             let xpriv = Xpriv::new_master(network, &seed)
                 .map_err(|e| {
@@ -64,44 +55,54 @@ impl BTCImportLogic {
                 })?;
 
             seed.zeroize();
+            let fingerprint = xpriv.fingerprint(&Secp256k1::new());
 
-            let derivation_path = DerivationPath::from_str("m/84'/0'/0'/0/0")
-                .map_err(|_| "Invalid derivation path".to_string())?;
-            
-            let child_xpriv = xpriv.derive_priv(&secp, &derivation_path)
-                .map_err(|e| format!("Derivation failed: {}", e))?;
-
-            let public_key = child_xpriv.to_priv().public_key(&secp);
-            let compressed_pubkey = CompressedPublicKey(public_key.inner);
-            let address = Address::p2wpkh(&compressed_pubkey, network);
+            // Later receive addresses are derived from the account xpub, no passphrase needed
+            let account_xpub = crate::btcaccount::account_xpub(&xpriv, address_type)?;
+            let address = crate::btcaccount::address_at(&account_xpub, address_type, KeyChain::Receive, 0)?;
 
-            Ok((address.to_string(), enc, salt, iv))
+            // Seal the mnemonic into a versioned keystore record
+            keystore::seal(address.to_string(), &e_thread, &m_thread)
+                .map(|record| (record, account_xpub, fingerprint))
+                .map_err(|e| format!("Encryption failed: {}", e))
         }).await;
 
         match crypto_result {
-            Ok(Ok((address, encrypted, salt, iv))) => {
+            Ok(Ok((record, account_xpub, fingerprint))) => {
+                let address = record.address.clone();
                 let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                     progress: 0.5,
                     message: "Saving encrypted credentials...".to_string(),
                 }));
 
-                // --- NEW DETERMINISTIC STORAGE LOGIC ---
-                let wallet_data = EncryptedWalletData {
-                    address: address.clone(),
-                    encrypted_phrase: encrypted,
-                    salt,
-                    iv,
-                };
-
                 // This uses your utility to write to 'encrypt.json' 
                 // It overwrites if it already exists per your requirement.
-                if let Err(e) = write_json("btc_encrypt.json", &wallet_data) {
+                if let Err(e) = keystore::save("btc_encrypt.json", &record) {
                     let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                         progress: 1.0,
                         message: format!("File System Error: {}", e),
                     }));
                     return; 
                 }
+
+                // An imported mnemonic was typed in from the backup itself
+                if let Err(e) = crate::wallet::set_backup_verified("btc.json", &address) {
+                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                        progress: 1.0,
+                        message: format!("Error: Could not record backup verification: {}", e),
+                    }));
+                }
+                let _ = CHANNEL.btc_backup_verified_tx.send(true);
+
+                if let Err(e) = crate::btcaccount::track(&account_xpub, fingerprint, address_type) {
+                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                        progress: 1.0,
+                        message: format!("Error: Could not record BTC account xpub: {}", e),
+                    }));
+                }
+                crate::wallet::spawn_bitcoin_refresh(address.clone());
+
+                crate::audit::record(crate::audit::AuditEvent::WalletImported, format!("BTC {}", address));
                 // ----------------------------------------
 
                 let _ = ws_tx.try_send(WSCommand {
@@ -109,21 +110,18 @@ impl BTCImportLogic {
                     wallet: Some(address),
                     recipient: None,
                     amount: None,
-                    passphrase: None,
                     trustline_limit: None,
                     fee: None,
                     tx_type: None,
                     taker_pays: None,
                     taker_gets: None,
-                    seed: None,
                     flags: None,
                     wallet_type: None,
-                    bip39: None,
+                    signed_tx: None,
+                    tx_hash: None,
                 });
 
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::clipboard::clear();
 
                 let _ = CHANNEL.btc_wallet_process_tx.send(BTCWalletProcessState {
                     import_wallet: None,
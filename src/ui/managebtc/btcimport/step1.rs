@@ -1,10 +1,11 @@
 //src/ui/managebtc/btcimport/step1.rs
-//dependent upon src/utils/import_seed_layout.rs
+//dependent upon src/utils/import_seed_layout.rs and src/utils/slip39_layout.rs
 
 use dioxus_native::prelude::*;
 use crate::context::{BtcContext, GlobalContext};
 use crate::channel::BTCWalletProcessState;
 use crate::utils::import_seed_layout::ImportSeedForm;
+use crate::utils::slip39_layout::Slip39ImportForm;
 use zeroize::Zeroizing;
 
 #[component]
@@ -18,34 +19,73 @@ pub fn view() -> Element {
     let mut btc_wallet_process = btc_ctx.btc_wallet_process;
     let seed_words = use_signal(|| vec![String::new(); 24]);
     let mut error_msg = use_signal(|| None::<String>);
+    let mut slip39_mode = use_signal(|| false);
+    let share_inputs = use_signal(|| vec![String::new(); 2]);
 
     let on_continue = move |_| {
-        let current_words = seed_words.read().clone();
-        let word_count = current_words.iter().filter(|w| !w.is_empty()).count();
+        let seed_phrase = match crate::mnemonic::resolve_phrase(&seed_words.read()) {
+            Ok(phrase) => phrase,
+            Err(e) => {
+                error_msg.set(Some(e));
+                return;
+            }
+        };
+
+        btc_wallet_process.with_mut(|state: &mut BTCWalletProcessState| {
+            if let Some(ref mut import) = state.import_wallet {
+                import.seed = Some(seed_phrase);
+                import.error = None;
+                import.step = 2; 
+            }
+        });
+    };
+
+    // SLIP-39: the shares travel to the import logic as one share per line and are combined there
+    let on_recover = move |_| {
+        let shares: Zeroizing<Vec<String>> = Zeroizing::new(share_inputs.read().iter()
+            .map(|s| s.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|s| !s.is_empty())
+            .collect());
 
-        if word_count != 24 {
-            error_msg.set(Some("MNEMONIC_INTEGRITY_CHECK_FAILED: 24_WORDS_REQUIRED".to_string()));
+        if shares.is_empty() {
+            error_msg.set(Some("SHARE_REQUIRED".to_string()));
             return;
         }
+        for (i, share) in shares.iter().enumerate() {
+            if let Err(e) = crate::slip39::decode_share(share) {
+                error_msg.set(Some(format!("SHARE_{:02}: {}", i + 1, e)));
+                return;
+            }
+        }
 
-        let seed_phrase = Zeroizing::new(current_words.join(" "));
-
+        let joined = Zeroizing::new(shares.join("\n"));
         btc_wallet_process.with_mut(|state: &mut BTCWalletProcessState| {
             if let Some(ref mut import) = state.import_wallet {
-                import.seed = Some(seed_phrase);
+                import.seed = Some(joined);
                 import.error = None;
-                import.step = 2; 
+                import.step = 2;
             }
         });
     };
 
     rsx! {
-        ImportSeedForm {
-            // Pass in the BTC-specific label to dynamically render the header
-            network_label: "BITCOIN_MAINNET".to_string(),
-            seed_words: seed_words,
-            error_msg: error_msg,
-            on_continue: on_continue,
+        if slip39_mode() {
+            Slip39ImportForm {
+                network_label: "BITCOIN_MAINNET".to_string(),
+                share_inputs,
+                error_msg,
+                on_use_bip39: move |_| { error_msg.set(None); slip39_mode.set(false); },
+                on_continue: on_recover,
+            }
+        } else {
+            ImportSeedForm {
+                // Pass in the BTC-specific label to dynamically render the header
+                network_label: "BITCOIN_MAINNET".to_string(),
+                seed_words: seed_words,
+                error_msg: error_msg,
+                on_slip39: move |_| { error_msg.set(None); slip39_mode.set(true); },
+                on_continue: on_continue,
+            }
         }
     }
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use dioxus_native::prelude::*;
 use crate::context::{GlobalContext, BtcContext};
 use crate::ui::managebtc::btcimport::btcimportlogic::BTCImportLogic;
 use crate::utils::wallet_security_layout::WalletSecurityForm;
+use crate::utils::address_type_layout::AddressTypePicker;
 use zeroize::Zeroizing;
 
 #[component]
@@ -19,7 +20,10 @@ pub fn view() -> Element {
         let b_pass = Zeroizing::new(bip39_buffer().trim().to_string());
         let e_pass = Zeroizing::new(encryption_buffer().trim().to_string());
         
-        let seed_opt = btc_ctx.btc_wallet_process.read().import_wallet.as_ref().and_then(|w| w.seed.clone());
+        let (seed_opt, address_type) = btc_ctx.btc_wallet_process.read()
+            .import_wallet.as_ref()
+            .map(|w| (w.seed.clone(), w.address_type))
+            .unwrap_or_default();
 
         if let Some(seed_guard) = seed_opt {
             if seed_guard.is_empty() || e_pass.len() < 10 {
@@ -28,7 +32,7 @@ pub fn view() -> Element {
                 });
                 return;
             }
-            tokio::spawn(BTCImportLogic::process(seed_guard, b_pass, e_pass, global.ws_tx.clone()));
+            tokio::spawn(BTCImportLogic::process(seed_guard, b_pass, e_pass, address_type, global.ws_tx.clone()));
         }
 
         bip39_buffer.set(String::new());
@@ -38,16 +42,29 @@ pub fn view() -> Element {
 
     let import_state = btc_ctx.btc_wallet_process.read();
     let current_error = import_state.import_wallet.as_ref().and_then(|i| i.error.clone());
+    let address_type = import_state.import_wallet.as_ref().map(|i| i.address_type).unwrap_or_default();
+
+    // The type the mnemonic was used with elsewhere; a different one finds no funds
+    let picker = rsx! {
+        AddressTypePicker {
+            address_type,
+            on_select: move |choice| btc_ctx.btc_wallet_process.with_mut(|s| {
+                if let Some(ref mut i) = s.import_wallet { i.address_type = choice; }
+            }),
+        }
+    };
 
     rsx! {
         WalletSecurityForm {
             flow_label: "IMPORT".to_string(),
+            step: 2,
             network_label: "BITCOIN_MAINNET".to_string(),
             action_label: "INIT_BTC_IMPORT".to_string(),
             bip39_buffer: bip39_buffer,
             encryption_buffer: encryption_buffer,
             current_error: current_error,
             on_action_click: on_import_click,
+            extra: picker,
         }
     }
 }
\ No newline at end of file
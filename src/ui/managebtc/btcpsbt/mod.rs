@@ -0,0 +1,330 @@
+// src/ui/managebtc/btcpsbt/mod.rs
+//
+// Air-gapped signing. The online install arrives here from the send flow with an unsigned PSBT
+// to export; the offline install imports it, reviews it and signs it; the online install
+// imports the signed copy again and broadcasts it.
+
+use dioxus_native::prelude::*;
+use crate::context::{BtcContext, GlobalContext};
+use crate::clipboard::AddressChain;
+use crate::signer::psbt;
+use crate::signer::session::{self, SessionLimits};
+use crate::utils::qr_layout::QrCodeSvg;
+use crate::utils::send_auth_layout::SendAuthForm;
+use crate::utils::styles::{self, terminal_action};
+
+pub mod psbtlogic;
+use psbtlogic::BTCPsbtLogic;
+
+#[component]
+pub fn view() -> Element {
+    let global = use_context::<GlobalContext>();
+    let btc_ctx = use_context::<BtcContext>();
+    let mut btc_psbt = btc_ctx.btc_psbt;
+    let mut btc_modal = btc_ctx.btc_modal;
+
+    let (_, address_opt, key_is_deleted) = btc_ctx.bitcoin_wallet.read().clone();
+    let wallet_address = address_opt.unwrap_or_else(|| "NULL_ADDR".to_string());
+    let is_dark = global.theme_user.read().0;
+
+    let state = btc_psbt.read().clone();
+    let summary = state.psbt.as_deref()
+        .and_then(|encoded| psbt::decode(encoded.as_bytes()).ok())
+        .and_then(|decoded| psbt::summarize(&decoded).ok());
+
+    let mut source = use_signal(|| String::new());
+    let mut save_path = use_signal(|| String::new());
+    let mut show_qr = use_signal(|| false);
+    let mut authorizing = use_signal(|| false);
+
+    // Auth form state, as in the send flow
+    let input_mode = use_signal(|| {
+        if session::is_active(AddressChain::Btc) { "session" } else { "passphrase" }.to_string()
+    });
+    let session_limits = use_signal(|| None::<SessionLimits>);
+    let passphrase_val = use_signal(|| String::new());
+    let bip39_val = use_signal(|| String::new());
+    let seed_words = use_signal(|| vec![String::new(); 24]);
+
+    // A newly loaded or signed PSBT starts with its suggested file name and no auth form
+    let suggested = summary.as_ref().map(psbt::default_path).unwrap_or_default();
+    use_effect(use_reactive(&suggested, move |suggested| {
+        save_path.set(suggested);
+        authorizing.set(false);
+        show_qr.set(false);
+    }));
+
+    let on_back_click = move |_| {
+        if authorizing() {
+            authorizing.set(false);
+            return;
+        }
+        crate::clipboard::clear();
+        btc_modal.with_mut(|m| {
+            m.view_type = m.last_view.clone().unwrap_or_default();
+        });
+    };
+
+    let back_button = rsx! {
+        div {
+            class: "back-button-container",
+            onclick: on_back_click,
+            styles::previous_icon_button { text_color: "#fff".to_string() }
+        }
+    };
+
+    let error_box = rsx! {
+        if let Some(err) = state.error.clone() {
+            div { class: "error-box", "SIGNAL_INTERRUPT: {err}" }
+        }
+    };
+
+    let style = rsx! {
+        style { {r#"
+            .psbt-container { display: flex; flex-direction: column; width: 100%; max-width: 800px; margin: 0 auto; font-family: 'JetBrains Mono', monospace; padding: 2rem; position: relative; }
+            .back-button-container { position: absolute; top: 0.75rem; left: 0.75rem; cursor: pointer; z-index: 10; }
+            .step-header { border-bottom: 1px solid var(--border); padding-bottom: 1rem; margin-bottom: 2rem; }
+            .step-title { font-size: 0.7rem; color: var(--text-secondary); letter-spacing: 2px; }
+            .input-label { font-size: 0.65rem; color: var(--accent); border-left: 2px solid var(--accent); padding-left: 8px; margin-bottom: 0.75rem; }
+            .terminal-input-wrapper { display: flex; align-items: center; background: var(--input-bg); border: 1px solid var(--border); padding: 0.8rem 1rem; }
+            .bracket { color: var(--text-secondary); opacity: 0.4; font-weight: bold; }
+            .inner-input { flex: 1; background: transparent; border: none; outline: none; color: var(--text); font-family: inherit; font-size: 0.85rem; padding: 0 1rem; }
+            .summary-box { display: grid; grid-template-columns: 1fr; gap: 1px; background: var(--border); border: 1px solid var(--border); width: 100%; }
+            .summary-row { display: grid; grid-template-columns: 140px 1fr; background: var(--bg-grid); padding: 0.9rem 1rem; align-items: start; }
+            .row-label { font-size: 0.65rem; color: var(--accent); margin-top: 0.2rem; }
+            .row-value { font-size: 0.8rem; color: var(--text); word-break: break-all; line-height: 1.4; text-align: right; }
+            .row-tag { font-size: 0.6rem; color: var(--text-secondary); letter-spacing: 1px; }
+            .note-footer { margin-top: 2rem; padding: 1rem; border: 1px dashed var(--border); color: var(--text-secondary); font-size: 0.7rem; line-height: 1.5; }
+            .error-box { background: rgba(239, 68, 68, 0.1); border-left: 3px solid var(--status-warn); padding: 0.75rem 1rem; margin-top: 1rem; font-size: 0.75rem; color: var(--status-warn); }
+            .qr-panel { display: flex; justify-content: center; margin-top: 2rem; }
+            .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-end; align-items: center; gap: 1rem; flex-wrap: wrap; }
+        "#} }
+    };
+
+    // --- IMPORT ---
+    let Some(summary) = summary else {
+        let on_load = move |_| {
+            if source().trim().is_empty() {
+                btc_psbt.with_mut(|s| s.error = Some("ERR: PSBT_REQUIRED".to_string()));
+                return;
+            }
+            if let Err(e) = BTCPsbtLogic::load(&source()) {
+                btc_psbt.with_mut(|s| s.error = Some(e));
+            }
+        };
+
+        return rsx! {
+            {style}
+            div { class: "psbt-container",
+                {back_button}
+                div { class: "step-header",
+                    div { class: "step-title", "PSBT // IMPORT // BITCOIN_MAINNET" }
+                }
+                div { class: "input-label", "PSBT_FILE_PATH_OR_BASE64" }
+                div { class: "terminal-input-wrapper",
+                    span { class: "bracket", "[" }
+                    input {
+                        class: "inner-input",
+                        value: "{source()}",
+                        placeholder: "PATH_TO_PSBT_OR_BASE64",
+                        oninput: move |e| {
+                            source.set(e.value());
+                            btc_psbt.with_mut(|s| s.error = None);
+                        },
+                    }
+                    span { class: "bracket", "]" }
+                }
+                {error_box}
+                div { class: "note-footer",
+                    if key_is_deleted {
+                        "Load the PSBT signed on the offline install to review and broadcast it."
+                    } else {
+                        "Load a PSBT exported by a watch-only install to review and sign it with this wallet's key."
+                    }
+                }
+                div { class: "footer-nav",
+                    {terminal_action("LOAD_PSBT", true, on_load)}
+                }
+            }
+        };
+    };
+
+    let encoded = state.psbt.clone().unwrap_or_default();
+
+    // --- SIGN (offline side) ---
+    if authorizing() {
+        let encoded = encoded.clone();
+        let on_submit = move |_| {
+            let mode = input_mode();
+            let s_words = seed_words.read();
+            let mut validation_error: Option<String> = None;
+            match mode.as_str() {
+                "passphrase" => {
+                    if passphrase_val().trim().is_empty() {
+                        validation_error = Some("ERR: PASSPHRASE_REQUIRED".to_string());
+                    }
+                },
+                "seed" => {
+                    let word_count = s_words.iter().filter(|w| !w.trim().is_empty()).count();
+                    if !crate::entropy::WORD_COUNTS.contains(&word_count) {
+                        validation_error = Some("ERR: MNEMONIC_LENGTH_MISMATCH".to_string());
+                    }
+                },
+                "session" => {},
+                _ => { validation_error = Some("ERR: INVALID_MODE".to_string()); }
+            }
+            if let Some(err) = validation_error {
+                btc_psbt.with_mut(|s| s.error = Some(err));
+                return;
+            }
+
+            let seed_string = s_words.iter().filter(|w| !w.is_empty()).cloned().collect::<Vec<_>>().join(" ");
+            tokio::spawn(BTCPsbtLogic::sign(
+                mode,
+                passphrase_val(),
+                seed_string,
+                bip39_val(),
+                session_limits(),
+                encoded.clone(),
+            ));
+        };
+
+        return rsx! {
+            div { style: "position: relative; width: 100%;",
+                {back_button}
+                SendAuthForm {
+                    step_title: "PSBT // SIGNING_AUTHORIZATION".to_string(),
+                    network_label: "BITCOIN_MAINNET",
+                    chain: AddressChain::Btc,
+                    input_mode,
+                    session_limits,
+                    passphrase_val,
+                    seed_words,
+                    bip39_val,
+                    current_error: state.error.clone(),
+                    on_submit,
+                    on_clear_error: move |_| btc_psbt.with_mut(|s| s.error = None),
+                }
+            }
+        };
+    }
+
+    // --- REVIEW & EXPORT ---
+    let fully_signed = summary.fully_signed();
+    let signed_count = summary.inputs.iter().filter(|input| input.signed).count();
+    let status = if fully_signed { "SIGNED" } else { "UNSIGNED" };
+
+    let mut rows: Vec<(String, String, Option<&'static str>)> = vec![
+        ("TXID".to_string(), summary.txid.to_string(), None),
+    ];
+    for (index, input) in summary.inputs.iter().enumerate() {
+        rows.push((
+            format!("INPUT_{:02}", index),
+            format!("{} BTC // {}", input.value.to_btc(), input.address),
+            Some(if input.signed { "SIGNED" } else { "UNSIGNED" }),
+        ));
+    }
+    for (index, output) in summary.outputs.iter().enumerate() {
+        rows.push((
+            format!("OUTPUT_{:02}", index),
+            format!("{} BTC // {}", output.value.to_btc(), output.address),
+            output.change.then_some("CHANGE"),
+        ));
+    }
+    rows.push(("MINER_FEE_TOTAL".to_string(), format!("{} SATS", summary.fee.to_sat()), None));
+    rows.push(("SIGNATURES".to_string(), format!("{} / {}", signed_count, summary.inputs.len()), None));
+
+    let on_save = {
+        let encoded = encoded.clone();
+        move |_| {
+            if let Err(e) = BTCPsbtLogic::save(&save_path(), &encoded) {
+                btc_psbt.with_mut(|s| s.error = Some(e));
+            }
+        }
+    };
+    let on_broadcast = {
+        let encoded = encoded.clone();
+        let ws_tx = global.ws_tx.clone();
+        move |_| {
+            let last_v = btc_modal.read().last_view.clone();
+            if let Err(e) = BTCPsbtLogic::broadcast(encoded.clone(), wallet_address.clone(), ws_tx.clone(), last_v) {
+                btc_psbt.with_mut(|s| s.error = Some(e));
+            }
+        }
+    };
+
+    rsx! {
+        {style}
+        div { class: "psbt-container",
+            {back_button}
+            div { class: "step-header",
+                div { class: "step-title", "PSBT // {status} // BITCOIN_MAINNET" }
+            }
+
+            div { class: "summary-box",
+                for (label, value, tag) in rows {
+                    div { class: "summary-row",
+                        div { class: "row-label", "{label}" }
+                        div { class: "row-value",
+                            "{value}"
+                            if let Some(tag) = tag {
+                                div { class: "row-tag", "{tag}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { style: "margin-top: 2rem;",
+                div { class: "input-label", "EXPORT_PATH" }
+                div { class: "terminal-input-wrapper",
+                    span { class: "bracket", "[" }
+                    input {
+                        class: "inner-input",
+                        value: "{save_path()}",
+                        oninput: move |e| {
+                            save_path.set(e.value());
+                            btc_psbt.with_mut(|s| s.error = None);
+                        },
+                    }
+                    span { class: "bracket", "]" }
+                }
+            }
+            {error_box}
+
+            if show_qr() {
+                div { class: "qr-panel",
+                    QrCodeSvg { data: encoded.clone(), is_dark }
+                }
+            }
+
+            div { class: "note-footer",
+                if fully_signed {
+                    "Every input is signed. Broadcast from the online install, or export the signed PSBT to carry it back."
+                } else if key_is_deleted {
+                    "Export this PSBT to the offline install that holds the key, sign it there, then load the signed copy here to broadcast."
+                } else {
+                    "Verify every output before signing. Outputs tagged CHANGE return to this wallet."
+                }
+            }
+
+            div { class: "footer-nav",
+                {terminal_action("DISCARD", true, move |_| {
+                    btc_psbt.set(Default::default());
+                    source.set(String::new());
+                })}
+                {terminal_action(if show_qr() { "HIDE_QR" } else { "SHOW_QR" }, true, move |_| show_qr.toggle())}
+                {terminal_action("SAVE_PSBT", true, on_save)}
+                if !fully_signed && !key_is_deleted {
+                    {terminal_action("SIGN_PSBT", true, move |_| {
+                        btc_psbt.with_mut(|s| s.error = None);
+                        authorizing.set(true);
+                    })}
+                }
+                if fully_signed {
+                    {terminal_action("FINALIZE_AND_BROADCAST", true, on_broadcast)}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// src/ui/managebtc/btcpsbt/psbtlogic.rs
+
+use std::path::Path;
+use tokio::sync::mpsc::Sender;
+use crate::channel::{CHANNEL, WSCommand, ProgressState, BTCModalState, BTCSignTransactionState, BTCPsbtState, BTCActiveView};
+use zeroize::Zeroizing;
+use crate::signer::{self, psbt, Credentials, session::SessionLimits, btc::Spend};
+
+pub struct BTCPsbtLogic;
+
+fn report(progress: f32, message: String) {
+    let _ = CHANNEL.progress_tx.send(Some(ProgressState { progress, message }));
+}
+
+fn show(psbt: &bitcoin::psbt::Psbt) {
+    let _ = CHANNEL.btc_psbt_tx.send(BTCPsbtState { psbt: Some(psbt::to_base64(psbt)), error: None });
+}
+
+impl BTCPsbtLogic {
+    /// Online side: lays the reviewed send out as an unsigned PSBT and opens it for export.
+    pub async fn create(
+        recipient: String,
+        amount: String,
+        fee: String,
+        inputs: Vec<String>,
+        wallet_address: String,
+        last_view: Option<BTCActiveView>,
+    ) {
+        report(0.0, "Creating PSBT...".to_string());
+
+        let spend = match Spend::parse(&recipient, &amount, &fee).and_then(|spend| spend.with_inputs(&inputs)) {
+            Ok(spend) => spend,
+            Err(e) => return report(1.0, format!("Error: {}", e)),
+        };
+        let psbt = match signer::create_psbt(wallet_address, spend).await {
+            Ok(psbt) => psbt,
+            Err(e) => return report(1.0, format!("Error: {}", e)),
+        };
+
+        show(&psbt);
+        let _ = CHANNEL.btc_sign_transaction_tx.send(BTCSignTransactionState {
+            send_transaction: None,
+        });
+        let _ = CHANNEL.btc_modal_tx.send(BTCModalState {
+            view_type: BTCActiveView::Psbt,
+            last_view,
+        });
+        report(1.0, "PSBT created".to_string());
+    }
+
+    /// Opens a PSBT from a file path, or from base64 text pasted in its place.
+    pub fn load(source: &str) -> Result<(), String> {
+        let source = source.trim();
+        let psbt = if Path::new(source).is_file() {
+            psbt::read_file(Path::new(source))?
+        } else {
+            psbt::decode(source.as_bytes())?
+        };
+        psbt::summarize(&psbt)?;
+        show(&psbt);
+        Ok(())
+    }
+
+    pub fn save(path: &str, encoded: &str) -> Result<(), String> {
+        let psbt = psbt::decode(encoded.as_bytes())?;
+        psbt::write_file(Path::new(path.trim()), &psbt)?;
+        report(1.0, format!("PSBT saved to {}", path.trim()));
+        Ok(())
+    }
+
+    /// Offline side: signs every input with the wallet's key.
+    pub async fn sign(
+        mode: String,
+        passphrase: String,
+        mnemonic: String,
+        bip39_pass: String,
+        session: Option<SessionLimits>,
+        encoded: String,
+    ) {
+        let p_guard = Zeroizing::new(passphrase);
+        let m_guard = Zeroizing::new(mnemonic);
+        let b_guard = Zeroizing::new(bip39_pass);
+
+        report(0.0, "Signing PSBT...".to_string());
+
+        let credentials = match Credentials::from_form(&mode, p_guard, m_guard, b_guard, session) {
+            Ok(credentials) => credentials,
+            Err(e) => return report(1.0, format!("Error: {}", e)),
+        };
+        let psbt = match psbt::decode(encoded.as_bytes()) {
+            Ok(psbt) => psbt,
+            Err(e) => return report(1.0, format!("Error: {}", e)),
+        };
+        let signed = match signer::sign_psbt(credentials, psbt).await {
+            Ok(signed) => signed,
+            Err(e) => return report(1.0, format!("Error: {}", e)),
+        };
+
+        if let Ok(summary) = psbt::summarize(&signed) {
+            let detail = format!("txid={} inputs={} fee={}", summary.txid, summary.inputs.len(), summary.fee.to_sat());
+            crate::audit::record(crate::audit::AuditEvent::PsbtSigned, detail);
+        }
+        crate::clipboard::clear();
+        show(&signed);
+        report(1.0, "PSBT signed".to_string());
+    }
+
+    /// Online side: finalizes the signed PSBT and submits the transaction.
+    pub fn broadcast(
+        encoded: String,
+        wallet_address: String,
+        ws_tx: Sender<WSCommand>,
+        last_view: Option<BTCActiveView>,
+    ) -> Result<(), String> {
+        let psbt = psbt::decode(encoded.as_bytes())?;
+        let summary = psbt::summarize(&psbt)?;
+        let signed = psbt::finalize(psbt)?;
+
+        // The payment is whatever does not come back to the wallet
+        let payment = summary.outputs.iter().find(|output| !output.change);
+        let cmd = WSCommand {
+            command: "bitcoin_submit_transaction".to_string(),
+            wallet: Some(wallet_address),
+            recipient: payment.map(|output| output.address.clone()),
+            amount: payment.map(|output| output.value.to_btc().to_string()),
+            trustline_limit: None,
+            fee: Some(summary.fee.to_sat().to_string()),
+            tx_type: Some("BTC".to_string()),
+            taker_pays: None,
+            taker_gets: None,
+            flags: None,
+            wallet_type: Some("BTC".to_string()),
+            signed_tx: Some(signed.blob),
+            tx_hash: Some(signed.hash),
+        };
+
+        let summary = crate::audit::describe_command(&cmd);
+        ws_tx.try_send(cmd).map_err(|e| format!("Dispatch Error: {}", e))?;
+        crate::audit::record(crate::audit::AuditEvent::PsbtFinalized, summary);
+        let _ = CHANNEL.btc_psbt_tx.send(BTCPsbtState::default());
+        let _ = CHANNEL.btc_modal_tx.send(BTCModalState {
+            view_type: last_view.unwrap_or(BTCActiveView::BTC),
+            last_view: None,
+        });
+        Ok(())
+    }
+}
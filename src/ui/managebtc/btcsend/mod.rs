@@ -4,7 +4,6 @@ use dioxus_native::prelude::*;
 use crate::context::BtcContext;
 use crate::channel::{BTCSignTransactionState};
 use crate::utils::styles;
-use arboard::Clipboard; 
 
 pub mod step1;
 pub mod step2;
@@ -23,9 +22,7 @@ pub fn view() -> Element {
     let current_send = &sign_state.send_transaction;
 
     let on_back_click = move |_| {
-    if let Ok(mut ctx) = Clipboard::new() {
-        let _ = ctx.set_text("");
-    }
+    crate::clipboard::clear();
 
     btc_sign_transaction.with_mut(|state: &mut BTCSignTransactionState| {
         if let Some(ref mut send) = state.send_transaction {
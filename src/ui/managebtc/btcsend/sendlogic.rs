@@ -3,19 +3,33 @@
 use tokio::sync::mpsc::Sender;
 use crate::channel::{CHANNEL, WSCommand, ProgressState, BTCModalState, BTCSignTransactionState, BTCActiveView};
 use zeroize::Zeroizing;
-use arboard::Clipboard;
+use crate::signer::{self, Credentials, session::SessionLimits, btc::Spend};
 
 pub struct BTCSendLogic;
 
 impl BTCSendLogic {
+    /// Absolute fee in sats for the send at `rate` sat/vB, sized from the inputs it will spend.
+    pub async fn fee(
+        wallet_address: String,
+        recipient: String,
+        amount: String,
+        inputs: Vec<String>,
+        rate: f64,
+    ) -> Result<u64, String> {
+        let spend = Spend::parse(&recipient, &amount, "0").and_then(|spend| spend.with_inputs(&inputs))?;
+        signer::btc_fee(wallet_address, spend, rate).await.map(|fee| fee.to_sat())
+    }
+
     pub async fn process(
         mode: String,
         passphrase: String,      
         mnemonic: String,        
         bip39_pass: String,      
+        session: Option<SessionLimits>,
         recipient: String,
         amount: String,
         fee: String, 
+        inputs: Vec<String>,
         wallet_address: String,
         asset: String,
         ws_tx: Sender<WSCommand>,
@@ -32,38 +46,50 @@ impl BTCSendLogic {
             message: "Initiating transaction...".to_string(),
         }));
 
-        // 2. Prepare Optional Data (BIP39)
-        // FIX: We clone the *guard* (Zeroizing wrapper), not the raw string inside.
-        let bip39_opt = if b_guard.trim().is_empty() { 
-            None 
-        } else { 
-            Some(b_guard.clone()) 
+        // 2. Enforce XOR Logic (Passphrase vs Seed)
+        let credentials = match Credentials::from_form(&mode, p_guard, m_guard, b_guard, session) {
+            Ok(credentials) => credentials,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
-        // 3. Enforce XOR Logic (Passphrase vs Seed)
-        // FIX: We move or clone the Zeroizing guards directly.
-        let (passphrase, seed) = match mode.as_str() {
-            "passphrase" => {
-                let p = if p_guard.is_empty() { None } else { Some(p_guard.clone()) };
-                (p, None) 
-            },
-            "seed" => {
-                let s = if m_guard.trim().is_empty() { None } else { Some(m_guard.clone()) };
-                (None, s) 
-            },
-            _ => (None, None), 
+        // 3. Sign locally; only the signed blob leaves this task
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.5,
+            message: "Signing transaction...".to_string(),
+        }));
+        let spend = match Spend::parse(&recipient, &amount, &fee).and_then(|spend| spend.with_inputs(&inputs)) {
+            Ok(spend) => spend,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
+        };
+        let signed = match signer::sign_btc(wallet_address.clone(), credentials, spend).await {
+            Ok(signed) => signed,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
         // 4. Construct Command
-        // The fields in WSCommand are now Option<Zeroizing<String>>
         let cmd = WSCommand {
             command: "bitcoin_submit_transaction".to_string(),
             wallet: Some(wallet_address), 
             recipient: Some(recipient),
             amount: Some(amount),
-            passphrase, 
-            seed,       
-            bip39: bip39_opt,
             trustline_limit: None,
             fee: Some(fee),
             tx_type: Some("BTC".to_string()),
@@ -71,14 +97,16 @@ impl BTCSendLogic {
             taker_gets: None,
             flags: None,
             wallet_type: Some(asset),
+            signed_tx: Some(signed.blob),
+            tx_hash: Some(signed.hash),
         };
 
         // 5. Dispatch
+        let summary = crate::audit::describe_command(&cmd);
         match ws_tx.try_send(cmd) {
             Ok(_) => {
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::audit::record(crate::audit::AuditEvent::TransactionSigned, summary);
+                crate::clipboard::clear();
                 let _ = CHANNEL.btc_sign_transaction_tx.send(BTCSignTransactionState {
                     send_transaction: None, 
                 });
@@ -4,6 +4,7 @@
 use dioxus_native::prelude::*;
 use crate::context::BtcContext;
 use crate::utils::send_recipient_layout::SendAddressForm;
+use crate::clipboard::{self, AddressChain};
 
 #[component]
 pub fn view() -> Element {
@@ -18,8 +19,11 @@ pub fn view() -> Element {
             .unwrap_or_default()
     });
 
+    let mut paste_warning = use_signal(|| None::<String>);
+
     let on_input = move |e: FormEvent| {
         let clean_val = e.value().replace(['\n', '\r'], "");
+        paste_warning.set(clipboard::check_pasted_recipient(AddressChain::Btc, &clean_val));
         addr_buffer.set(clean_val);
         btc_sign_transaction.with_mut(|state| {
             if let Some(ref mut send) = state.send_transaction {
@@ -60,6 +64,7 @@ pub fn view() -> Element {
             address_buffer: addr_buffer,
             placeholder: "bc1...".to_string(),
             current_error: current_error,
+            paste_warning: paste_warning(),
             on_input: on_input,
             on_next_click: on_next_click,
         }
@@ -15,7 +15,9 @@ pub fn view() -> Element {
     let rates = global.rates.read();
     let exchange_rate = rates.get("BTC/USD").copied().unwrap_or(0.0) as f64;
 
-    let btc_balance = btc_ctx.bitcoin_wallet.read().0;
+    let (reported, wallet_address, _) = btc_ctx.bitcoin_wallet.read().clone();
+    // Change and later receive addresses count too, not only the wallet address
+    let btc_balance = btc_ctx.btc_account.read().balance_or(wallet_address.as_deref(), reported);
 
     let mut btc_in = use_signal(|| {
         sign_tx.read().send_transaction.as_ref()
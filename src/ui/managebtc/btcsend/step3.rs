@@ -1,20 +1,83 @@
 // src/ui/managebtc/btcsend/step3.rs
 
+use std::collections::BTreeMap;
 use dioxus_native::prelude::*;
+use bitcoin::{Amount, Denomination};
+use crate::chain::esplora;
 use crate::context::BtcContext;
+use crate::ui::managebtc::btcutxos::CoinTable;
+use crate::ui::managebtc::btcutxos::utxologic::{BTCUtxoLogic, CoinRow};
 use crate::utils::styles::terminal_action;
+use super::sendlogic::BTCSendLogic;
+
+// Lowest rate nodes relay by default, in sat/vB
+const MIN_RELAY_RATE: f64 = 1.0;
+const NORMAL_TARGET: u16 = 6;
+// Confirmation targets in blocks behind each preset
+const PRESETS: [(&str, u16); 3] = [("ECONOMY", 144), ("NORMAL", NORMAL_TARGET), ("PRIORITY", 1)];
+
+fn format_rate(rate: f64) -> String {
+    format!("{:.1}", rate.max(MIN_RELAY_RATE))
+}
 
 #[component]
 pub fn view() -> Element {
     let btc_ctx = use_context::<BtcContext>();
     let mut btc_sign_transaction = btc_ctx.btc_sign_transaction;
 
-    // Use a local buffer signal exactly like Step 1
-    let mut fee_buffer = use_signal(|| {
+    // Use a local buffer signal exactly like Step 1; the rate is in sat/vB
+    let mut rate_buffer = use_signal(|| {
         btc_sign_transaction.read()
             .send_transaction.as_ref()
-            .and_then(|s| Some(s.fee.clone()))
-            .unwrap_or_else(|| "200".to_string())
+            .map(|s| s.fee_rate.clone())
+            .unwrap_or_default()
+    });
+    let mut computing = use_signal(|| false);
+
+    let mut set_error = move |error: Option<String>| {
+        btc_sign_transaction.with_mut(|state| {
+            if let Some(ref mut send) = state.send_transaction {
+                send.error = error;
+            }
+        });
+    };
+
+    // Presets come from current estimates; the normal one fills an empty field
+    let mut estimates = use_signal(|| None::<BTreeMap<u16, f64>>);
+    use_future(move || async move {
+        match esplora::fee_estimates().await {
+            Ok(fetched) => {
+                if rate_buffer.read().is_empty() {
+                    if let Some(rate) = esplora::rate_for(&fetched, NORMAL_TARGET) {
+                        rate_buffer.set(format_rate(rate));
+                    }
+                }
+                estimates.set(Some(fetched));
+            }
+            // The rate can still be typed in by hand
+            Err(e) => set_error(Some(format!("ERR: FEE_ESTIMATES_UNAVAILABLE // {}", e))),
+        }
+    });
+
+    // Coin control: hand-picked inputs, or automatic selection when none are picked
+    let preset = btc_sign_transaction.read()
+        .send_transaction.as_ref()
+        .map(|s| s.inputs.clone())
+        .unwrap_or_default();
+    let mut manual = use_signal(|| !preset.is_empty());
+    let selected = use_signal(|| preset);
+    let mut coins = use_signal(|| None::<Vec<CoinRow>>);
+    let wallet_address = btc_ctx.bitcoin_wallet.read().1.clone().unwrap_or_default();
+    let fetch_address = wallet_address.clone();
+    use_future(move || {
+        let wallet_address = fetch_address.clone();
+        async move {
+            match BTCUtxoLogic::fetch(wallet_address).await {
+                // Frozen coins cannot be spent, so they are not offered
+                Ok(rows) => coins.set(Some(rows.into_iter().filter(|row| !row.frozen).collect())),
+                Err(e) => set_error(Some(format!("ERR: COINS_UNAVAILABLE // {}", e))),
+            }
+        }
     });
 
     let current_error = btc_sign_transaction.read()
@@ -22,35 +85,73 @@ pub fn view() -> Element {
         .and_then(|s| s.error.clone());
 
     let on_next_click = move |_| {
-        let fee_str = fee_buffer().trim().to_string();
-        
-        match fee_str.parse::<u64>() {
-            Ok(fee_val) => {
-                if fee_val < 200 {
-                    btc_sign_transaction.with_mut(|state| {
-                        if let Some(ref mut send) = state.send_transaction {
-                            send.error = Some("ERR: BELOW_MINIMUM_RELAY_FEE".to_string());
-                        }
-                    });
-                } else {
-                    btc_sign_transaction.with_mut(|state| {
-                        if let Some(ref mut send) = state.send_transaction {
-                            send.fee = fee_val.to_string();
-                            send.step = 4; 
-                            send.error = None;
-                        }
-                    });
-                }
-            }
-            Err(_) => {
-                btc_sign_transaction.with_mut(|state| {
+        if computing() {
+            return;
+        }
+        let rate = match rate_buffer().trim().parse::<f64>() {
+            Ok(rate) if rate.is_finite() => rate,
+            _ => return set_error(Some("ERR: INVALID_FEE_RATE".to_string())),
+        };
+        // Nodes do not relay transactions paying less than this
+        if rate < MIN_RELAY_RATE {
+            return set_error(Some("ERR: BELOW_MINIMUM_RELAY_FEE".to_string()));
+        }
+
+        let inputs = if manual() { selected() } else { Vec::new() };
+        let (recipient, amount) = btc_sign_transaction.read()
+            .send_transaction.as_ref()
+            .map(|s| (s.recipient.clone().unwrap_or_default(), s.amount.clone().unwrap_or_default()))
+            .unwrap_or_default();
+        let amount_value = Amount::from_str_in(&amount, Denomination::Bitcoin).unwrap_or(Amount::ZERO);
+        let picked: Amount = coins.read().iter().flatten()
+            .filter(|row| inputs.contains(&row.outpoint))
+            .map(|row| row.value)
+            .sum();
+        if manual() && inputs.is_empty() {
+            return set_error(Some("ERR: NO_COINS_SELECTED".to_string()));
+        }
+        if manual() && picked < amount_value {
+            return set_error(Some("ERR: SELECTED_COINS_INSUFFICIENT".to_string()));
+        }
+
+        let fee_target = estimates.read().as_ref().and_then(|estimates| esplora::target_for(estimates, rate));
+        let fee_rate = rate_buffer().trim().to_string();
+        let wallet_address = wallet_address.clone();
+        computing.set(true);
+        set_error(None);
+        spawn(async move {
+            let result = BTCSendLogic::fee(wallet_address, recipient, amount, inputs.clone(), rate).await;
+            computing.set(false);
+            match result {
+                Ok(fee) => btc_sign_transaction.with_mut(|state| {
                     if let Some(ref mut send) = state.send_transaction {
-                        send.error = Some("ERR: NON_INTEGER_VALUE".to_string());
+                        send.fee = fee.to_string();
+                        send.fee_rate = fee_rate;
+                        send.fee_target = fee_target;
+                        send.inputs = inputs;
+                        send.step = 4;
+                        send.error = None;
                     }
-                });
+                }),
+                Err(e) => set_error(Some(format!("ERR: {}", e))),
             }
-        }
+        });
+    };
+
+    // Expected confirmation of the rate as typed, against current estimates
+    let current = estimates();
+    let typed_rate = rate_buffer().trim().parse::<f64>().ok();
+    let target_hint = match (&current, typed_rate) {
+        (None, _) => "ESTIMATES_UNAVAILABLE".to_string(),
+        (Some(_), None) => "ENTER_RATE".to_string(),
+        (Some(current), Some(rate)) => match esplora::target_for(current, rate) {
+            Some(target) => format!("CONFIRMS_IN ~{} BLOCKS", target),
+            None => "BELOW_CURRENT_ESTIMATES".to_string(),
+        },
     };
+    let presets: Vec<(&str, Option<String>)> = PRESETS.iter()
+        .map(|(label, target)| (*label, current.as_ref().and_then(|current| esplora::rate_for(current, *target)).map(format_rate)))
+        .collect();
 
     rsx! {
         style { {r#"
@@ -74,6 +175,7 @@ pub fn view() -> Element {
             .inner-input { flex: 1; background: transparent; border: none; outline: none; color: var(--text); font-family: inherit; font-size: 1rem; padding: 0 1rem; }
             .unit-tag { font-size: 0.7rem; color: var(--text-secondary); padding-left: 0.5rem; }
             .error-box { background: rgba(239, 68, 68, 0.1); border-left: 3px solid var(--status-warn); padding: 0.75rem 1rem; margin-top: 1rem; font-size: 0.75rem; color: var(--status-warn); }
+            .preset-row { display: flex; gap: 1rem; flex-wrap: wrap; margin-bottom: 1rem; }
             .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-end; align-items: center; gap: 2rem; }
         "#} }
 
@@ -84,29 +186,41 @@ pub fn view() -> Element {
 
             div { class: "input-section",
                 div { class: "input-label-row",
-                    div { class: "input-label", "MINER_FEE" }
-                    div { class: "fee-info-micro", "MIN_AMOUNT: 200 SATS" }
+                    div { class: "input-label", "FEE_RATE" }
+                    div { class: "fee-info-micro", "{target_hint}" }
+                }
+                div { class: "preset-row",
+                    for (label, rate) in presets {
+                        {
+                            let active = rate.as_deref().map(|rate| rate == rate_buffer().trim()).unwrap_or(false);
+                            let text = match &rate {
+                                Some(rate) => format!("{} // {} SAT/VB", label, rate),
+                                None => format!("{} // --", label),
+                            };
+                            terminal_action(&text, active, move |_| {
+                                if let Some(rate) = rate.clone() {
+                                    rate_buffer.set(rate);
+                                    set_error(None);
+                                }
+                            })
+                        }
+                    }
                 }
                 div { class: "terminal-input-wrapper",
                     span { class: "bracket", "[" }
                     input {
                         class: "inner-input",
                         // Removed r#type: "number" as it's buggy in native
-                        value: "{fee_buffer()}",
+                        value: "{rate_buffer()}",
                         oninput: move |e| {
                             let val = e.value();
-                            // Filter only digits to prevent alpha characters in fee
-                            let clean_val: String = val.chars().filter(|c| c.is_ascii_digit()).collect();
-                            fee_buffer.set(clean_val);
-                            
-                            btc_sign_transaction.with_mut(|state| {
-                                if let Some(ref mut send) = state.send_transaction {
-                                    send.error = None;
-                                }
-                            });
+                            // Digits and a decimal point only, for fractional rates
+                            let clean_val: String = val.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
+                            rate_buffer.set(clean_val);
+                            set_error(None);
                         },
                     }
-                    span { class: "unit-tag", "SATS" }
+                    span { class: "unit-tag", "SAT/VB" }
                     span { class: "bracket", "]" }
                 }
 
@@ -115,9 +229,21 @@ pub fn view() -> Element {
                 }
             }
 
+            div { class: "input-section",
+                div { class: "input-label-row",
+                    div { class: "input-label", "INPUTS" }
+                    {terminal_action(if manual() { "MANUAL" } else { "AUTOMATIC" }, manual(), move |_| manual.toggle())}
+                }
+                if manual() {
+                    match coins() {
+                        Some(rows) => rsx! { CoinTable { rows, selected, manage: false } },
+                        None => rsx! { div { class: "fee-info-micro", "SCANNING_COINS..." } },
+                    }
+                }
+            }
+
             div { class: "footer-nav",
-               
-                {terminal_action("CONTINUE", true, on_next_click)}
+                {terminal_action(if computing() { "SIZING_TRANSACTION..." } else { "CONTINUE" }, !computing(), on_next_click)}
             }
         }
     }
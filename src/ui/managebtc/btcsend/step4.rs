@@ -20,6 +20,16 @@ pub fn view() -> Element {
     let recipient = send_data.and_then(|s| s.recipient.clone()).unwrap_or_else(|| "NULL".into());
     let amount = send_data.and_then(|s| s.amount.clone()).unwrap_or_else(|| "0.00".into());
     let fee = send_data.and_then(|s| Some(s.fee.clone())).unwrap_or_else(|| "0".into());
+    let fee_rate = send_data.map(|s| s.fee_rate.clone()).unwrap_or_default();
+    // Blocks come about every ten minutes
+    let target = match send_data.and_then(|s| s.fee_target) {
+        Some(blocks) => format!("~{} BLOCKS (~{} MIN)", blocks, blocks as u32 * 10),
+        None => "NOT_ESTIMATED".to_string(),
+    };
+    let inputs = match send_data.map(|s| s.inputs.len()).unwrap_or(0) {
+        0 => "AUTOMATIC".to_string(),
+        n => format!("{} SELECTED", n),
+    };
     
     let usd_amount = if let Ok(amt) = amount.parse::<f64>() {
         format!("{:.2}", amt * exchange_rate)
@@ -40,7 +50,10 @@ pub fn view() -> Element {
         ("RECIPIENT_ADDR".to_string(), recipient),
         ("SEND_QUANTITY".to_string(), format!("{} BTC", amount)),
         ("USD_VALUATION".to_string(), format!("${}", usd_amount)),
+        ("FEE_RATE".to_string(), format!("{} SAT/VB", fee_rate)),
         ("MINER_FEE_TOTAL".to_string(), format!("{} SATS", fee)),
+        ("CONFIRMATION_TARGET".to_string(), target),
+        ("INPUT_COINS".to_string(), inputs),
         ("NETWORK_ID".to_string(), "BITCOIN_MAINNET".to_string()),
     ];
 
@@ -3,8 +3,12 @@
 
 use dioxus_native::prelude::*;
 use crate::context::{GlobalContext, BtcContext};
+use crate::clipboard::AddressChain;
+use crate::signer::session::{self, SessionLimits};
 use crate::ui::managebtc::btcsend::sendlogic::BTCSendLogic;
+use crate::ui::managebtc::btcpsbt::psbtlogic::BTCPsbtLogic;
 use crate::utils::send_auth_layout::SendAuthForm;
+use crate::utils::styles::terminal_action;
 
 #[component]
 pub fn view() -> Element {
@@ -12,10 +16,14 @@ pub fn view() -> Element {
     let btc_ctx = use_context::<BtcContext>();
     let mut btc_sign_transaction = btc_ctx.btc_sign_transaction;
 
-    let (_, address_opt, _) = btc_ctx.bitcoin_wallet.read().clone();
+    let (_, address_opt, key_is_deleted) = btc_ctx.bitcoin_wallet.read().clone();
     let wallet_address = address_opt.unwrap_or_else(|| "NULL_ADDR".to_string());
 
-    let input_mode = use_signal(|| "passphrase".to_string()); 
+    // Offer the open session first; the form falls back if it closes
+    let input_mode = use_signal(|| {
+        if session::is_active(AddressChain::Btc) { "session" } else { "passphrase" }.to_string()
+    });
+    let session_limits = use_signal(|| None::<SessionLimits>);
     let passphrase_val = use_signal(|| String::new());
     let bip39_val = use_signal(|| String::new());
     let seed_words = use_signal(|| vec![String::new(); 24]);
@@ -24,6 +32,36 @@ pub fn view() -> Element {
         .send_transaction.as_ref()
         .and_then(|s| s.error.clone());
 
+    // Watch-only: the key lives on an offline install, so the spend leaves here as a PSBT
+    if key_is_deleted {
+        let on_create = move |_| {
+            let (recipient, amount, fee, inputs) = {
+                let state = btc_sign_transaction.read();
+                let st = state.send_transaction.as_ref().unwrap();
+                (st.recipient.clone().unwrap_or_default(), st.amount.clone().unwrap_or_default(), st.fee.clone(), st.inputs.clone())
+            };
+            let last_v = btc_ctx.btc_modal.read().last_view.clone();
+            tokio::spawn(BTCPsbtLogic::create(recipient, amount, fee, inputs, wallet_address.clone(), last_v));
+        };
+
+        return rsx! {
+            div { style: "display: flex; flex-direction: column; width: 100%; max-width: 800px; margin: 0 auto; font-family: 'JetBrains Mono', monospace; padding: 2rem;",
+                div { style: "border-bottom: 1px solid var(--border); padding-bottom: 1rem; margin-bottom: 2rem;",
+                    div { style: "font-size: 0.7rem; color: var(--text-secondary); letter-spacing: 2px;", "TRANSACTION_AUTHORIZATION // STEP_05 // BITCOIN_MAINNET" }
+                }
+                div { style: "padding: 1rem; border: 1px dashed var(--border); color: var(--text-secondary); font-size: 0.7rem; line-height: 1.5;",
+                    "KEY_OFF_DEVICE // This install is watch-only. Create a PSBT, sign it on the offline install that holds the key, then load the signed copy here to broadcast."
+                }
+                if let Some(err) = current_error {
+                    div { style: "background: rgba(239, 68, 68, 0.1); border-left: 3px solid var(--status-warn); padding: 0.75rem 1rem; margin-top: 1rem; font-size: 0.75rem; color: var(--status-warn);", "SIGNAL_INTERRUPT: {err}" }
+                }
+                div { style: "margin-top: 2rem; display: flex; justify-content: flex-end;",
+                    {terminal_action("CREATE_PSBT", true, on_create)}
+                }
+            }
+        };
+    }
+
     let on_submit = move |_| {
         let mode = input_mode();
         let p_val = passphrase_val(); 
@@ -41,10 +79,11 @@ pub fn view() -> Element {
             },
             "seed" => {
                 let word_count = s_words.iter().filter(|w| !w.trim().is_empty()).count();
-                if word_count != 24 {
+                if !crate::entropy::WORD_COUNTS.contains(&word_count) {
                     validation_error = Some("ERR: MNEMONIC_LENGTH_MISMATCH".to_string());
                 }
             },
+            "session" => {},
             _ => { validation_error = Some("ERR: INVALID_MODE".to_string()); }
         }
 
@@ -58,14 +97,15 @@ pub fn view() -> Element {
         }
 
         // --- BTC SPECIFIC DATA PREP ---
-        let (recipient, amount, asset, fee) = {
+        let (recipient, amount, asset, fee, inputs) = {
             let state = btc_sign_transaction.read();
             let st = state.send_transaction.as_ref().unwrap();
             (
                 st.recipient.clone().unwrap_or_default(), 
                 st.amount.clone().unwrap_or_default(), 
                 st.asset.clone(), 
-                st.fee.clone() // BTC unique field
+                st.fee.clone(), // BTC unique field
+                st.inputs.clone(),
             )
         };
         
@@ -77,9 +117,11 @@ pub fn view() -> Element {
             p_val,
             seed_string,
             b39,
+            session_limits(),
             recipient,
             amount,
             fee,
+            inputs,
             wallet_address.clone(),
             asset,
             global.ws_tx.clone(),
@@ -91,7 +133,9 @@ pub fn view() -> Element {
         SendAuthForm {
             step_title: "TRANSACTION_AUTHORIZATION // STEP_05".to_string(),
             network_label: "BITCOIN_MAINNET",
+            chain: AddressChain::Btc,
             input_mode,
+            session_limits,
             passphrase_val,
             seed_words,
             bip39_val,
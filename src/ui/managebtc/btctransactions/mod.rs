@@ -1,7 +1,8 @@
 // src/ui/managebtc/transactions.rs
+use std::collections::{HashMap, HashSet};
 use dioxus_native::prelude::*;
 use crate::context::BtcContext;
-use crate::channel::{BTCActiveView, BitcoinTransactionStatus};
+use crate::channel::{BTCActiveView, BTCTransactionData, BitcoinTransactionStatus};
 use crate::utils::styles;
 use chrono::{DateTime, Utc, TimeZone};
 
@@ -22,15 +23,50 @@ fn format_timestamp(ts: &str) -> String {
     }
 }
 
+// Amount and counterparties as the wallet sees them: a send counts only what left the wallet,
+// so change paid back to one of its own addresses is neither amount nor receiver
+fn net_view(tx: &BTCTransactionData, owned: &HashSet<String>) -> (String, Vec<String>) {
+    let outgoing = tx.sender_addresses.iter().any(|a| owned.contains(a));
+
+    // Without per-output values only the receiver list can be corrected
+    if tx.outputs.is_empty() {
+        let external: Vec<String> = tx.receiver_addresses.iter().filter(|a| !owned.contains(*a)).cloned().collect();
+        let receivers = if outgoing && !external.is_empty() { external } else { tx.receiver_addresses.clone() };
+        return (tx.amount.clone(), receivers);
+    }
+
+    let counted: Vec<_> = tx.outputs.iter().filter(|o| owned.contains(&o.address) != outgoing).collect();
+    // Paying only to itself, e.g. a consolidation, leaves nothing external to count
+    let counted = if counted.is_empty() { tx.outputs.iter().collect() } else { counted };
+    let amount: u64 = counted.iter().map(|o| o.value).sum();
+    let receivers = counted.iter().map(|o| o.address.clone()).collect();
+    (amount.to_string(), receivers)
+}
+
 #[component]
 pub fn view() -> Element {
     let mut btc_ctx = use_context::<BtcContext>();
     let tx_state = btc_ctx.btc_transactions.read();
+    let account = btc_ctx.btc_account.read();
+
+    // Receive and change addresses of the account, tracked in btc.json
+    let wallet = btc_ctx.bitcoin_wallet.read().1.clone().unwrap_or_default();
+    let owned = use_memo(use_reactive(&wallet, |wallet| crate::btcaccount::owned_addresses(&wallet)));
+
+    // The backend reports on the wallet address only; the account history covers every address
+    // and carries per-output values, so it wins for transactions both know
+    let mut merged: HashMap<&str, &BTCTransactionData> = tx_state.transactions.iter()
+        .map(|(txid, tx)| (txid.as_str(), tx))
+        .collect();
+    if account.wallet.as_deref() == Some(wallet.as_str()) {
+        merged.extend(account.transactions.iter().map(|(txid, tx)| (txid.as_str(), tx)));
+    }
 
-    let mut sorted_txs: Vec<_> = tx_state.transactions.values().collect();
+    let mut sorted_txs: Vec<_> = merged.into_values().collect();
     sorted_txs.sort_by_key(|tx| std::cmp::Reverse(parse_timestamp(&tx.timestamp)));
 
     let display_txs = sorted_txs.into_iter().take(100).collect::<Vec<_>>();
+    let net = display_txs.iter().map(|tx| net_view(tx, &owned.read())).collect::<Vec<_>>();
 
     let on_back_click = move |_: MouseEvent| {
         btc_ctx.btc_modal.with_mut(|state| {
@@ -129,15 +165,15 @@ pub fn view() -> Element {
                 }
 
                 div { class: "table-body",
-                    for (i, tx) in display_txs.iter().enumerate() {
+                    for (i, (tx, (amount, receivers))) in display_txs.iter().zip(net).enumerate() {
                         TransactionRow { 
                             key: "{tx.txid}",
                             index: i,
                             tx_id: tx.txid.clone(),
                             status: tx.status.clone(),
-                            amount: tx.amount.clone(),
+                            amount,
                             fee: tx.fees.clone(),
-                            receivers: tx.receiver_addresses.clone(),
+                            receivers,
                             senders: tx.sender_addresses.clone(),
                             timestamp: tx.timestamp.clone(),
                         }
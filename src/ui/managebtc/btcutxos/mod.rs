@@ -0,0 +1,275 @@
+// src/ui/managebtc/btcutxos/mod.rs
+//
+// Coin control: every unspent output with its confirmations and label, freezing coins out of
+// automatic selection, sending from hand-picked coins and merging small ones while fees are low.
+
+use dioxus_native::prelude::*;
+use crate::context::BtcContext;
+use crate::channel::{BTCActiveView, BTCSignTransaction};
+use crate::utils::styles::{self, terminal_action};
+
+pub mod utxologic;
+use utxologic::{BTCUtxoLogic, CoinRow, CONSOLIDATE_MAX_RATE};
+
+/// Coin list with a selection mark per row. `manage` adds the label field and freeze toggle;
+/// `on_changed` gets the outcome of each save.
+#[component]
+pub fn CoinTable(
+    rows: Vec<CoinRow>,
+    selected: Signal<Vec<String>>,
+    manage: bool,
+    on_changed: Option<EventHandler<Result<(), String>>>,
+) -> Element {
+    rsx! {
+        style { {r#"
+            .coin-table { display: flex; flex-direction: column; gap: 1px; background: var(--border); border: 1px solid var(--border); width: 100%; }
+            .coin-row { display: grid; grid-template-columns: 2.5rem 1fr auto; gap: 0.75rem; background: var(--bg-grid); padding: 0.75rem 1rem; align-items: center; }
+            .coin-mark { cursor: pointer; color: var(--accent); font-size: 0.8rem; font-weight: bold; }
+            .coin-main { display: flex; flex-direction: column; gap: 0.25rem; min-width: 0; }
+            .coin-value { font-size: 0.85rem; color: var(--text); }
+            .coin-meta { font-size: 0.6rem; color: var(--text-secondary); word-break: break-all; }
+            .coin-label-input { background: transparent; border: none; border-bottom: 1px dashed var(--border); outline: none; color: var(--text); font-family: inherit; font-size: 0.7rem; padding: 0.2rem 0; }
+            .coin-frozen { opacity: 0.5; }
+        "#} }
+
+        div { class: "coin-table",
+            if rows.is_empty() {
+                div { class: "coin-row",
+                    div {}
+                    div { class: "coin-meta", "NO_UNSPENT_OUTPUTS" }
+                    div {}
+                }
+            }
+            for row in rows {
+                {
+                    let outpoint = row.outpoint.clone();
+                    let is_selected = selected.read().contains(&row.outpoint);
+                    let confirmations = if row.confirmations == 0 { "UNCONFIRMED".to_string() } else { format!("{} CONF", row.confirmations) };
+                    let toggle = {
+                        let outpoint = outpoint.clone();
+                        move |_| selected.with_mut(|picked| {
+                            match picked.iter().position(|o| *o == outpoint) {
+                                Some(i) => { picked.remove(i); }
+                                None => picked.push(outpoint.clone()),
+                            }
+                        })
+                    };
+                    let on_label = {
+                        let outpoint = outpoint.clone();
+                        move |e: FormEvent| {
+                            let saved = BTCUtxoLogic::set_label(&outpoint, e.value())
+                                .map_err(|e| format!("ERR: LABEL_NOT_SAVED // {}", e));
+                            if let Some(handler) = on_changed { handler.call(saved) }
+                        }
+                    };
+                    let on_freeze = {
+                        let outpoint = outpoint.clone();
+                        let frozen = row.frozen;
+                        move |_| {
+                            let saved = BTCUtxoLogic::set_frozen(&outpoint, !frozen)
+                                .map_err(|e| format!("ERR: COIN_NOT_UPDATED // {}", e));
+                            if let Some(handler) = on_changed { handler.call(saved) }
+                        }
+                    };
+
+                    rsx! {
+                        div { key: "{row.outpoint}", class: if row.frozen { "coin-row coin-frozen" } else { "coin-row" },
+                            div { class: "coin-mark", onclick: toggle,
+                                if is_selected { "[x]" } else { "[ ]" }
+                            }
+                            div { class: "coin-main",
+                                div { class: "coin-value", "{row.value.to_btc()} BTC" }
+                                div { class: "coin-meta", "{row.address}" }
+                                div { class: "coin-meta", "{row.outpoint} // {confirmations}" }
+                                if manage {
+                                    input {
+                                        class: "coin-label-input",
+                                        value: "{row.label}",
+                                        placeholder: "LABEL",
+                                        onchange: on_label,
+                                    }
+                                } else if !row.label.is_empty() {
+                                    div { class: "coin-meta", "LABEL // {row.label}" }
+                                }
+                            }
+                            div {
+                                if manage {
+                                    {terminal_action(if row.frozen { "UNFREEZE" } else { "FREEZE" }, !row.frozen, on_freeze)}
+                                } else if row.frozen {
+                                    div { class: "coin-meta", "FROZEN" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn view() -> Element {
+    let btc_ctx = use_context::<BtcContext>();
+    let mut btc_modal = btc_ctx.btc_modal;
+    let mut btc_sign_transaction = btc_ctx.btc_sign_transaction;
+
+    let (_, address_opt, _) = btc_ctx.bitcoin_wallet.read().clone();
+    let wallet_address = address_opt.unwrap_or_default();
+
+    let mut rows = use_signal(|| Vec::<CoinRow>::new());
+    let mut loading = use_signal(|| true);
+    let mut error = use_signal(|| None::<String>);
+    let mut rate = use_signal(|| None::<f64>);
+    let selected = use_signal(|| Vec::<String>::new());
+
+    let refresh = {
+        let wallet_address = wallet_address.clone();
+        // A rescan first looks for newly used addresses, as on wallet load
+        move |rescan: bool| {
+            let wallet_address = wallet_address.clone();
+            async move {
+                loading.set(true);
+                if rescan {
+                    if let Err(e) = crate::wallet::refresh_bitcoin(wallet_address.clone()).await {
+                        error.set(Some(e));
+                        loading.set(false);
+                        return;
+                    }
+                }
+                match BTCUtxoLogic::fetch(wallet_address).await {
+                    Ok(fetched) => {
+                        rows.set(fetched);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                rate.set(BTCUtxoLogic::consolidation_rate().await.ok());
+                loading.set(false);
+            }
+        }
+    };
+    let refresh_on_load = refresh.clone();
+    use_future(move || refresh_on_load(false));
+
+    // Notes are saved straight away; re-reading them keeps the list in step
+    let on_changed = move |saved: Result<(), String>| {
+        if let Err(e) = saved {
+            return error.set(Some(e));
+        }
+        let account = crate::btcaccount::load();
+        rows.with_mut(|rows| {
+            for row in rows.iter_mut() {
+                if let Ok(outpoint) = row.outpoint.parse() {
+                    let note = account.coin(&outpoint);
+                    row.label = note.label;
+                    row.frozen = note.frozen;
+                }
+            }
+        });
+    };
+
+    let picked = selected.read().clone();
+    let candidates = BTCUtxoLogic::candidates(&rows.read(), &picked);
+    let picked_total: bitcoin::Amount = rows.read().iter()
+        .filter(|row| picked.contains(&row.outpoint))
+        .map(|row| row.value)
+        .sum();
+    let fees_low = rate().map(|r| r <= CONSOLIDATE_MAX_RATE).unwrap_or(false);
+    let rate_text = match rate() {
+        Some(r) if r <= CONSOLIDATE_MAX_RATE => format!("{:.1} SAT/VB // FEES_LOW", r),
+        Some(r) => format!("{:.1} SAT/VB // FEES_HIGH // CONSOLIDATE_LATER", r),
+        None => "FEE_RATE_UNAVAILABLE".to_string(),
+    };
+
+    let on_send = move |_| {
+        let inputs = selected();
+        if inputs.is_empty() {
+            error.set(Some("ERR: NO_COINS_SELECTED".to_string()));
+            return;
+        }
+        if rows.read().iter().any(|row| row.frozen && inputs.contains(&row.outpoint)) {
+            error.set(Some("ERR: SELECTION_INCLUDES_FROZEN_COIN".to_string()));
+            return;
+        }
+        btc_sign_transaction.with_mut(|state| {
+            state.send_transaction = Some(BTCSignTransaction {
+                step: 1,
+                error: None,
+                recipient: None,
+                amount: None,
+                fee: "".to_string(),
+                fee_rate: "".to_string(),
+                fee_target: None,
+                asset: "BTC".to_string(),
+                inputs,
+            });
+        });
+        btc_modal.with_mut(|m| {
+            m.last_view = Some(BTCActiveView::Utxos);
+            m.view_type = BTCActiveView::Send;
+        });
+    };
+
+    let on_consolidate = {
+        let candidates = candidates.clone();
+        move |_| {
+            let Some(r) = rate() else { return; };
+            if r > CONSOLIDATE_MAX_RATE {
+                return;
+            }
+            match BTCUtxoLogic::consolidate(&wallet_address, &candidates, r) {
+                Ok(send) => {
+                    btc_sign_transaction.with_mut(|state| state.send_transaction = Some(send));
+                    btc_modal.with_mut(|m| {
+                        m.last_view = Some(BTCActiveView::Utxos);
+                        m.view_type = BTCActiveView::Send;
+                    });
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        }
+    };
+
+    let row_list = rows();
+    let coin_count = row_list.len();
+
+    rsx! {
+        style { {r#"
+            .utxo-container { display: flex; flex-direction: column; width: 100%; max-width: 800px; margin: 0 auto; font-family: 'JetBrains Mono', monospace; padding: 2rem; position: relative; }
+            .back-button-container { position: absolute; top: 0.75rem; left: 0.75rem; cursor: pointer; z-index: 10; }
+            .step-header { border-bottom: 1px solid var(--border); padding-bottom: 1rem; margin-bottom: 2rem; display: flex; justify-content: space-between; align-items: baseline; }
+            .step-title { font-size: 0.7rem; color: var(--text-secondary); letter-spacing: 2px; }
+            .utxo-status { font-size: 0.6rem; color: var(--text-secondary); letter-spacing: 1px; margin-bottom: 1rem; }
+            .error-box { background: rgba(239, 68, 68, 0.1); border-left: 3px solid var(--status-warn); padding: 0.75rem 1rem; margin-bottom: 1rem; font-size: 0.75rem; color: var(--status-warn); }
+            .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-end; align-items: center; gap: 1rem; flex-wrap: wrap; }
+        "#} }
+
+        div { class: "utxo-container",
+            div {
+                class: "back-button-container",
+                onclick: move |_| btc_modal.with_mut(|m| m.view_type = BTCActiveView::BTC),
+                styles::previous_icon_button { text_color: "#fff".to_string() }
+            }
+
+            div { class: "step-header",
+                div { class: "step-title", "COIN_CONTROL // UNSPENT_OUTPUTS // BITCOIN_MAINNET" }
+                div { class: "step-title", if loading() { "SCANNING..." } else { "{coin_count} COINS" } }
+            }
+
+            div { class: "utxo-status", "CONSOLIDATION_RATE // {rate_text}" }
+            div { class: "utxo-status", "SELECTED // {picked.len()} COINS // {picked_total.to_btc()} BTC" }
+
+            if let Some(err) = error() {
+                div { class: "error-box", "SIGNAL_INTERRUPT: {err}" }
+            }
+
+            CoinTable { rows: row_list, selected, manage: true, on_changed }
+
+            div { class: "footer-nav",
+                {terminal_action("REFRESH", !loading(), move |_| { spawn(refresh(true)); })}
+                {terminal_action("SEND_SELECTED", !picked.is_empty(), on_send)}
+                {terminal_action(&format!("CONSOLIDATE_{}", candidates.len()), fees_low && candidates.len() >= 2, on_consolidate)}
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// src/ui/managebtc/btcutxos/utxologic.rs
+
+use std::str::FromStr;
+use bitcoin::{Address, Amount, Denomination, OutPoint};
+use crate::btcaccount;
+use crate::channel::BTCSignTransaction;
+use crate::chain::esplora;
+use crate::signer::{self, btc};
+
+// Coins below this are the ones worth merging while fees are low
+pub const SMALL_COIN: Amount = Amount::from_sat(100_000);
+// Above this rate consolidating costs more than it is likely to save later
+pub const CONSOLIDATE_MAX_RATE: f64 = 10.0;
+// Blocks a consolidation can wait for; nothing depends on it confirming soon
+const CONSOLIDATE_TARGET: u16 = 144;
+
+/// One unspent output as the coin list shows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoinRow {
+    pub outpoint: String,
+    pub value: Amount,
+    pub address: String,
+    pub confirmations: u32,
+    pub label: String,
+    pub frozen: bool,
+}
+
+pub struct BTCUtxoLogic;
+
+impl BTCUtxoLogic {
+    /// The wallet's coins with their notes, largest first.
+    pub async fn fetch(wallet: String) -> Result<Vec<CoinRow>, String> {
+        let coins = signer::btc_utxos(wallet).await?;
+        let tip_height = esplora::tip_height().await?;
+        let account = btcaccount::load();
+
+        let mut rows: Vec<CoinRow> = coins.into_iter()
+            .map(|coin| {
+                let note = account.coin(&coin.utxo.outpoint);
+                CoinRow {
+                    outpoint: coin.utxo.outpoint.to_string(),
+                    value: coin.utxo.value,
+                    address: coin.address,
+                    confirmations: coin.utxo.confirmations(tip_height),
+                    label: note.label,
+                    frozen: note.frozen,
+                }
+            })
+            .collect();
+        rows.sort_by(|a, b| b.value.cmp(&a.value));
+        Ok(rows)
+    }
+
+    pub fn set_label(outpoint: &str, label: String) -> Result<(), String> {
+        let outpoint = OutPoint::from_str(outpoint).map_err(|e| format!("Invalid coin: {}", e))?;
+        btcaccount::set_coin_label(&outpoint, label).map_err(|e| e.to_string())
+    }
+
+    pub fn set_frozen(outpoint: &str, frozen: bool) -> Result<(), String> {
+        let outpoint = OutPoint::from_str(outpoint).map_err(|e| format!("Invalid coin: {}", e))?;
+        btcaccount::set_coin_frozen(&outpoint, frozen).map_err(|e| e.to_string())
+    }
+
+    /// Fee rate in sat/vB for a consolidation that can wait about a day.
+    pub async fn consolidation_rate() -> Result<f64, String> {
+        let estimates = esplora::fee_estimates().await?;
+        esplora::rate_for(&estimates, CONSOLIDATE_TARGET)
+            .map(|rate| rate.max(1.0))
+            .ok_or_else(|| "No fee estimates available".to_string())
+    }
+
+    /// The coins to merge: the ones picked, or else every small unfrozen coin.
+    pub fn candidates(rows: &[CoinRow], selected: &[String]) -> Vec<CoinRow> {
+        rows.iter()
+            .filter(|row| !row.frozen)
+            .filter(|row| if selected.is_empty() {
+                row.value < SMALL_COIN
+            } else {
+                selected.contains(&row.outpoint)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// A send of `coins` to the next receive address of the wallet, less the fee at `rate`,
+    /// ready for review.
+    pub fn consolidate(wallet: &str, coins: &[CoinRow], rate: f64) -> Result<BTCSignTransaction, String> {
+        if coins.len() < 2 {
+            return Err("ERR: CONSOLIDATION_NEEDS_TWO_COINS".to_string());
+        }
+        let account = btcaccount::load();
+        let destination = btcaccount::next_receive()
+            .map(|tracked| tracked.address)
+            .unwrap_or_else(|| wallet.to_string());
+        let script = Address::from_str(&destination)
+            .map_err(|e| format!("Invalid address: {}", e))?
+            .assume_checked()
+            .script_pubkey();
+
+        let vsize = btc::estimate_vsize(account.script_type, coins.len(), &[script.clone()]);
+        let fee = Amount::from_sat((rate * vsize as f64).ceil() as u64);
+        let total = coins.iter().map(|coin| coin.value).sum::<Amount>();
+        let amount = total.checked_sub(fee)
+            .filter(|amount| *amount >= script.minimal_non_dust())
+            .ok_or_else(|| "ERR: COINS_TOO_SMALL_FOR_FEE".to_string())?;
+
+        Ok(BTCSignTransaction {
+            step: 4,
+            error: None,
+            recipient: Some(destination),
+            amount: Some(amount.to_string_in(Denomination::Bitcoin)),
+            asset: "BTC".to_string(),
+            fee: fee.to_sat().to_string(),
+            fee_rate: format!("{:.1}", rate),
+            fee_target: Some(CONSOLIDATE_TARGET),
+            inputs: coins.iter().map(|coin| coin.outpoint.clone()).collect(),
+        })
+    }
+}
@@ -1,10 +1,8 @@
 // src/ui/managebtc/mod.rs
 use dioxus_native::prelude::*;
-use crate::context::BtcContext;
+use crate::context::{BtcContext, XrpContext};
+use crate::btcaccount::AddressType;
 use crate::channel::{BTCImport, BTCActiveView};
-use bip39::{Mnemonic, Language};
-use rand::{rng, Rng};
-use zeroize::Zeroizing; 
 use crate::utils::styles::terminal_action; 
 
 pub mod btcimport;
@@ -13,10 +11,13 @@ pub mod receive;
 pub mod btcsend; 
 pub mod btctransactions; 
 pub mod btccreate;
+pub mod btcpsbt;
+pub mod btcutxos;
 
 #[component]
 pub fn render_manage_btc() -> Element {
     let btc_ctx = use_context::<BtcContext>();
+    let xrp_ctx = use_context::<XrpContext>();
     
     let mut btc_modal = btc_ctx.btc_modal; 
     let mut btc_wallet_process = btc_ctx.btc_wallet_process; 
@@ -32,33 +33,63 @@ pub fn render_manage_btc() -> Element {
         BTCActiveView::Send         => return rsx! { btcsend::view {} },
         BTCActiveView::Transactions => return rsx! { btctransactions::view {} },
         BTCActiveView::Receive      => return rsx! { receive::view {} },
+        BTCActiveView::Psbt         => return rsx! { btcpsbt::view {} },
+        BTCActiveView::Utxos        => return rsx! { btcutxos::view {} },
         BTCActiveView::BTC          => {} 
     }
 
     // --- TERMINAL ACTIONS ---
     let create_btn = terminal_action("CREATE_BTC_WALLET", true, move |_| {
-        let mut entropy = [0u8; 32];
-        rng().fill_bytes(&mut entropy);
-        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy).unwrap();
-        let seed = Zeroizing::new(mnemonic.to_string());
+        // Length and optional dice/coin entropy can be changed on the seed step
+        let seed = crate::entropy::new_mnemonic(crate::entropy::DEFAULT_WORD_COUNT)
+            .expect("OS RNG entropy always yields a valid mnemonic");
 
         btc_wallet_process.with_mut(|state| {
-            state.create_wallet = Some(BTCImport { step: 1, seed: Some(seed), error: None });
+            state.create_wallet = Some(BTCImport { step: 1, seed: Some(seed), shares: None, unified: false, address_type: AddressType::Taproot, error: None });
         });
         btc_modal.with_mut(|s| s.view_type = BTCActiveView::Create);
     });
 
     let import_btn = terminal_action("IMPORT_BTC_WALLET", true, move |_| {
         btc_wallet_process.with_mut(|state| {
-            state.import_wallet = Some(BTCImport { step: 1, seed: None, error: None });
+            state.import_wallet = Some(BTCImport { step: 1, seed: None, shares: None, unified: false, address_type: AddressType::NativeSegwit, error: None });
         });
         btc_modal.with_mut(|s| s.view_type = BTCActiveView::Import);
     });
 
+    // Unified mode: a new master seed while neither chain has a wallet yet, or this chain
+    // re-derived from the master seed XRP is already using
+    let master_exists = crate::master::master_exists();
+    let offer_unified = master_exists || xrp_ctx.wallet_balance.read().1.is_none();
+    let unified_btn = terminal_action(if master_exists { "USE_MASTER_SEED" } else { "CREATE_UNIFIED_WALLET" }, true, move |_| {
+        let (step, seed) = if master_exists {
+            (3, None)
+        } else {
+            (1, Some(crate::entropy::new_mnemonic(crate::entropy::DEFAULT_WORD_COUNT)
+                .expect("OS RNG entropy always yields a valid mnemonic")))
+        };
+        btc_wallet_process.with_mut(|state| {
+            state.create_wallet = Some(BTCImport { step, seed, shares: None, unified: true, address_type: AddressType::NativeSegwit, error: None });
+        });
+        btc_modal.with_mut(|s| s.view_type = BTCActiveView::Create);
+    });
+
     let history_btn = terminal_action("HISTORY", matches!(view_type, BTCActiveView::Transactions), move |_| {
         btc_modal.with_mut(|s| s.view_type = BTCActiveView::Transactions);
     });
 
+    let coins_btn = terminal_action("COINS", matches!(view_type, BTCActiveView::Utxos), move |_| {
+        btc_modal.with_mut(|s| s.view_type = BTCActiveView::Utxos);
+    });
+
+    // Watch-only installs export and broadcast PSBTs; the install holding the key signs them
+    let psbt_btn = terminal_action("PSBT", matches!(view_type, BTCActiveView::Psbt), move |_| {
+        btc_modal.with_mut(|s| {
+            s.last_view = Some(BTCActiveView::BTC);
+            s.view_type = BTCActiveView::Psbt;
+        });
+    });
+
     // --- RENDER ---
    rsx! {
         style { {r#"
@@ -127,6 +158,9 @@ pub fn render_manage_btc() -> Element {
                             style: "display: flex; flex-direction: column; gap: 1rem; width: 100%; align-items: center;",
                             {create_btn}
                             {import_btn}
+                            if offer_unified {
+                                {unified_btn}
+                            }
                         }
                     }
                 } else {
@@ -137,6 +171,8 @@ pub fn render_manage_btc() -> Element {
             if has_wallet {
                 div { class: "term-sidebar-right",
                     {history_btn}
+                    {coins_btn}
+                    {psbt_btn}
                 }
             }
         }
@@ -1,7 +1,10 @@
 // src/ui/managebtc/receive.rs
 use dioxus_native::prelude::*;
+use crate::btcaccount::{self, KeyChain, TrackedAddress, GAP_LIMIT_OPTIONS};
 use crate::context::{BtcContext, GlobalContext};
 use crate::utils::receive_layout::ReceiveAddressLayout;
+use crate::utils::styles::terminal_action;
+use crate::clipboard::AddressChain;
 
 #[component]
 pub fn view() -> Element {
@@ -9,15 +12,70 @@ pub fn view() -> Element {
     let global = use_context::<GlobalContext>();
 
     let (_, address_opt, _) = btc_ctx.bitcoin_wallet.read().clone();
-    let address = address_opt.unwrap_or_else(|| "No Address".to_string());
     let is_dark = global.theme_user.read().0;
 
+    // Each visit refreshes which addresses were paid to, then hands out the next unused one
+    let mut receive = use_signal(|| None::<TrackedAddress>);
+    let mut scanning = use_signal(|| true);
+    let mut gap_limit = use_signal(|| btcaccount::load().gap_limit);
+    let mut scan_error = use_signal(|| None::<String>);
+
+    let wallet_address = address_opt.clone();
+    let scan = move || {
+        let wallet_address = wallet_address.clone();
+        async move {
+            scanning.set(true);
+            let result = match wallet_address {
+                Some(wallet) => crate::wallet::refresh_bitcoin(wallet).await,
+                None => btcaccount::discover().await.map(|_| ()),
+            };
+            // The address handed out may already have been paid to if the scan did not finish
+            scan_error.set(result.err());
+            receive.set(btcaccount::next_receive());
+            scanning.set(false);
+        }
+    };
+    let scan_on_load = scan.clone();
+    use_future(move || scan_on_load());
+
+    // Cycle 20 -> 50 -> 100 -> 200, then rescan with the new limit
+    let on_gap_toggle = move |_| {
+        let idx = GAP_LIMIT_OPTIONS.iter().position(|g| *g == gap_limit()).unwrap_or(0);
+        let next = GAP_LIMIT_OPTIONS[(idx + 1) % GAP_LIMIT_OPTIONS.len()];
+        if btcaccount::set_gap_limit(next).is_ok() {
+            gap_limit.set(next);
+            spawn(scan());
+        }
+    };
+
+    // Wallets without a recorded xpub keep showing their single address
+    let (address, mut detail) = match (scanning(), receive()) {
+        (true, _) => ("No Address".to_string(), "SCANNING_ADDRESSES...".to_string()),
+        (false, Some(tracked)) => (
+            tracked.address.clone(),
+            format!("{} // UNUSED", btcaccount::load().path(KeyChain::Receive, tracked.index)),
+        ),
+        (false, None) => (
+            address_opt.unwrap_or_else(|| "No Address".to_string()),
+            btcaccount::load().path(KeyChain::Receive, 0),
+        ),
+    };
+
+    if let Some(e) = scan_error() {
+        detail = format!("{} // SCAN_FAILED: {}", detail, e);
+    }
+
+    let actions = terminal_action(&format!("GAP_LIMIT_{}", gap_limit()), false, on_gap_toggle);
+
     rsx! {
         ReceiveAddressLayout {
             network_name: "BITCOIN_NETWORK".to_string(),
             protocol_label: "BITCOIN".to_string(),
             address: address,
+            chain: AddressChain::Btc,
             is_dark: is_dark,
+            detail,
+            actions,
             on_back: move |_| {
                 btc_ctx.btc_modal.with_mut(|state| {
                     if let Some(prev) = state.last_view.clone() {
@@ -27,4 +85,4 @@ pub fn view() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -3,6 +3,8 @@
 
 use dioxus_native::prelude::*;
 use crate::context::{RlusdContext, GlobalContext, XrpContext};
+use crate::clipboard::AddressChain;
+use crate::signer::session::{self, SessionLimits};
 pub mod enable_logic;
 use enable_logic::RlusdEnableLogic;
 pub mod rlusdbalance;
@@ -22,7 +24,11 @@ pub fn render_rlusd_balance() -> Element {
     let (_, has_rlusd, _) = rlusd_ctx.rlusd.read().clone();
 
     // ── KEEP EXACTLY AS IN YOUR ORIGINAL ── (this was the only thing that broke)
-    let input_mode = use_signal(|| "passphrase".to_string()); 
+    // Offer the open session first; the form falls back if it closes
+    let input_mode = use_signal(|| {
+        if session::is_active(AddressChain::Xrp) { "session" } else { "passphrase" }.to_string()
+    });
+    let session_limits = use_signal(|| None::<SessionLimits>);
     let mut passphrase_val = use_signal(|| String::new());
     let mut bip39_val = use_signal(|| String::new());
     let mut seed_words = use_signal(|| vec![String::new(); 24]);
@@ -45,10 +51,11 @@ pub fn render_rlusd_balance() -> Element {
             },
             "seed" => {
                 let word_count = s_words.iter().filter(|w| !w.trim().is_empty()).count();
-                if word_count != 24 {
+                if !crate::entropy::WORD_COUNTS.contains(&word_count) {
                     validation_error = Some("ERR: MNEMONIC_LENGTH_MISMATCH".to_string());
                 }
             },
+            "session" => {},
             _ => { validation_error = Some("ERR: INVALID_MODE".to_string()); }
         }
 
@@ -65,6 +72,7 @@ pub fn render_rlusd_balance() -> Element {
             p_val,
             seed_string,
             b39,
+            session_limits(),
             wallet_addr_clone,
             "RLUSD".to_string(),
             global.ws_tx.clone(),
@@ -84,7 +92,9 @@ pub fn render_rlusd_balance() -> Element {
             reserve_info,
             enable_btn_text: "ENABLE_RLUSD".to_string(),
             has_token: has_rlusd,
+            chain: AddressChain::Xrp,
             input_mode,
+            session_limits,
             passphrase_val,
             bip39_val,
             seed_words,
@@ -3,7 +3,7 @@
 use tokio::sync::mpsc::Sender;
 use crate::channel::{CHANNEL, WSCommand, ProgressState, SignTransactionState};
 use zeroize::Zeroizing;
-use arboard::Clipboard;
+use crate::signer::{self, Credentials, session::SessionLimits, xrp::XrpTx};
 
 pub struct SgdEnableLogic;
 
@@ -13,6 +13,7 @@ impl SgdEnableLogic {
         passphrase: String,      
         mnemonic: String,        
         bip39_pass: String,      
+        session: Option<SessionLimits>,
         wallet_address: String,
         asset: String,
         ws_tx: Sender<WSCommand>,
@@ -27,24 +28,34 @@ impl SgdEnableLogic {
             message: "Enabling XSGD Trustline...".to_string(),
         }));
 
-        // 2. Prepare Optional Data (BIP39)
-        let bip39_opt = if b_guard.trim().is_empty() { 
-            None 
-        } else { 
-            Some(b_guard.clone()) 
+        // 2. Enforce XOR Logic (Passphrase vs Seed)
+        let credentials = match Credentials::from_form(&mode, p_guard, m_guard, b_guard, session) {
+            Ok(credentials) => credentials,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
-        // 3. Enforce XOR Logic (Passphrase vs Seed)
-        let (passphrase, seed) = match mode.as_str() {
-            "passphrase" => {
-                let p = if p_guard.is_empty() { None } else { Some(p_guard.clone()) };
-                (p, None) 
-            },
-            "seed" => {
-                let s = if m_guard.trim().is_empty() { None } else { Some(m_guard.clone()) };
-                (None, s) 
-            },
-            _ => (None, None), 
+        // 3. Sign locally; only the signed blob leaves this task
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.5,
+            message: "Signing trustline...".to_string(),
+        }));
+        let limit = "1000000".to_string();
+        let tx = XrpTx::TrustSet { asset: asset.clone(), limit: limit.clone() };
+        let signed = match signer::sign_xrp(wallet_address.clone(), credentials, tx).await {
+            Ok(signed) => signed,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
         // 4. Construct Command for RLUSD
@@ -53,24 +64,23 @@ impl SgdEnableLogic {
             wallet: Some(wallet_address),
             recipient: None,
             amount: None,
-            passphrase,
-            seed,
-            trustline_limit: Some("1000000".to_string()),
+            trustline_limit: Some(limit),
             fee: None,
             tx_type: Some("trustset".to_string()), 
             taker_pays: None,
             taker_gets: None,
             flags: None,
             wallet_type: Some(asset),
-            bip39: bip39_opt,
+            signed_tx: Some(signed.blob),
+            tx_hash: Some(signed.hash),
         };
 
         // 5. Dispatch
+        let summary = crate::audit::describe_command(&cmd);
         match ws_tx.try_send(cmd) {
             Ok(_) => {
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::audit::record(crate::audit::AuditEvent::TransactionSigned, summary);
+                crate::clipboard::clear();
                 let _ = CHANNEL.sign_transaction_tx.send(SignTransactionState {
                     send_transaction: None, 
                 });
@@ -1,9 +1,6 @@
 use dioxus_native::prelude::*;
-use crate::context::{XrpContext, RlusdContext, SgdContext, EuroContext};
+use crate::context::{XrpContext, RlusdContext, SgdContext, EuroContext, BtcContext};
 use crate::channel::{XRPImport, ActiveView, Trade};
-use bip39::{Mnemonic, Language};
-use rand::{rng, Rng};
-use zeroize::Zeroizing;
 use crate::utils::styles::{terminal_action, nav_action}; 
 use crate::utils::reserves::{get_xrp_balance_info};
 
@@ -23,6 +20,7 @@ pub fn render_manage_xrp() -> Element {
     let rlusd_ctx = use_context::<RlusdContext>();
     let euro_ctx  = use_context::<EuroContext>();
     let sgd_ctx  = use_context::<SgdContext>();
+    let btc_ctx = use_context::<BtcContext>();
 
     let mut xrp_modal = xrp.xrp_modal; 
     let mut wallet_process = xrp.wallet_process; 
@@ -70,19 +68,33 @@ pub fn render_manage_xrp() -> Element {
 
 
     let create_btn = terminal_action("CREATE_XRP_WALLET", true, move |_| {
-        let mut entropy = [0u8; 32];
-        rng().fill_bytes(&mut entropy);
-        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy).unwrap();
-        let seed = Zeroizing::new(mnemonic.to_string());
-        wallet_process.with_mut(|state| state.create_wallet = Some(XRPImport { step: 1, seed: Some(seed), error: None }));
+        // Length and optional dice/coin entropy can be changed on the seed step
+        let seed = crate::entropy::new_mnemonic(crate::entropy::DEFAULT_WORD_COUNT)
+            .expect("OS RNG entropy always yields a valid mnemonic");
+        wallet_process.with_mut(|state| state.create_wallet = Some(XRPImport { step: 1, seed: Some(seed), shares: None, unified: false, error: None }));
         xrp_modal.with_mut(|s| s.view_type = ActiveView::Create);
     });
 
     let import_btn = terminal_action("IMPORT_XRP_WALLET", true, move |_| {
-        wallet_process.with_mut(|state| state.import_wallet = Some(XRPImport { step: 1, seed: None, error: None }));
+        wallet_process.with_mut(|state| state.import_wallet = Some(XRPImport { step: 1, seed: None, shares: None, unified: false, error: None }));
         xrp_modal.with_mut(|s| s.view_type = ActiveView::Import);
     });
 
+    // Unified mode: a new master seed while neither chain has a wallet yet, or this chain
+    // re-derived from the master seed BTC is already using
+    let master_exists = crate::master::master_exists();
+    let offer_unified = master_exists || btc_ctx.bitcoin_wallet.read().1.is_none();
+    let unified_btn = terminal_action(if master_exists { "USE_MASTER_SEED" } else { "CREATE_UNIFIED_WALLET" }, true, move |_| {
+        let (step, seed) = if master_exists {
+            (3, None)
+        } else {
+            (1, Some(crate::entropy::new_mnemonic(crate::entropy::DEFAULT_WORD_COUNT)
+                .expect("OS RNG entropy always yields a valid mnemonic")))
+        };
+        wallet_process.with_mut(|state| state.create_wallet = Some(XRPImport { step, seed, shares: None, unified: true, error: None }));
+        xrp_modal.with_mut(|s| s.view_type = ActiveView::Create);
+    });
+
     let trade_btn = terminal_action("TRADE", matches!(view_type, ActiveView::Trade), move |_| {
         xrp_modal.with_mut(|state| { state.last_view = Some(ActiveView::XRP); state.view_type = ActiveView::Trade; });
         trade_tx.with_mut(|state| {
@@ -167,6 +179,9 @@ pub fn render_manage_xrp() -> Element {
                             style: "display: flex; flex-direction: column; gap: 1rem; width: 100%; align-items: center;",
                             {create_btn}
                             {import_btn}
+                            if offer_unified {
+                                {unified_btn}
+                            }
                         }
                     }
                 } else {
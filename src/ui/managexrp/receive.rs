@@ -2,6 +2,7 @@
 use dioxus_native::prelude::*;
 use crate::context::{XrpContext, GlobalContext};
 use crate::utils::receive_layout::ReceiveAddressLayout;
+use crate::clipboard::AddressChain;
 
 #[component]
 pub fn view() -> Element {
@@ -17,6 +18,7 @@ pub fn view() -> Element {
             network_name: "XRP_LEDGER".to_string(),
             protocol_label: "XRPL".to_string(),
             address: address,
+            chain: AddressChain::Xrp,
             is_dark: is_dark,
             on_back: move |_| {
                 xrp_ctx.xrp_modal.with_mut(|state| {
@@ -2,7 +2,6 @@ use dioxus_native::prelude::*;
 use crate::context::XrpContext;
 use crate::channel::{SignTradeState};
 use crate::utils::styles;
-use arboard::Clipboard; 
 
 pub mod step1;
 pub mod step2;
@@ -19,9 +18,7 @@ pub fn view() -> Element {
     let current_send = &trade_state.send_trade;
 
     let on_back_click = move |_| {
-    if let Ok(mut ctx) = Clipboard::new() {
-        let _ = ctx.set_text("");
-    }
+    crate::clipboard::clear();
 
     trade.with_mut(|state: &mut SignTradeState| {
         if let Some(ref mut send) = state.send_trade {
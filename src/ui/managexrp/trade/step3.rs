@@ -1,5 +1,7 @@
 use dioxus_native::prelude::*;
 use crate::context::{GlobalContext, XrpContext};
+use crate::clipboard::AddressChain;
+use crate::signer::session::{self, SessionLimits};
 use crate::ui::managexrp::trade::tradelogic::TradeLogic;
 use crate::utils::send_auth_layout::SendAuthForm;
 
@@ -12,7 +14,11 @@ pub fn view() -> Element {
     let (_, address_opt, _) = xrp_ctx.wallet_balance.read().clone();
     let wallet_address = address_opt.unwrap_or_else(|| "NULL_ADDR".to_string());
 
-    let input_mode = use_signal(|| "passphrase".to_string());
+    // Offer the open session first; the form falls back if it closes
+    let input_mode = use_signal(|| {
+        if session::is_active(AddressChain::Xrp) { "session" } else { "passphrase" }.to_string()
+    });
+    let session_limits = use_signal(|| None::<SessionLimits>);
     let passphrase_val = use_signal(|| String::new());
     let bip39_val = use_signal(|| String::new());
     let seed_words = use_signal(|| vec![String::new(); 24]);
@@ -39,10 +45,11 @@ pub fn view() -> Element {
             },
             "seed" => {
                 let word_count = s_words.iter().filter(|w| !w.trim().is_empty()).count();
-                if word_count != 24 {
+                if !crate::entropy::WORD_COUNTS.contains(&word_count) {
                     validation_error = Some("ERR: MNEMONIC_LENGTH_MISMATCH".to_string());
                 }
             },
+            "session" => {},
             _ => { validation_error = Some("ERR: INVALID_AUTH_MODE".to_string()); }
         }
 
@@ -90,6 +97,7 @@ pub fn view() -> Element {
             p_val,
             seed_string,
             b39,
+            session_limits(),
             base,
             quote,
             amount,
@@ -105,7 +113,9 @@ pub fn view() -> Element {
         SendAuthForm {
             step_title: "TRANSACTION_AUTHORIZATION // STEP_03".to_string(),
             network_label: "XRPL_MAINNET".to_string(),
+            chain: AddressChain::Xrp,
             input_mode,
+            session_limits,
             passphrase_val,
             seed_words,
             bip39_val,
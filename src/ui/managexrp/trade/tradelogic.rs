@@ -4,7 +4,7 @@
 use tokio::sync::mpsc::Sender;
 use crate::channel::{CHANNEL, WSCommand, ProgressState, XRPModalState, SignTradeState, ActiveView};
 use zeroize::Zeroizing;
-use arboard::Clipboard;
+use crate::signer::{self, Credentials, session::SessionLimits, xrp::XrpTx};
 
 pub struct TradeLogic;
 
@@ -15,6 +15,7 @@ impl TradeLogic {
         passphrase: String,
         mnemonic: String,
         bip39_pass: String,
+        session: Option<SessionLimits>,
         // Trade specific variables
         base_asset: String,
         quote_asset: String,
@@ -36,27 +37,19 @@ impl TradeLogic {
             message: "Preparing Trade...".to_string(),
         }));
 
-        // 2. Prepare Optional Data (BIP39)
-        let bip39_opt = if b_guard.trim().is_empty() { 
-            None 
-        } else { 
-            Some(b_guard.clone()) 
-        };
-
-        // 3. Enforce XOR Logic (Passphrase vs Seed)
-        let (passphrase, seed) = match mode.as_str() {
-            "passphrase" => {
-                let p = if p_guard.is_empty() { None } else { Some(p_guard.clone()) };
-                (p, None)
-            },
-            "seed" => {
-                let s = if m_guard.trim().is_empty() { None } else { Some(m_guard.clone()) };
-                (None, s)
-            },
-            _ => (None, None),
+        // 2. Enforce XOR Logic (Passphrase vs Seed)
+        let credentials = match Credentials::from_form(&mode, p_guard, m_guard, b_guard, session) {
+            Ok(credentials) => credentials,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
-        // 4. Calculate Trade Values
+        // 3. Calculate Trade Values
         // Amount = How much Base asset we want
         // Price = How much Quote asset per 1 Base asset
         let amount_f = amount.parse::<f64>().unwrap_or(0.0);
@@ -64,15 +57,41 @@ impl TradeLogic {
         
         // Offer Amount = Total Quote asset required (Amount * Price)
         let offer_amount_val = amount_f * price_f;
-        let offer_amount = offer_amount_val.to_string();
+        // XRP is signed in whole drops, so the product is cut to drop precision here
+        let offer_amount = if quote_asset.eq_ignore_ascii_case("XRP") {
+            format!("{:.6}", offer_amount_val)
+        } else {
+            offer_amount_val.to_string()
+        };
 
-        // 5. Structure TakerPays vs TakerGets
+        // 4. Structure TakerPays vs TakerGets
         // In this UI flow, we are "Buying" Base using Quote.
         // TakerPays: What the network pays me (I receive Base)
         // TakerGets: What I pay the network (I give Quote)
         // Note: The WSCommand struct expects tuples for these fields based on your egui code.
-        let taker_pays = Some((amount.clone(), base_asset));
-        let taker_gets = Some((offer_amount, quote_asset));
+        let taker_pays = (amount.clone(), base_asset);
+        let taker_gets = (offer_amount, quote_asset);
+
+        // 5. Sign locally; only the signed blob leaves this task
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.5,
+            message: "Signing offer...".to_string(),
+        }));
+        let tx = XrpTx::OfferCreate {
+            taker_pays: taker_pays.clone(),
+            taker_gets: taker_gets.clone(),
+            flags: flags.clone(),
+        };
+        let signed = match signer::sign_xrp(wallet_address.clone(), credentials, tx).await {
+            Ok(signed) => signed,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
+        };
 
         // 6. Construct Command
         let cmd = WSCommand {
@@ -80,25 +99,24 @@ impl TradeLogic {
             wallet: Some(wallet_address),
             recipient: None,
             amount: None, 
-            passphrase,
-            seed,
-            bip39: bip39_opt,
             trustline_limit: None,
             fee: None,
             tx_type: Some("offer_create".to_string()),
-            taker_pays,
-            taker_gets,
+            taker_pays: Some(taker_pays),
+            taker_gets: Some(taker_gets),
             flags: Some(flags),
             wallet_type: None, 
+            signed_tx: Some(signed.blob),
+            tx_hash: Some(signed.hash),
         };
 
 
         // 7. Dispatch
+        let summary = crate::audit::describe_command(&cmd);
         match ws_tx.try_send(cmd) {
             Ok(_) => {
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::audit::record(crate::audit::AuditEvent::TransactionSigned, summary);
+                crate::clipboard::clear();
                 
                 let _ = CHANNEL.trade_tx.send(SignTradeState {
                     send_trade: None,
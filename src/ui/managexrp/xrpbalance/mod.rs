@@ -31,8 +31,16 @@ pub fn render_xrp_balance() -> Element {
     };
 
     // --- STATUS ---
-    let status_color = if key_is_deleted { "var(--text-secondary)" } else { "var(--text-secondary)" };
-    let status_text = if key_is_deleted { "PURGED // KEY_OFF_DEVICE" } else { "ACTIVE // KEY_ON_DEVICE" };
+    // Nag until the mnemonic backup has been checked; irrelevant once the key is off the device
+    let backup_unverified = !key_is_deleted && !*xrp_ctx.backup_verified.read();
+    let status_color = if backup_unverified { "var(--status-warn)" } else { "var(--text-secondary)" };
+    let status_text = if key_is_deleted {
+        "PURGED // KEY_OFF_DEVICE"
+    } else if backup_unverified {
+        "ACTIVE // BACKUP_UNVERIFIED // RECHECK_WRITTEN_SEED"
+    } else {
+        "ACTIVE // KEY_ON_DEVICE"
+    };
 
     let rates = global.rates.read();
     let xrp_usd_rate = rates.get("XRP/USD").copied().unwrap_or(0.0) as f64;
@@ -4,11 +4,12 @@ use tokio::sync::mpsc::Sender;
 use tokio::time::sleep;
 use crate::utils::json_storage::{self, remove_json, get_config_path};
 use crate::channel::{CHANNEL, WSCommand, ProgressState, TransactionState};
+use crate::clipboard::AddressChain;
 
 pub struct WalletOperations;
 
 impl WalletOperations {
-    /// Deletes only the encrypted private key file (xrp_encrypt.json)
+    /// Deletes only the encrypted private key file (xrp_encrypt.json, or the unused master seed)
     pub async fn delete_key(wallet_address: String) {
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
             progress: 0.0,
@@ -17,22 +18,29 @@ impl WalletOperations {
 
         let mut delete_success = true;
 
-        // 1. Target the XRP-specific encryption file
-        if let Ok(path) = get_config_path("xrp_encrypt.json") {
-            if path.exists() {
-                if let Err(e) = remove_json("xrp_encrypt.json") {
-                    delete_success = false;
-                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
-                        progress: 0.3,
-                        message: format!("Warning: Could not delete encrypted file: {}", e),
-                    }));
-                }
-            } else {
+        // 1. Target the XRP keystore (xrp_encrypt.json, or the master seed in unified mode)
+        let unified = crate::master::is_unified(AddressChain::Xrp);
+        match crate::master::release_key(AddressChain::Xrp) {
+            Ok(true) => {}
+            Ok(false) if unified && crate::master::master_exists() => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 0.2,
+                    message: "Master seed kept while BTC still uses it; XRP marked watch-only.".to_string(),
+                }));
+            }
+            Ok(false) => {
                 let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                     progress: 0.2,
                     message: "XRP key already removed from storage.".to_string(),
                 }));
             }
+            Err(e) => {
+                delete_success = false;
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 0.3,
+                    message: format!("Warning: Could not delete encrypted file: {}", e),
+                }));
+            }
         }
 
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
@@ -59,10 +67,12 @@ impl WalletOperations {
         let (current_balance, _, _) = *CHANNEL.wallet_balance_rx.borrow();
         let _ = CHANNEL.wallet_balance_tx.send((
             current_balance,
-            Some(wallet_address),
+            Some(wallet_address.clone()),
             true, // key deleted
         ));
 
+        crate::audit::record(crate::audit::AuditEvent::KeyPurged, format!("XRP {}", wallet_address));
+
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
             progress: 1.0,
             message: if delete_success { "XRP Key deletion complete".to_string() } else { "Deletion finished with errors".to_string() },
@@ -76,12 +86,8 @@ impl WalletOperations {
         message: "Starting XRP wallet removal...".to_string(),
     }));
 
-    // 1. Delete the encrypted sensitive data
-    if let Ok(path) = get_config_path("xrp_encrypt.json") {
-        if path.exists() {
-            let _ = remove_json("xrp_encrypt.json");
-        }
-    }
+    // 1. Delete the encrypted sensitive data (a master seed BTC still uses is kept)
+    let _ = crate::master::release_key(AddressChain::Xrp);
 
     // 2. Delete the wallet metadata (xrp.json)
     if let Ok(path) = get_config_path("xrp.json") {
@@ -109,16 +115,15 @@ impl WalletOperations {
         wallet: Some(wallet_address.clone()),
         recipient: None,
         amount: None,
-        passphrase: None,
         trustline_limit: None,
         fee: None,
         tx_type: None,
         taker_pays: None,
         taker_gets: None,
-        seed: None,
         flags: None,
         wallet_type: None,
-        bip39: None,
+        signed_tx: None,
+        tx_hash: None,
     };
 
     let _ = ws_tx.try_send(command);
@@ -1,64 +1,65 @@
 //src/ui/managexrp/xrpcreate/step2.rs
-//dependent upon src/utils/wallet_security_layout.rs
+//dependent upon src/utils/seed_quiz_layout.rs
 
 use dioxus_native::prelude::*;
-use crate::context::{GlobalContext, XrpContext};
-use crate::ui::managexrp::xrpcreate::xrpcreatelogic::XRPCreateLogic;
-use crate::utils::wallet_security_layout::WalletSecurityForm;
-use zeroize::Zeroizing;
+use crate::context::XrpContext;
+use crate::channel::XRPWalletProcessState;
+use crate::utils::seed_quiz_layout::{SeedQuizForm, backup_words, pick_positions, slot_label, answers_match};
 
 #[component]
 pub fn view() -> Element {
-    let global = use_context::<GlobalContext>();
-    let mut xrp_ctx = use_context::<XrpContext>();
-    let _ = global.theme_user.read().0;
-    
-    let mut bip39_buffer = use_signal(|| String::new());
-    let mut encryption_buffer = use_signal(|| String::new());
+    let xrp_ctx = use_context::<XrpContext>();
+    let mut wallet_process = xrp_ctx.wallet_process;
 
-    let on_create_click = move |_| {
-        let b_pass = Zeroizing::new(bip39_buffer().trim().to_string());
-        let e_pass = Zeroizing::new(encryption_buffer().trim().to_string());
-        
-        let seed_opt = xrp_ctx.wallet_process.read()
-            .create_wallet.as_ref()
-            .and_then(|w| w.seed.clone());
+    // Words as written down: the mnemonic itself, or every SLIP-39 share when it was split
+    let backup = use_memo(move || {
+        wallet_process.read().create_wallet
+            .as_ref()
+            .map(|c| backup_words(
+                c.seed.as_ref().map(|s| s.as_str()).unwrap_or_default(),
+                c.shares.as_ref().map(|s| s.as_slice()),
+            ))
+            .unwrap_or_default()
+    });
 
-        if let Some(seed_guard) = seed_opt {
-            if e_pass.len() < 10 {
-                 xrp_ctx.wallet_process.with_mut(|state| {
-                    if let Some(ref mut create) = state.create_wallet {
-                        create.error = Some("ERR: MIN_10_CHARS_REQUIRED".to_string());
-                    }
-                });
-                return;
-            }
-            tokio::spawn(XRPCreateLogic::process(seed_guard, b_pass, e_pass, global.ws_tx.clone()));
-        }
+    let mut positions = use_signal(move || pick_positions(&backup.peek()));
+    let mut answers = use_signal(|| vec![String::new(); positions.peek().len()]);
 
-        bip39_buffer.set(String::new());
-        encryption_buffer.set(String::new());
-        
-        xrp_ctx.wallet_process.with_mut(|state| {
-            if let Some(ref mut create) = state.create_wallet {
-                create.seed = None; 
-                create.error = None;
-            }
-        });
+    let on_verify = move |_| {
+        if answers_match(&backup.read(), &positions(), &answers()) {
+            wallet_process.with_mut(|state: &mut XRPWalletProcessState| {
+                if let Some(ref mut create) = state.create_wallet {
+                    create.error = None;
+                    create.step = 3;
+                }
+            });
+        } else {
+            // Ask for a fresh set of positions so the quiz can't be brute-forced one slot at a time
+            let next = pick_positions(&backup.read());
+            answers.set(vec![String::new(); next.len()]);
+            positions.set(next);
+            wallet_process.with_mut(|state| {
+                if let Some(ref mut create) = state.create_wallet {
+                    create.error = Some("ERR: WORD_MISMATCH // RECHECK_WRITTEN_BACKUP".to_string());
+                }
+            });
+        }
     };
 
-    let create_state = xrp_ctx.wallet_process.read();
-    let current_error = create_state.create_wallet.as_ref().and_then(|i| i.error.clone());
+    let network_label = if wallet_process.read().create_wallet.as_ref().map(|c| c.unified).unwrap_or(false) {
+        "UNIFIED_XRP_BTC"
+    } else {
+        "XRP_MAINNET"
+    };
+    let current_error = wallet_process.read().create_wallet.as_ref().and_then(|c| c.error.clone());
 
     rsx! {
-        WalletSecurityForm {
-            flow_label: "CREATION".to_string(),
-            network_label: "XRP_MAINNET".to_string(),
-            action_label: "INIT_XRP_CREATION".to_string(),
-            bip39_buffer,
-            encryption_buffer,
+        SeedQuizForm {
+            network_label: network_label.to_string(),
+            labels: positions().into_iter().map(|slot| slot_label(&backup.read(), slot)).collect::<Vec<String>>(),
+            answers,
             current_error,
-            on_action_click: on_create_click,
+            on_verify,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,82 @@
+//src/ui/managexrp/xrpcreate/step3.rs
+//dependent upon src/utils/wallet_security_layout.rs
+
+use dioxus_native::prelude::*;
+use crate::context::{GlobalContext, XrpContext};
+use crate::ui::managexrp::xrpcreate::xrpcreatelogic::XRPCreateLogic;
+use crate::ui::unifiedlogic::UnifiedLogic;
+use crate::utils::wallet_security_layout::WalletSecurityForm;
+use crate::clipboard::AddressChain;
+use zeroize::Zeroizing;
+
+#[component]
+pub fn view() -> Element {
+    let global = use_context::<GlobalContext>();
+    let mut xrp_ctx = use_context::<XrpContext>();
+    let _ = global.theme_user.read().0;
+    
+    let mut bip39_buffer = use_signal(|| String::new());
+    let mut encryption_buffer = use_signal(|| String::new());
+
+    let on_create_click = move |_| {
+        let b_pass = Zeroizing::new(bip39_buffer().trim().to_string());
+        let e_pass = Zeroizing::new(encryption_buffer().trim().to_string());
+        
+        let (seed_opt, unified) = xrp_ctx.wallet_process.read()
+            .create_wallet.as_ref()
+            .map(|w| (w.seed.clone(), w.unified))
+            .unwrap_or((None, false));
+
+        // A unified flow without a seed re-derives this chain from the existing master seed
+        if seed_opt.is_some() || unified {
+            if e_pass.len() < 10 {
+                 xrp_ctx.wallet_process.with_mut(|state| {
+                    if let Some(ref mut create) = state.create_wallet {
+                        create.error = Some("ERR: MIN_10_CHARS_REQUIRED".to_string());
+                    }
+                });
+                return;
+            }
+            let ws_tx = global.ws_tx.clone();
+            match seed_opt {
+                Some(seed_guard) if unified => { tokio::spawn(UnifiedLogic::create(seed_guard, b_pass, e_pass, ws_tx)); }
+                Some(seed_guard) => { tokio::spawn(XRPCreateLogic::process(seed_guard, b_pass, e_pass, ws_tx)); }
+                None if crate::master::master_exists() => {
+                    tokio::spawn(UnifiedLogic::attach(AddressChain::Xrp, b_pass, e_pass, ws_tx));
+                }
+                None => {}
+            }
+        }
+
+        bip39_buffer.set(String::new());
+        encryption_buffer.set(String::new());
+        
+        xrp_ctx.wallet_process.with_mut(|state| {
+            if let Some(ref mut create) = state.create_wallet {
+                create.seed = None; 
+                create.error = None;
+            }
+        });
+    };
+
+    let create_state = xrp_ctx.wallet_process.read();
+    let current_error = create_state.create_wallet.as_ref().and_then(|i| i.error.clone());
+    let (network_label, action_label) = match create_state.create_wallet.as_ref().map(|c| (c.unified, c.seed.is_some())) {
+        Some((true, true)) => ("UNIFIED_XRP_BTC", "INIT_UNIFIED_CREATION"),
+        Some((true, false)) => ("MASTER_SEED // XRP_MAINNET", "DERIVE_XRP_FROM_MASTER"),
+        _ => ("XRP_MAINNET", "INIT_XRP_CREATION"),
+    };
+
+    rsx! {
+        WalletSecurityForm {
+            flow_label: "CREATION".to_string(),
+            step: 3,
+            network_label: network_label.to_string(),
+            action_label: action_label.to_string(),
+            bip39_buffer,
+            encryption_buffer,
+            current_error,
+            on_action_click: on_create_click,
+        }
+    }
+}
\ No newline at end of file
@@ -3,20 +3,10 @@ use tokio::sync::mpsc::Sender;
 use xrpl::wallet::Wallet;
 use bip39::{Language, Mnemonic};
 use ripple_address_codec::{encode_seed, Ed25519};
-use crate::encrypt::encrypt_data;
-use crate::utils::json_storage::write_json; // Use your utility
+use crate::keystore::{self, KeystoreRecord};
+use crate::secmem::SecretString;
 use crate::channel::{CHANNEL, WSCommand, ProgressState, XRPWalletProcessState, XRPModalState, ActiveView};
-use arboard::Clipboard; 
 use zeroize::{Zeroize, Zeroizing};
-use serde::Serialize;
-
-#[derive(Serialize)]
-struct EncryptedWalletData {
-    address: String,
-    encrypted_phrase: String,
-    salt: String,
-    iv: String,
-}
 
 pub struct XRPCreateLogic;
 
@@ -32,17 +22,19 @@ impl XRPCreateLogic {
             message: "Finalizing wallet creation...".to_string(),
         }));
 
-        let m_thread = mnemonic_phrase.clone();
-        let b_thread = bip39_pass.clone();
-        let e_thread = encryption_pass.clone();
+        // A single locked copy of each secret, moved into the worker thread
+        let m_thread = SecretString::from(mnemonic_phrase);
+        let b_thread = SecretString::from(bip39_pass);
+        let e_thread = SecretString::from(encryption_pass);
 
-        let crypto_result = tokio::task::spawn_blocking(move || -> Result<(String, String, String, String), String> {
+        let crypto_result = tokio::task::spawn_blocking(move || -> Result<KeystoreRecord, String> {
             let mnemonic = Mnemonic::parse_in(Language::English, m_thread.as_str())
                 .map_err(|e| format!("Invalid mnemonic: {}", e))?;
 
-            let seed_bytes = mnemonic.to_seed(b_thread.as_str());
+            let mut seed_bytes = mnemonic.to_seed(b_thread.as_str());
             
             let mut entropy: [u8; 16] = seed_bytes[0..16].try_into().expect("BIP39 Invalid Seed");
+            seed_bytes.zeroize();
             let mut base58_seed = encode_seed(&entropy, &Ed25519);
             
             entropy.zeroize(); 
@@ -56,35 +48,36 @@ impl XRPCreateLogic {
             let address = wallet.classic_address.clone();
             base58_seed.zeroize();
 
-            let (enc, salt, iv) = encrypt_data(e_thread, m_thread)
-                .map_err(|e| format!("Encryption failed: {}", e))?;
-
-            Ok((address, enc, salt, iv))
+            // Seal the mnemonic into a versioned keystore record
+            keystore::seal(address, &e_thread, &m_thread)
+                .map_err(|e| format!("Encryption failed: {}", e))
         }).await;
 
         match crypto_result {
-            Ok(Ok((address, encrypted, salt, iv))) => {
+            Ok(Ok(record)) => {
+                let address = record.address.clone();
 
                 let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                     progress: 0.5,
                     message: "Saving encrypted credentials...".to_string(),
                 }));
 
-                // --- DETERMINISTIC FILE STORAGE ---
-                let wallet_data = EncryptedWalletData {
-                    address: address.clone(),
-                    encrypted_phrase: encrypted,
-                    salt,
-                    iv,
-                };
-
-                if let Err(e) = write_json("xrp_encrypt.json", &wallet_data) {
+                if let Err(e) = keystore::save("xrp_encrypt.json", &record) {
                     let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                         progress: 1.0,
                         message: format!("File System Error: {}", e),
                     }));
                     return; 
                 }
+
+                // Step 2 already checked the written backup
+                if let Err(e) = crate::wallet::set_backup_verified("xrp.json", &address) {
+                    let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                        progress: 1.0,
+                        message: format!("Error: Could not record backup verification: {}", e),
+                    }));
+                }
+                let _ = CHANNEL.xrp_backup_verified_tx.send(true);
                 // ----------------------------------
 
                 let _ = ws_tx.try_send(WSCommand {
@@ -92,21 +85,18 @@ impl XRPCreateLogic {
                     wallet: Some(address),
                     recipient: None,
                     amount: None,
-                    passphrase: None,
                     trustline_limit: None,
                     fee: None,
                     tx_type: None,
                     taker_pays: None,
                     taker_gets: None,
-                    seed: None,
                     flags: None,
                     wallet_type: None,
-                    bip39: None,
+                    signed_tx: None,
+                    tx_hash: None,
                 });
 
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::clipboard::clear();
 
                 let _ = CHANNEL.xrp_wallet_process_tx.send(XRPWalletProcessState {
                     import_wallet: None,
@@ -3,7 +3,6 @@ use dioxus_native::prelude::*;
 use crate::context::XrpContext;
 use crate::channel::{ActiveView}; 
 use crate::utils::styles;
-use arboard::Clipboard;  
 
 
 pub mod step1;
@@ -22,9 +21,7 @@ pub fn view() -> Element {
     
     let on_back_click = move |_| {
         // 1. Clear clipboard immediately
-        if let Ok(mut ctx) = Clipboard::new() {
-            let _ = ctx.set_text("");
-        }
+        crate::clipboard::clear();
 
         // 2. Handle Data: Step down 
         wallet_process.with_mut(|state| {
@@ -42,6 +42,7 @@ pub fn view() -> Element {
     rsx! {
         WalletSecurityForm {
             flow_label: "IMPORT".to_string(), // New required prop
+            step: 2,
             network_label: "XRP_MAINNET".to_string(),
             action_label: "INIT_XRP_IMPORT".to_string(),
             bip39_buffer: bip39_buffer,
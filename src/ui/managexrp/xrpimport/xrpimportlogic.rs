@@ -2,22 +2,11 @@
 
 use tokio::sync::mpsc::Sender;
 use xrpl::wallet::Wallet;
-use bip39::{Language, Mnemonic};
 use ripple_address_codec::{encode_seed, Ed25519};
-use crate::encrypt::encrypt_data;
-use crate::utils::json_storage::write_json; // Use your utility
+use crate::keystore::{self, KeystoreRecord};
+use crate::secmem::SecretString;
 use crate::channel::{CHANNEL, WSCommand, ProgressState, XRPWalletProcessState, XRPModalState, ActiveView};
-use arboard::Clipboard; 
 use zeroize::{Zeroize, Zeroizing};
-use serde::Serialize;
-
-#[derive(Serialize)]
-struct EncryptedWalletData {
-    address: String,
-    encrypted_phrase: String,
-    salt: String,
-    iv: String,
-}
 
 pub struct XRPImportLogic;
 
@@ -33,18 +22,27 @@ impl XRPImportLogic {
         message: "Starting XRP wallet import...".to_string(),
     }));
 
-    let m_thread = mnemonic_phrase.clone();
-    let b_thread = bip39_pass.clone();
-    let e_thread = encryption_pass.clone();
+    // A single locked copy of each secret, moved into the worker thread
+    let m_thread = SecretString::from(mnemonic_phrase);
+    let b_thread = SecretString::from(bip39_pass);
+    let e_thread = SecretString::from(encryption_pass);
+
+    let crypto_result = tokio::task::spawn_blocking(move || -> Result<KeystoreRecord, String> {
+        // SLIP-39 shares arrive one per line; recombine them into the BIP39 phrase that gets sealed
+        let m_thread = if crate::slip39::looks_like_shares(&m_thread) {
+            SecretString::from(crate::slip39::recover_mnemonic(&m_thread)?)
+        } else {
+            m_thread
+        };
 
-    let crypto_result = tokio::task::spawn_blocking(move || -> Result<(String, String, String, String), String> {
-        let mnemonic = Mnemonic::parse_in(Language::English, m_thread.as_str())
-            .map_err(|e| format!("Invalid mnemonic: {}", e))?;
+        // Any BIP39 language the import form recognised
+        let mnemonic = crate::mnemonic::parse_any(m_thread.as_str())?;
 
-        let seed_bytes = mnemonic.to_seed(b_thread.as_str());
+        let mut seed_bytes = mnemonic.to_seed(b_thread.as_str());
         
         // XRP specific derivation (Ed25519)
         let mut entropy: [u8; 16] = seed_bytes[0..16].try_into().expect("BIP39 Invalid Seed");
+        seed_bytes.zeroize();
         let mut base58_seed = encode_seed(&entropy, &Ed25519);
         
         entropy.zeroize(); 
@@ -58,30 +56,21 @@ impl XRPImportLogic {
         let address = wallet.classic_address.clone();
         base58_seed.zeroize();
 
-        // Encrypt mnemonic with AES-256
-        let (enc, salt, iv) = encrypt_data(e_thread, m_thread)
-            .map_err(|e| format!("Encryption failed: {}", e))?;
-
-        Ok((address, enc, salt, iv))
+        // Seal the mnemonic into a versioned keystore record
+        keystore::seal(address, &e_thread, &m_thread)
+            .map_err(|e| format!("Encryption failed: {}", e))
     }).await;
 
     match crypto_result {
-        Ok(Ok((address, encrypted, salt, iv))) => {
+        Ok(Ok(record)) => {
+            let address = record.address.clone();
             let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                 progress: 0.5,
                 message: "Saving encrypted XRP credentials...".to_string(),
             }));
 
-            // --- FILE-BASED STORAGE ---
-            let wallet_data = EncryptedWalletData {
-                address: address.clone(),
-                encrypted_phrase: encrypted,
-                salt,
-                iv,
-            };
-
             // Using xrp_encrypt.json for asset isolation
-            if let Err(e) = write_json("xrp_encrypt.json", &wallet_data) {
+            if let Err(e) = keystore::save("xrp_encrypt.json", &record) {
                 let _ = CHANNEL.progress_tx.send(Some(ProgressState {
                     progress: 1.0,
                     message: format!("File System Error: {}", e),
@@ -89,26 +78,33 @@ impl XRPImportLogic {
                 return;
             }
 
+            // An imported mnemonic was typed in from the backup itself
+            if let Err(e) = crate::wallet::set_backup_verified("xrp.json", &address) {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: Could not record backup verification: {}", e),
+                }));
+            }
+            let _ = CHANNEL.xrp_backup_verified_tx.send(true);
+            crate::audit::record(crate::audit::AuditEvent::WalletImported, format!("XRP {}", address));
+
             let _ = ws_tx.try_send(WSCommand {
                 command: "import_wallet".to_string(), // Backend likely expects this for XRP
                 wallet: Some(address),
                 recipient: None,
                 amount: None,
-                passphrase: None,
                 trustline_limit: None,
                 fee: None,
                 tx_type: None,
                 taker_pays: None,
                 taker_gets: None,
-                seed: None,
                 flags: None,
                 wallet_type: None,
-                bip39: None,
+                signed_tx: None,
+                tx_hash: None,
             });
 
-            if let Ok(mut ctx) = Clipboard::new() {
-                let _ = ctx.set_text("");
-            }
+            crate::clipboard::clear();
 
             let _ = CHANNEL.xrp_wallet_process_tx.send(XRPWalletProcessState {
                 import_wallet: None,
@@ -2,7 +2,6 @@ use dioxus_native::prelude::*;
 use crate::context::XrpContext;
 use crate::channel::{SignTransactionState};
 use crate::utils::styles;
-use arboard::Clipboard; // Added for clipboard clearing
 
 pub mod step1;
 pub mod step2;
@@ -20,9 +19,7 @@ pub fn view() -> Element {
     let current_send = &sign_state.send_transaction;
 
     let on_back_click = move |_| {
-    if let Ok(mut ctx) = Clipboard::new() {
-        let _ = ctx.set_text("");
-    }
+    crate::clipboard::clear();
 
     sign_transaction.with_mut(|state: &mut SignTransactionState| {
         if let Some(ref mut send) = state.send_transaction {
@@ -3,7 +3,7 @@
 use tokio::sync::mpsc::Sender;
 use crate::channel::{CHANNEL, WSCommand, ProgressState, XRPModalState, SignTransactionState, ActiveView};
 use zeroize::Zeroizing;
-use arboard::Clipboard;
+use crate::signer::{self, Credentials, session::SessionLimits, xrp::XrpTx};
 
 pub struct XRPSendLogic;
 
@@ -13,6 +13,7 @@ impl XRPSendLogic {
         passphrase: String,      
         mnemonic: String,        
         bip39_pass: String,      
+        session: Option<SessionLimits>,
         recipient: String,
         amount: String,
         wallet_address: String,
@@ -31,38 +32,45 @@ impl XRPSendLogic {
             message: "Initiating transaction...".to_string(),
         }));
 
-        // 2. Prepare Optional Data (BIP39)
-        // FIX: We clone the *guard* (Zeroizing wrapper), not the raw string inside.
-        let bip39_opt = if b_guard.trim().is_empty() { 
-            None 
-        } else { 
-            Some(b_guard.clone()) 
+        // 2. Enforce XOR Logic (Passphrase vs Seed)
+        let credentials = match Credentials::from_form(&mode, p_guard, m_guard, b_guard, session) {
+            Ok(credentials) => credentials,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
-        // 3. Enforce XOR Logic (Passphrase vs Seed)
-        // FIX: We move or clone the Zeroizing guards directly.
-        let (passphrase, seed) = match mode.as_str() {
-            "passphrase" => {
-                let p = if p_guard.is_empty() { None } else { Some(p_guard.clone()) };
-                (p, None) 
-            },
-            "seed" => {
-                let s = if m_guard.trim().is_empty() { None } else { Some(m_guard.clone()) };
-                (None, s) 
-            },
-            _ => (None, None), 
+        // 3. Sign locally; only the signed blob leaves this task
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.5,
+            message: "Signing transaction...".to_string(),
+        }));
+        let tx = XrpTx::Payment {
+            destination: recipient.clone(),
+            value: amount.clone(),
+            asset: asset.clone(),
+        };
+        let signed = match signer::sign_xrp(wallet_address.clone(), credentials, tx).await {
+            Ok(signed) => signed,
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
         };
 
         // 4. Construct Command
-        // The fields in WSCommand are now Option<Zeroizing<String>>
         let cmd = WSCommand {
             command: "submit_transaction".to_string(),
             wallet: Some(wallet_address), 
             recipient: Some(recipient),
             amount: Some(amount),
-            passphrase, 
-            seed,       
-            bip39: bip39_opt,
             trustline_limit: None,
             fee: None,
             tx_type: Some("payment".to_string()),
@@ -70,14 +78,16 @@ impl XRPSendLogic {
             taker_gets: None,
             flags: None,
             wallet_type: Some(asset),
+            signed_tx: Some(signed.blob),
+            tx_hash: Some(signed.hash),
         };
 
         // 5. Dispatch
+        let summary = crate::audit::describe_command(&cmd);
         match ws_tx.try_send(cmd) {
             Ok(_) => {
-                if let Ok(mut ctx) = Clipboard::new() {
-                    let _ = ctx.set_text("");
-                }
+                crate::audit::record(crate::audit::AuditEvent::TransactionSigned, summary);
+                crate::clipboard::clear();
                 let _ = CHANNEL.sign_transaction_tx.send(SignTransactionState {
                     send_transaction: None, 
                 });
@@ -10,7 +10,13 @@ pub mod progressbar;
 pub mod managebtc;
 pub mod changepin;
 pub mod pinlogic;
+pub mod changepassphrase;
+pub mod passphraselogic;
+pub mod unifiedlogic;
+pub mod backup;
+pub mod backuplogic;
 pub mod networkstatus;
+pub mod auditlog;
 
 
 
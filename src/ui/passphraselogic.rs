@@ -0,0 +1,59 @@
+use zeroize::Zeroizing;
+use crate::channel::{CHANNEL, ProgressState, SideBarView};
+use crate::secmem::SecretString;
+use crate::clipboard::AddressChain;
+
+pub struct PassphraseLogic;
+
+impl PassphraseLogic {
+    /// Maps the chain label used by the views to its keystore file. For a unified wallet that is
+    /// the master seed, so the new passphrase applies to both chains.
+    pub fn keystore_file(chain: &str) -> &'static str {
+        crate::master::keystore_file(if chain == "BTC" { AddressChain::Btc } else { AddressChain::Xrp })
+    }
+
+    pub async fn change_passphrase(
+        chain: String,
+        old_passphrase: Zeroizing<String>,
+        new_passphrase: Zeroizing<String>,
+    ) {
+        // Both passphrases stay pinned in RAM through the two slow KDF runs
+        let old_passphrase = SecretString::from(old_passphrase);
+        let new_passphrase = SecretString::from(new_passphrase);
+
+        // 1. Verification State
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.3,
+            message: format!("Decrypting {} key...", chain),
+        }));
+
+        // 2. Decrypt with the old passphrase and re-seal under the new one
+        let filename = Self::keystore_file(&chain);
+        let result = tokio::task::spawn_blocking(move || {
+            crate::keystore::change_passphrase(filename, &old_passphrase, &new_passphrase)
+        }).await;
+
+        match result {
+            Ok(Ok(())) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("{} passphrase updated successfully!", chain),
+                }));
+
+                let _ = CHANNEL.sidebar_view_tx.send(SideBarView::None);
+            }
+            Ok(Err(e)) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("SIGNAL_INTERRUPT: Passphrase update failed: {}", e),
+                }));
+            }
+            Err(_) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: "SIGNAL_INTERRUPT: Passphrase update failed.".to_string(),
+                }));
+            }
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::channel::{CHANNEL, ProgressState, SideBarView}; 
+use crate::pin::UnlockFormat;
 
 pub struct PinLogic;
 
 impl PinLogic {
-    pub async fn change_pin(old_pin: String, new_pin: String) {
+    pub async fn change_pin(old_pin: String, new_pin: String, format: UnlockFormat) {
         // 1. Verification State
         let _ = CHANNEL.progress_tx.send(Some(ProgressState {
             progress: 0.5,
@@ -12,7 +13,7 @@ impl PinLogic {
 
         // 2. Perform the actual PIN change
         let result = tokio::task::spawn_blocking(move || {
-            crate::pin::change_pin(&old_pin, &new_pin)
+            crate::pin::change_pin(&old_pin, &new_pin, format)
         }).await;
 
         match result {
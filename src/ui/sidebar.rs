@@ -2,6 +2,7 @@ use dioxus_native::prelude::*;
 use crate::context::GlobalContext;
 use crate::channel::{CHANNEL, SideBarView};
 use crate::utils::styles::terminal_action;
+use crate::utils::signing_session_layout::SessionCountdown;
 
 pub fn render_theme_toggle() -> Element {
     let global = use_context::<GlobalContext>();
@@ -34,6 +35,26 @@ pub fn render_pin_button() -> Element {
     })
 }
 
+pub fn render_passphrase_button() -> Element {
+    let global = use_context::<GlobalContext>();
+    let sidebar_view = *global.sidebar_view.read();
+    let is_active = sidebar_view == SideBarView::ChangePassphrase;
+
+    terminal_action("KEYS", is_active, move |_| {
+        let _ = CHANNEL.sidebar_view_tx.send(SideBarView::ChangePassphrase);
+    })
+}
+
+pub fn render_backup_button() -> Element {
+    let global = use_context::<GlobalContext>();
+    let sidebar_view = *global.sidebar_view.read();
+    let is_active = sidebar_view == SideBarView::Backup;
+
+    terminal_action("BACKUP", is_active, move |_| {
+        let _ = CHANNEL.sidebar_view_tx.send(SideBarView::Backup);
+    })
+}
+
 pub fn render_rates_button() -> Element {
     let global = use_context::<GlobalContext>();
     let sidebar_view = *global.sidebar_view.read();
@@ -52,4 +73,41 @@ pub fn render_network_button() -> Element {
     terminal_action("NETWORK", is_active, move |_| {
         let _ = CHANNEL.sidebar_view_tx.send(SideBarView::NetworkStatus);
     })
+}
+
+pub fn render_audit_button() -> Element {
+    let global = use_context::<GlobalContext>();
+    let sidebar_view = *global.sidebar_view.read();
+    let is_active = sidebar_view == SideBarView::AuditLog;
+
+    terminal_action("AUDIT", is_active, move |_| {
+        let _ = CHANNEL.sidebar_view_tx.send(SideBarView::AuditLog);
+    })
+}
+
+// Only shown while a signing session keeps a key unlocked
+pub fn render_session_indicator() -> Element {
+    let global = use_context::<GlobalContext>();
+    let sessions = global.signing_sessions.read().clone();
+    if sessions.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            style: "display: flex; align-items: center; gap: 0.5rem; font-size: 0.7rem; color: var(--status-warn);",
+            for session in sessions {
+                SessionCountdown { key: "{session.chain.label()}", session }
+            }
+            {terminal_action("END_SESSION", true, move |_| {
+                crate::signer::session::end_all("ended by user");
+            })}
+        }
+    }
+}
+
+pub fn render_lock_button() -> Element {
+    terminal_action("LOCK", false, move |_| {
+        crate::lock::lock_now();
+    })
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+// src/ui/unifiedlogic.rs
+//
+// Final step of the unified wallet flow, reachable from both xrpcreate and btccreate.
+// `create` seals a new master seed and registers both accounts; `attach` re-derives one chain
+// from an existing master seed.
+
+use tokio::sync::mpsc::Sender;
+use zeroize::Zeroizing;
+use crate::channel::{
+    CHANNEL, WSCommand, ProgressState, XRPWalletProcessState, XRPModalState, ActiveView,
+    BTCWalletProcessState, BTCModalState, BTCActiveView,
+};
+use crate::clipboard::AddressChain;
+use crate::audit::{self, AuditEvent};
+use crate::master::{self, UnifiedAccounts};
+use crate::secmem::SecretString;
+
+pub struct UnifiedLogic;
+
+impl UnifiedLogic {
+    pub async fn create(
+        mnemonic_phrase: Zeroizing<String>,
+        bip39_pass: Zeroizing<String>,
+        encryption_pass: Zeroizing<String>,
+        ws_tx: Sender<WSCommand>,
+    ) {
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.0,
+            message: "Sealing master seed...".to_string(),
+        }));
+
+        let m_thread = SecretString::from(mnemonic_phrase);
+        let b_thread = SecretString::from(bip39_pass);
+        let e_thread = SecretString::from(encryption_pass);
+
+        let result = tokio::task::spawn_blocking(move || {
+            master::create(&m_thread, &b_thread, &e_thread)
+        }).await;
+
+        Self::finish(result, &[AddressChain::Xrp, AddressChain::Btc], ws_tx);
+    }
+
+    pub async fn attach(
+        chain: AddressChain,
+        bip39_pass: Zeroizing<String>,
+        encryption_pass: Zeroizing<String>,
+        ws_tx: Sender<WSCommand>,
+    ) {
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 0.0,
+            message: "Decrypting master seed...".to_string(),
+        }));
+
+        let b_thread = SecretString::from(bip39_pass);
+        let e_thread = SecretString::from(encryption_pass);
+
+        let result = tokio::task::spawn_blocking(move || {
+            master::attach(chain, &b_thread, &e_thread)
+        }).await;
+
+        Self::finish(result, &[chain], ws_tx);
+    }
+
+    fn finish(
+        result: Result<Result<UnifiedAccounts, String>, tokio::task::JoinError>,
+        chains: &[AddressChain],
+        ws_tx: Sender<WSCommand>,
+    ) {
+        let accounts = match result {
+            Ok(Ok(accounts)) => accounts,
+            Ok(Err(e)) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Error: {}", e),
+                }));
+                return;
+            }
+            Err(e) => {
+                let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+                    progress: 1.0,
+                    message: format!("Internal Thread Error: {}", e),
+                }));
+                return;
+            }
+        };
+
+        for &chain in chains {
+            Self::register(chain, accounts.address(chain), &ws_tx);
+        }
+        if chains.contains(&AddressChain::Btc) {
+            crate::wallet::spawn_bitcoin_refresh(accounts.address(AddressChain::Btc).to_string());
+        }
+
+        // Re-deriving one chain from the existing master seed brings a key back into use
+        if let [chain] = chains {
+            let label = match chain {
+                AddressChain::Xrp => "XRP",
+                AddressChain::Btc => "BTC",
+            };
+            audit::record(AuditEvent::WalletImported, format!("{} {} (master seed {})", label, accounts.address(*chain), accounts.fingerprint));
+        }
+
+        crate::clipboard::clear();
+
+        let _ = CHANNEL.xrp_wallet_process_tx.send(XRPWalletProcessState {
+            import_wallet: None,
+            create_wallet: None,
+        });
+        let _ = CHANNEL.xrp_modal_tx.send(XRPModalState {
+            view_type: ActiveView::XRP,
+            last_view: None,
+        });
+        let _ = CHANNEL.btc_wallet_process_tx.send(BTCWalletProcessState {
+            import_wallet: None,
+            create_wallet: None,
+        });
+        let _ = CHANNEL.btc_modal_tx.send(BTCModalState {
+            view_type: BTCActiveView::BTC,
+            last_view: None,
+        });
+
+        let _ = CHANNEL.progress_tx.send(Some(ProgressState {
+            progress: 1.0,
+            message: format!("Master seed {} ready", accounts.fingerprint),
+        }));
+    }
+
+    // Same backend registration the per-chain create flows send
+    fn register(chain: AddressChain, address: &str, ws_tx: &Sender<WSCommand>) {
+        let command = match chain {
+            AddressChain::Xrp => {
+                let _ = CHANNEL.xrp_backup_verified_tx.send(true);
+                "import_wallet"
+            }
+            AddressChain::Btc => {
+                let _ = CHANNEL.btc_backup_verified_tx.send(true);
+                "import_bitcoin_wallet"
+            }
+        };
+
+        let _ = ws_tx.try_send(WSCommand {
+            command: command.to_string(),
+            wallet: Some(address.to_string()),
+            recipient: None,
+            amount: None,
+            trustline_limit: None,
+            fee: None,
+            tx_type: None,
+            taker_pays: None,
+            taker_gets: None,
+            flags: None,
+            wallet_type: None,
+            signed_tx: None,
+            tx_hash: None,
+        });
+    }
+}
@@ -0,0 +1,34 @@
+// src/utils/address_type_layout.rs
+// Script type choice for the Bitcoin create and import flows.
+
+use dioxus_native::prelude::*;
+use crate::btcaccount::AddressType;
+use crate::utils::styles::terminal_action;
+
+fn prefix(address_type: AddressType) -> &'static str {
+    match address_type {
+        AddressType::Legacy => "1...",
+        AddressType::NestedSegwit => "3...",
+        AddressType::NativeSegwit => "bc1q...",
+        AddressType::Taproot => "bc1p...",
+    }
+}
+
+#[component]
+pub fn AddressTypePicker(address_type: AddressType, on_select: EventHandler<AddressType>) -> Element {
+    let buttons: Vec<Element> = AddressType::ALL.iter().map(|&choice| {
+        terminal_action(choice.label(), choice == address_type, move |_| on_select.call(choice))
+    }).collect();
+
+    rsx! {
+        div { class: "input-section",
+            div { class: "input-label-row",
+                div { class: "input-label", "ADDRESS_TYPE" }
+                div { class: "input-hint", "[ADDRESSES_START_WITH_{prefix(address_type)}]" }
+            }
+            div { style: "display: flex; flex-wrap: wrap; gap: 0.25rem;",
+                {buttons.into_iter()}
+            }
+        }
+    }
+}
@@ -6,8 +6,16 @@ pub fn CreateSeedForm(
     network_label: String,
     words: Vec<String>,
     on_copy: EventHandler<MouseEvent>,
+    on_slip39: EventHandler<MouseEvent>,
+    mut print_path: Signal<String>,
+    mut print_entropy: Signal<bool>,
+    print_status: Option<String>,
+    on_print: EventHandler<MouseEvent>,
     on_continue: EventHandler<MouseEvent>,
+    children: Element,
 ) -> Element {
+    let mut show_print = use_signal(|| false);
+
     rsx! {
         style { {r#"
             .create-step-container {
@@ -72,6 +80,29 @@ pub fn CreateSeedForm(
                 letter-spacing: 1px;
             }
 
+            .print-panel {
+                margin-top: 1.5rem;
+                border: 1px dashed var(--border);
+                padding: 1rem;
+                display: flex;
+                flex-direction: column;
+                gap: 0.75rem;
+            }
+
+            .print-label { font-size: 0.65rem; color: var(--accent); letter-spacing: 1px; }
+
+            .print-input {
+                background: var(--input-bg);
+                border: 1px solid var(--border);
+                outline: none;
+                color: var(--text);
+                font-family: inherit;
+                font-size: 0.8rem;
+                padding: 0.5rem 0.75rem;
+            }
+
+            .print-status { font-size: 0.65rem; color: var(--text-secondary); word-break: break-all; }
+
             .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-start; align-items: center; gap: 2rem; }
         "#} }
 
@@ -91,15 +122,46 @@ pub fn CreateSeedForm(
                 }
             }
 
+            // Length / entropy options supplied by the caller
+            {children}
+
             // 3. Warning
             div { class: "warning-banner", 
                 ">> CAUTION: Do not lose this seed phrase. If you lose the seed, you lose your coins." 
             }
 
-            // 4. Actions
+            // 4. Paper backup
+            if show_print() {
+                div { class: "print-panel",
+                    div { class: "print-label", "PAPER_BACKUP // SVG_OUTPUT_PATH" }
+                    input {
+                        class: "print-input",
+                        spellcheck: false,
+                        value: "{print_path}",
+                        oninput: move |e| print_path.set(e.value()),
+                    }
+                    div { style: "display: flex; gap: 1rem;",
+                        {terminal_action("INCLUDE_ENTROPY_QR", print_entropy(), move |_| {
+                            let next = !print_entropy();
+                            print_entropy.set(next);
+                        })}
+                        {terminal_action("WRITE_SHEET", true, move |e| on_print.call(e))}
+                    }
+                    if let Some(status) = print_status {
+                        div { class: "print-status", ">> {status}" }
+                    }
+                }
+            }
+
+            // 5. Actions
             div { class: "footer-nav",
                 div { style: "display: flex; gap: 1rem;",
                     {terminal_action("COPY", false, move |e| on_copy.call(e))},
+                    {terminal_action("SPLIT_SLIP39", false, move |e| on_slip39.call(e))},
+                    {terminal_action("PRINT_BACKUP", show_print(), move |_| {
+                        let next = !show_print();
+                        show_print.set(next);
+                    })},
                     {terminal_action("CONTINUE", true, move |e| on_continue.call(e))}
                 }
             }
@@ -1,6 +1,10 @@
 // src/utils/enable_layout.rs
 use dioxus_native::prelude::*;
 use crate::utils::styles::terminal_action;
+use crate::utils::signing_session_layout::{use_open_session, SessionNotice, SessionPicker};
+use crate::clipboard::AddressChain;
+use crate::context::GlobalContext;
+use crate::signer::session::SessionLimits;
 
 #[component]
 pub fn render_token_enable(
@@ -8,7 +12,9 @@ pub fn render_token_enable(
     reserve_info: String,
     enable_btn_text: String,
     has_token: bool,
+    chain: AddressChain,
     input_mode: Signal<String>,
+    session_limits: Signal<Option<SessionLimits>>,
     passphrase_val: Signal<String>,
     bip39_val: Signal<String>,
     seed_words: Signal<Vec<String>>,
@@ -17,6 +23,17 @@ pub fn render_token_enable(
 
     children: Element,
 ) -> Element {
+    let open_session = use_open_session(chain);
+    let global = use_context::<GlobalContext>();
+
+    // The session can expire while this form is open; fall back to the passphrase
+    use_effect(move || {
+        let still_open = global.signing_sessions.read().iter().any(|s| s.chain == chain);
+        if input_mode() == "session" && !still_open {
+            input_mode.set("passphrase".to_string());
+        }
+    });
+
     rsx! {
         style { {r#"
             .terminal-container {
@@ -69,6 +86,16 @@ pub fn render_token_enable(
                 }
 
                 div { class: "auth-tabs",
+                    if open_session.is_some() {
+                        button {
+                            class: if input_mode() == "session" { "auth-tab auth-tab-active" } else { "auth-tab" },
+                            onclick: move |_| {
+                                input_mode.set("session".to_string());
+                                error_msg.set(None);
+                            },
+                            "SIGNING_SESSION"
+                        }
+                    }
                     button { 
                         class: if input_mode() == "passphrase" { "auth-tab auth-tab-active" } else { "auth-tab" },
                         onclick: move |_| {
@@ -87,7 +114,11 @@ pub fn render_token_enable(
                     }
                 }
 
-                if input_mode() == "passphrase" {
+                if input_mode() == "session" {
+                    if let Some(session) = open_session {
+                        SessionNotice { session }
+                    }
+                } else if input_mode() == "passphrase" {
                     div { class: "input-section",
                         div { class: "input-label-row", div { class: "input-label", "ENCRYPTION_KEY" } }
                         div { class: "terminal-input-wrapper",
@@ -136,20 +167,24 @@ pub fn render_token_enable(
                     }
                 }
 
-                div { class: "input-section",
-                    div { class: "input-label-row", 
-                        div { class: "input-label", "BIP39_PASSPHRASE" } 
-                        div { class: "input-hint", "[OPTIONAL]" }
-                    }
-                    div { class: "terminal-input-wrapper",
-                        span { class: "bracket", "[" }
-                        input {
-                            class: "inner-input",
-                            value: "{bip39_val()}",
-                            oninput: move |e| bip39_val.set(e.value())
+                if input_mode() != "session" {
+                    div { class: "input-section",
+                        div { class: "input-label-row", 
+                            div { class: "input-label", "BIP39_PASSPHRASE" } 
+                            div { class: "input-hint", "[OPTIONAL]" }
+                        }
+                        div { class: "terminal-input-wrapper",
+                            span { class: "bracket", "[" }
+                            input {
+                                class: "inner-input",
+                                value: "{bip39_val()}",
+                                oninput: move |e| bip39_val.set(e.value())
+                            }
+                            span { class: "bracket", "]" }
                         }
-                        span { class: "bracket", "]" }
                     }
+
+                    SessionPicker { session_limits }
                 }
 
                 if let Some(err) = error_msg() {
@@ -1,14 +1,47 @@
 //src/utils/import_seed_layout.rs
 use dioxus_native::prelude::*;
 use crate::utils::styles::terminal_action;
+use crate::mnemonic::{self, WordState};
 
 #[component]
 pub fn ImportSeedForm(
     network_label: String,
     mut seed_words: Signal<Vec<String>>,
     mut error_msg: Signal<Option<String>>,
+    on_slip39: EventHandler<MouseEvent>,
     on_continue: EventHandler<MouseEvent>,
 ) -> Element {
+    // Cell whose suggestions are shown; the one typed into last
+    let mut active_cell = use_signal(|| None::<usize>);
+
+    let language = mnemonic::detect_language(&seed_words.read());
+    let language_label = language.map(mnemonic::language_name).unwrap_or("DETECTING");
+    let cell_classes: Vec<&str> = seed_words.read().iter()
+        .map(|w| match language.map(|l| mnemonic::word_state(l, w)).unwrap_or(WordState::Empty) {
+            WordState::Valid => "input-cell word-valid",
+            WordState::Partial => "input-cell",
+            WordState::Invalid => "input-cell word-invalid",
+            WordState::Empty if w.trim().is_empty() => "input-cell",
+            WordState::Empty => "input-cell word-invalid",
+        })
+        .collect();
+    let suggestions: Vec<&'static str> = match (language, active_cell()) {
+        (Some(lang), Some(i)) => mnemonic::suggestions(lang, &seed_words.read()[i]),
+        _ => Vec::new(),
+    };
+
+    // Checksum feedback as soon as a complete phrase is on screen
+    let checksum = match mnemonic::resolve_phrase(&seed_words.read()) {
+        Ok(_) => Some(("CHECKSUM_OK", "var(--accent)")),
+        Err(e) if e.ends_with("CHECKSUM_MISMATCH") => Some(("CHECKSUM_INVALID // RECHECK_WORD_ORDER", "var(--status-warn)")),
+        Err(_) => None,
+    };
+
+    let mut apply_completion = move |i: usize, word: &str| {
+        seed_words.with_mut(|words| words[i] = word.to_string());
+        active_cell.set(None);
+    };
+
     rsx! {
         style { {r#"
             .import-step-container {
@@ -47,6 +80,30 @@ pub fn ImportSeedForm(
                 padding: 0.2rem 0.5rem;
             }
 
+            .word-valid { border-color: var(--accent); }
+
+            .word-invalid { border-color: var(--status-warn); }
+
+            .suggest-row {
+                display: flex;
+                flex-wrap: wrap;
+                gap: 0.5rem;
+                margin-top: 1rem;
+                min-height: 1.6rem;
+                align-items: center;
+            }
+
+            .suggest-chip {
+                font-size: 0.7rem;
+                color: var(--text);
+                border: 1px solid var(--border);
+                background: var(--bg-grid);
+                padding: 0.2rem 0.6rem;
+                cursor: pointer;
+            }
+
+            .suggest-chip:hover { border-color: var(--accent); color: var(--accent); }
+
             .cell-index {
                 font-size: 0.6rem;
                 color: var(--text-secondary);
@@ -88,13 +145,13 @@ pub fn ImportSeedForm(
             
             // 1. Header Row
             div { class: "step-header",
-                div { class: "step-title", "WALLET_IMPORT // STEP_01 // MNEMONIC_ENTRY // {network_label} // CTRL V" }
+                div { class: "step-title", "WALLET_IMPORT // STEP_01 // MNEMONIC_ENTRY // {network_label} // LANG: {language_label} // CTRL V" }
             }
 
             // 2. The Grid Container
             div { class: "word-grid",
                 for i in 0..24 {
-                    div { key: "{i}", class: "input-cell",
+                    div { key: "{i}", class: "{cell_classes[i]}",
                         span { class: "cell-index", "{i + 1:02}" }
                         input {
                             class: "cell-input",
@@ -104,8 +161,10 @@ pub fn ImportSeedForm(
                             oninput: move |evt| {
                                 let val = evt.value().replace(['\n', '\r'], " ");
                                 error_msg.set(None);
+                                active_cell.set(Some(i));
                                 
-                                if val.trim().contains(' ') {
+                                // split_whitespace also covers the ideographic space Japanese phrases use
+                                if val.split_whitespace().count() > 1 {
                                     // Handle paste of multiple words
                                     let words: Vec<String> = val.split_whitespace().map(|s| s.to_string()).collect();
                                     let mut current_words = seed_words.peek().clone();
@@ -121,12 +180,37 @@ pub fn ImportSeedForm(
                                     current_words[i] = val.trim().to_string();
                                     seed_words.set(current_words);
                                 }
+                            },
+                            onkeydown: move |evt| {
+                                if evt.key() == Key::Enter {
+                                    let typed = seed_words.peek()[i].clone();
+                                    if let Some(word) = language.and_then(|l| mnemonic::complete(l, &typed)) {
+                                        apply_completion(i, word);
+                                    }
+                                }
                             }
                         }
                     }
                 }
             }
 
+            // Autocomplete candidates for the cell being typed in
+            div { class: "suggest-row",
+                if let Some(i) = active_cell() {
+                    for word in suggestions {
+                        div {
+                            key: "{word}",
+                            class: "suggest-chip",
+                            onclick: move |_| apply_completion(i, word),
+                            "{word}"
+                        }
+                    }
+                }
+                if let Some((label, color)) = checksum {
+                    span { style: "font-size: 0.65rem; letter-spacing: 1px; margin-left: auto; color: {color};", "{label}" }
+                }
+            }
+
             // 3. Error Handling
             if let Some(err) = error_msg() {
                 div { class: "error-banner", ">> {err}" }
@@ -134,6 +218,7 @@ pub fn ImportSeedForm(
 
             // 4. Action Footer
             div { class: "footer-meta",
+                {terminal_action("SLIP39_SHARES", false, move |e| on_slip39.call(e))}
                 {terminal_action("VERIFY_STRUCTURE", true, move |e| on_continue.call(e))}
             }
         }
@@ -1,48 +1,431 @@
 //src/utils/json_storage.rs
 use dirs::config_dir;
-use std::fs::{self, create_dir_all};
-use std::path::PathBuf;
+use std::fs::{self, create_dir_all, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use serde::{Serialize};
 use serde::de::DeserializeOwned;
 use std::io;
+use std::cell::Cell;
+use std::sync::RwLock;
 
-pub fn get_config_path(filename: &str) -> io::Result<PathBuf> {
-    let path = config_dir()
+/// Every file Dannesk keeps in its config directory. Backups bundle exactly this set, so a
+/// module that starts keeping a new file adds it here.
+pub const MANAGED_FILES: &[&str] = &[
+    "xrp.json",
+    "btc.json",
+    "xrp_encrypt.json",
+    "btc_encrypt.json",
+    "master_encrypt.json",
+    "pin.json",
+    "kdf.json",
+    "preferences.json",
+    "issuers.json",
+    "settings.json",
+    "audit.log",
+    "audit_head.json",
+];
+
+/// The subset of MANAGED_FILES kept as plain text rather than JSON, read and restored verbatim.
+pub const TEXT_FILES: &[&str] = &["audit.log"];
+
+/// The subset of MANAGED_FILES that identify a wallet; never overwritten without confirmation.
+pub const WALLET_FILES: &[&str] = &["xrp.json", "btc.json", "xrp_encrypt.json", "btc_encrypt.json", "master_encrypt.json"];
+
+/// Files holding key material or PIN hashes. Written owner-only (0600) on Linux.
+pub const SECRET_FILES: &[&str] = &["xrp_encrypt.json", "btc_encrypt.json", "master_encrypt.json", "pin.json"];
+
+/// Files shared by every profile. Everything else resolves inside the active profile's root.
+pub const ROOT_FILES: &[&str] = &["pin.json", "kdf.json", "preferences.json", "issuers.json"];
+
+/// Which set of wallet files is active. The decoy profile is opened by the duress PIN and
+/// lives in its own subdirectory with its own wallets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Primary,
+    Decoy,
+}
+
+static ACTIVE_PROFILE: RwLock<Profile> = RwLock::new(Profile::Primary);
+
+const DECOY_DIR: &str = "alt";
+
+pub fn set_profile(profile: Profile) {
+    if let Ok(mut active) = ACTIVE_PROFILE.write() {
+        *active = profile;
+    }
+}
+
+thread_local! {
+    // Set by `in_profile` for work that must not follow the active profile
+    static PROFILE_OVERRIDE: Cell<Option<Profile>> = const { Cell::new(None) };
+}
+
+pub fn active_profile() -> Profile {
+    if let Some(profile) = PROFILE_OVERRIDE.with(Cell::get) {
+        return profile;
+    }
+    ACTIVE_PROFILE.read().map(|p| *p).unwrap_or(Profile::Primary)
+}
+
+// Puts the previous override back even if the work panics
+struct OverrideGuard(Option<Profile>);
+
+impl Drop for OverrideGuard {
+    fn drop(&mut self) {
+        PROFILE_OVERRIDE.with(|o| o.set(self.0));
+    }
+}
+
+/// Runs `f` with every path resolving inside `profile`, on this thread only, whichever profile
+/// is active for the rest of the app.
+pub fn in_profile<R>(profile: Profile, f: impl FnOnce() -> R) -> R {
+    let _guard = OverrideGuard(PROFILE_OVERRIDE.with(|o| o.replace(Some(profile))));
+    f()
+}
+
+#[cfg(test)]
+thread_local! {
+    static TEST_ROOT: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Points this test thread's config directory at a fresh, empty directory and returns it.
+#[cfg(test)]
+pub(crate) fn use_test_root() -> PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let root = std::env::temp_dir().join(format!("dannesk-test-{}-{}", std::process::id(), NEXT.fetch_add(1, Ordering::Relaxed)));
+    let _ = fs::remove_dir_all(&root);
+    TEST_ROOT.with(|r| *r.borrow_mut() = Some(root.clone()));
+    root
+}
+
+fn config_root() -> io::Result<PathBuf> {
+    #[cfg(test)]
+    if let Some(root) = TEST_ROOT.with(|r| r.borrow().clone()) {
+        return Ok(root);
+    }
+    Ok(config_dir()
         .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not determine config directory"))?
-        .join("Dannesk")
-        .join(filename);
+        .join("Dannesk"))
+}
+
+pub fn get_config_path(filename: &str) -> io::Result<PathBuf> {
+    let root = config_root()?;
+    let path = match active_profile() {
+        Profile::Decoy if !ROOT_FILES.contains(&filename) => root.join(DECOY_DIR).join(filename),
+        _ => root.join(filename),
+    };
     if let Some(parent) = path.parent() {
         create_dir_all(parent)?;
+        restrict_permissions(parent, 0o700)?;
     }
     Ok(path)
 }
 
+// Sibling paths used by write_json: the in-flight temp file and the previous generation.
+fn tmp_path(path: &Path) -> PathBuf {
+    path.with_extension("json.tmp")
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension("json.bak")
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn restrict_permissions(path: &Path, mode: u32) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn restrict_permissions(_path: &Path, _mode: u32) -> io::Result<()> {
+    Ok(())
+}
+
+/// Creates `path`, which must not exist yet, with `mode` applied from the moment it exists
+/// rather than after it has been opened.
+#[cfg(unix)]
+pub(crate) fn create_new_file(path: &Path, mode: u32) -> io::Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+    OpenOptions::new().write(true).create_new(true).mode(mode).open(path)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn create_new_file(path: &Path, _mode: u32) -> io::Result<File> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+// A temp file left by an interrupted write is dropped, so the new one starts out private
+fn create_tmp(tmp: &Path, secret: bool) -> io::Result<File> {
+    match fs::remove_file(tmp) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    create_new_file(tmp, if secret { 0o600 } else { 0o666 })
+}
+
+// Flushes the rename itself to disk; without this a crash can still lose the new directory entry.
+#[cfg(unix)]
+fn sync_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) => File::open(dir)?.sync_all(),
+        None => Ok(()),
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+// Keeps the current generation as `.bak`, only if it is intact, so a corrupt primary never
+// overwrites the last good backup.
+fn roll_backup(path: &Path, secret: bool) -> io::Result<()> {
+    if let Ok(current) = fs::read_to_string(path) {
+        if serde_json::from_str::<serde_json::Value>(&current).is_ok() {
+            let bak = backup_path(path);
+            fs::copy(path, &bak)?;
+            if secret {
+                restrict_permissions(&bak, 0o600)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Crash-safe write: the data goes to a temp file which is fsynced and then renamed over the
+/// target, so readers only ever see the old or the new contents. The previous contents, if they
+/// still parse, are kept as `<file>.bak` for read_json to fall back on.
 pub fn write_json<T: Serialize>(filename: &str, data: &T) -> io::Result<()> {
     let path = get_config_path(filename)?;
+    let tmp = tmp_path(&path);
     let json = serde_json::to_string(data)?;
-    fs::write(path, json)?;
-    Ok(())
+    let secret = SECRET_FILES.contains(&filename);
+
+    let result = (|| -> io::Result<()> {
+        let mut file = create_tmp(&tmp, secret)?;
+        file.write_all(json.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+
+        roll_backup(&path, secret)?;
+        fs::rename(&tmp, &path)?;
+        sync_dir(&path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
 }
 
+fn parse_file(path: &Path) -> io::Result<serde_json::Value> {
+    let content = fs::read_to_string(path)?;
+    let value = serde_json::from_str(&content)?;
+    Ok(value)
+}
+
+/// Reads `filename`, falling back to its `.bak` generation if the primary is truncated or
+/// otherwise not valid JSON. A good backup is copied back over the broken primary.
 pub fn read_json<T: DeserializeOwned>(filename: &str) -> io::Result<T> {
     let path = get_config_path(filename)?;
-    let content = fs::read_to_string(path)?;
-    let data = serde_json::from_str(&content)?;
-    Ok(data)
+    let primary_err = match parse_file(&path) {
+        Ok(value) => return Ok(serde_json::from_value(value)?),
+        Err(e) => e,
+    };
+
+    // A missing file is a normal "not set yet" state, not corruption.
+    if primary_err.kind() == io::ErrorKind::NotFound {
+        return Err(primary_err);
+    }
+
+    let bak = backup_path(&path);
+    match parse_file(&bak) {
+        Ok(value) => {
+            let data: T = serde_json::from_value(value)?;
+            fs::copy(&bak, &path)?;
+            if SECRET_FILES.contains(&filename) {
+                restrict_permissions(&path, 0o600)?;
+            }
+            Ok(data)
+        }
+        Err(_) => Err(primary_err),
+    }
 }
 
 pub fn update_json<T: Serialize + DeserializeOwned + std::default::Default>(
     filename: &str,
     update_fn: impl FnOnce(&mut T),
 ) -> io::Result<()> {
-    let mut data = read_json(filename).or_else(|_| Ok::<T, std::io::Error>(T::default()))?;
+    // Only a missing file starts from defaults; an unreadable one must not be overwritten blindly.
+    let mut data = match read_json(filename) {
+        Ok(data) => data,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => T::default(),
+        Err(e) => return Err(e),
+    };
     update_fn(&mut data);
     write_json(filename, &data)?;
     Ok(())
 }
 
+/// Reads one of TEXT_FILES as it is on disk.
+pub fn read_text(filename: &str) -> io::Result<String> {
+    fs::read_to_string(get_config_path(filename)?)
+}
+
+/// Replaces one of TEXT_FILES the way write_json does, owner-only and without a `.bak`.
+pub fn write_text(filename: &str, content: &str) -> io::Result<()> {
+    let path = get_config_path(filename)?;
+    let tmp = path.with_extension("tmp");
+
+    let result = (|| -> io::Result<()> {
+        let mut file = create_tmp(&tmp, true)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&tmp, &path)?;
+        sync_dir(&path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+fn staged_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".staged");
+    PathBuf::from(name)
+}
+
+/// Writes `content` as the next version of `filename` beside it, fsynced, without touching the
+/// live file. `install_staged` moves it into place; `discard_staged` drops it. Lets a caller
+/// prepare a set of files and replace them only once every one was written.
+pub fn stage(filename: &str, content: &str) -> io::Result<()> {
+    let staged = staged_path(&get_config_path(filename)?);
+    let result = create_tmp(&staged, SECRET_FILES.contains(&filename) || TEXT_FILES.contains(&filename))
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&staged);
+    }
+    result
+}
+
+/// Replaces `filename` with its staged version, keeping the previous JSON generation as `.bak`.
+pub fn install_staged(filename: &str) -> io::Result<()> {
+    let path = get_config_path(filename)?;
+    if !TEXT_FILES.contains(&filename) {
+        roll_backup(&path, SECRET_FILES.contains(&filename))?;
+    }
+    fs::rename(staged_path(&path), &path)?;
+    sync_dir(&path)
+}
+
+pub fn discard_staged(filename: &str) {
+    if let Ok(path) = get_config_path(filename) {
+        let _ = fs::remove_file(staged_path(&path));
+    }
+}
+
+/// Drops the `.bak` generation of `filename`, e.g. once it holds a key under a retired passphrase.
+pub fn discard_backup(filename: &str) -> io::Result<()> {
+    let bak = backup_path(&get_config_path(filename)?);
+    if bak.exists() {
+        fs::remove_file(bak)?;
+    }
+    Ok(())
+}
+
+/// Removes `filename` together with its backup generation, so purged keys leave nothing behind.
 pub fn remove_json(filename: &str) -> io::Result<()> {
     let path = get_config_path(filename)?;
+    let bak = backup_path(&path);
+    if bak.exists() {
+        fs::remove_file(bak)?;
+    }
     fs::remove_file(path)?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn corrupt_primary_falls_back_to_backup() {
+        use_test_root();
+        write_json("xrp.json", &json!({ "address": "first" })).unwrap();
+        write_json("xrp.json", &json!({ "address": "second" })).unwrap();
+        let path = get_config_path("xrp.json").unwrap();
+        fs::write(&path, "{\"address\": \"sec").unwrap();
+
+        let recovered: Value = read_json("xrp.json").unwrap();
+        assert_eq!(recovered["address"], "first");
+        // The good generation is copied back over the broken primary
+        assert_eq!(parse_file(&path).unwrap(), recovered);
+    }
+
+    #[test]
+    fn update_refuses_to_overwrite_unreadable_file() {
+        use_test_root();
+        let path = get_config_path("btc.json").unwrap();
+        fs::write(&path, "not json").unwrap();
+
+        let result = update_json("btc.json", |data: &mut Value| *data = json!({ "address": "new" }));
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "not json");
+    }
+
+    #[test]
+    fn missing_file_updates_from_default() {
+        use_test_root();
+        update_json("btc.json", |data: &mut Value| *data = json!({ "address": "new" })).unwrap();
+        assert_eq!(read_json::<Value>("btc.json").unwrap()["address"], "new");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn secret_files_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        use_test_root();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        for name in SECRET_FILES {
+            write_json(name, &json!({ "generation": 1 })).unwrap();
+            write_json(name, &json!({ "generation": 2 })).unwrap();
+            let path = get_config_path(name).unwrap();
+            assert_eq!(mode(&path), 0o600, "{}", name);
+            assert_eq!(mode(&backup_path(&path)), 0o600, "{} backup", name);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stale_temp_file_is_replaced_not_reused() {
+        use std::os::unix::fs::PermissionsExt;
+        use_test_root();
+        let path = get_config_path("pin.json").unwrap();
+        fs::write(tmp_path(&path), "left over").unwrap();
+        fs::set_permissions(tmp_path(&path), fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_json("pin.json", &json!({ "generation": 1 })).unwrap();
+        assert!(!tmp_path(&path).exists());
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    #[test]
+    fn decoy_profile_keeps_its_own_files() {
+        let root = use_test_root();
+        in_profile(Profile::Decoy, || {
+            assert_eq!(get_config_path("xrp.json").unwrap(), root.join(DECOY_DIR).join("xrp.json"));
+            assert_eq!(get_config_path("pin.json").unwrap(), root.join("pin.json"));
+            in_profile(Profile::Primary, || assert_eq!(get_config_path("xrp.json").unwrap(), root.join("xrp.json")));
+            assert_eq!(active_profile(), Profile::Decoy);
+        });
+    }
+}
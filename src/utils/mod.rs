@@ -8,9 +8,15 @@ pub mod send_amount_layout;
 pub mod send_recipient_layout;
 pub mod send_review_layout;
 pub mod send_auth_layout;
+pub mod signing_session_layout;
 pub mod import_seed_layout;
 pub mod wallet_security_layout;
+pub mod address_type_layout;
 pub mod create_seed_layout;
+pub mod seed_entropy_layout;
+pub mod seed_quiz_layout;
+pub mod slip39_layout;
+pub mod qr_layout;
 pub mod receive_layout;
 pub mod balance_layout;
 pub mod market_order_form;
@@ -0,0 +1,75 @@
+// src/utils/qr_layout.rs
+use dioxus_native::prelude::*;
+use qrcode::{QrCode, types::Color};
+
+// Dark modules of the QR code for `data`, or None when it does not fit in one code
+fn qr_modules(data: &str) -> Option<(u32, Vec<(u32, u32)>)> {
+    let code = QrCode::new(data.as_bytes()).ok()?;
+    let width = code.width() as u32;
+    let mut dark = Vec::with_capacity((width * width) as usize);
+    for y in 0..width {
+        for x in 0..width {
+            if code[(x as usize, y as usize)] == Color::Dark {
+                dark.push((x, y));
+            }
+        }
+    }
+    Some((width, dark))
+}
+
+/// Vector QR code (Vello/Blitz renders instantly). Data too long for a single code shows a
+/// notice in its place.
+#[component]
+pub fn QrCodeSvg(data: String, is_dark: bool) -> Element {
+    let modules = use_memo(use_reactive(&data, |data| qr_modules(&data)));
+
+    let module_color = if is_dark { "#ffffff" } else { "#000000" };
+    let bg_color = if is_dark { "#111111" } else { "#ffffff" };
+
+    let Some((grid_size, dark_modules)) = modules.read().clone() else {
+        return rsx! {
+            div {
+                style: "width: 280px; margin-bottom: 2rem; padding: 2rem 0; border: 1px dashed var(--border); text-align: center; color: var(--status-warn); font-size: 0.65rem; letter-spacing: 1px;",
+                "TOO_LARGE_FOR_QR // USE_FILE"
+            }
+        };
+    };
+
+    rsx! {
+        style { {r#"
+            .qr-img {
+                width: 280px !important;      /* big & reliable */
+                height: 280px !important;
+                margin-bottom: 2rem;
+                image-rendering: crisp-edges;
+                display: block;
+                flex-shrink: 0;
+            }
+        "#} }
+
+        svg {
+            class: "qr-img",
+            view_box: "-4 -4 {grid_size + 8} {grid_size + 8}",
+            preserve_aspect_ratio: "xMidYMid meet",
+
+            // Quiet zone (4 modules padding, looks professional)
+            rect {
+                x: "-4",
+                y: "-4",
+                width: "{grid_size + 8}",
+                height: "{grid_size + 8}",
+                fill: "{bg_color}",
+            }
+
+            for (x, y) in dark_modules {
+                rect {
+                    x: "{x}",
+                    y: "{y}",
+                    width: "1",
+                    height: "1",
+                    fill: "{module_color}",
+                }
+            }
+        }
+    }
+}
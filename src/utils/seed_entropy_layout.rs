@@ -0,0 +1,105 @@
+//src/utils/seed_entropy_layout.rs
+use dioxus_native::prelude::*;
+use crate::entropy::{entropy_len, EntropySource, MixReport, WORD_COUNTS};
+use crate::utils::styles::terminal_action;
+
+#[component]
+pub fn SeedEntropyPanel(
+    word_count: usize,
+    on_word_count: EventHandler<usize>,
+    mut source: Signal<EntropySource>,
+    mut rolls: Signal<String>,
+    report: Option<MixReport>,
+    current_error: Option<String>,
+    on_mix: EventHandler<MouseEvent>,
+) -> Element {
+    let mut show_mix = use_signal(|| false);
+
+    let entered = source().normalise(&rolls()).map(|r| r.len()).unwrap_or(0);
+    let recommended = source().recommended_rolls(word_count);
+    let entropy_bytes = entropy_len(word_count);
+    let placeholder = source().alphabet();
+    let report_lines: Vec<String> = report
+        .map(|r| vec![
+            format!("ROLLS   {}", r.rolls.as_str()),
+            format!("RNG     {}", r.rng_hex.as_str()),
+            format!("SHA256  {}", r.digest_hex.as_str()),
+            format!("ENTROPY {}", r.entropy_hex.as_str()),
+        ])
+        .unwrap_or_default();
+    let count_color = if entered >= recommended { "var(--accent)" } else { "var(--status-warn)" };
+
+    rsx! {
+        style { {r#"
+            .entropy-panel { margin-top: 1.5rem; display: flex; flex-direction: column; gap: 0.75rem; }
+            .entropy-row { display: flex; align-items: center; gap: 1rem; flex-wrap: wrap; }
+            .entropy-label { font-size: 0.65rem; color: var(--text-secondary); letter-spacing: 1px; min-width: 6rem; }
+            .entropy-box { border: 1px dashed var(--border); padding: 1rem; display: flex; flex-direction: column; gap: 0.75rem; }
+            .entropy-input {
+                background: var(--input-bg);
+                border: 1px solid var(--border);
+                outline: none;
+                color: var(--text);
+                font-family: inherit;
+                font-size: 0.8rem;
+                padding: 0.5rem 0.75rem;
+            }
+            .entropy-hint { font-size: 0.6rem; color: var(--text-secondary); line-height: 1.5; }
+            .entropy-formula { font-size: 0.65rem; color: var(--accent); letter-spacing: 1px; }
+            .entropy-value { font-size: 0.6rem; color: var(--text); word-break: break-all; line-height: 1.5; }
+            .entropy-error { font-size: 0.65rem; color: var(--status-warn); }
+        "#} }
+
+        div { class: "entropy-panel",
+            div { class: "entropy-row",
+                span { class: "entropy-label", "MNEMONIC_LENGTH" }
+                for count in WORD_COUNTS {
+                    {terminal_action(&format!("{}_WORDS", count), count == word_count, move |_| on_word_count.call(count))}
+                }
+                {terminal_action("MIX_USER_ENTROPY", show_mix(), move |_| {
+                    let next = !show_mix();
+                    show_mix.set(next);
+                })}
+            }
+
+            if show_mix() {
+                div { class: "entropy-box",
+                    div { class: "entropy-row",
+                        span { class: "entropy-label", "SOURCE" }
+                        for option in [EntropySource::Dice, EntropySource::Coin] {
+                            {terminal_action(option.label(), source() == option, move |_| source.set(option))}
+                        }
+                    }
+                    input {
+                        class: "entropy-input",
+                        spellcheck: false,
+                        autocomplete: "off",
+                        placeholder: "{placeholder}",
+                        value: "{rolls}",
+                        oninput: move |e| rolls.set(e.value()),
+                    }
+                    div { class: "entropy-hint",
+                        span { style: "color: {count_color};", "{entered} / {recommended} " }
+                        "values for full {word_count}-word strength from your input alone. Fewer still mixes safely with the OS RNG."
+                    }
+                    div { class: "entropy-formula", "ENTROPY = RNG XOR SHA256(ROLLS)[0..{entropy_bytes}]" }
+                    div { class: "entropy-hint",
+                        "ROLLS is your input with separators removed (coins as 1=H, 0=T), hashed as ASCII. Check it with any SHA-256 tool."
+                    }
+
+                    for line in report_lines {
+                        div { class: "entropy-value", "{line}" }
+                    }
+
+                    if let Some(err) = current_error {
+                        div { class: "entropy-error", ">> {err}" }
+                    }
+
+                    div { class: "entropy-row",
+                        {terminal_action("MIX_AND_REGENERATE", true, move |e| on_mix.call(e))}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use dioxus_native::prelude::*;
+use rand::{rng, RngExt};
+use rand::seq::index::sample;
+use crate::utils::styles::terminal_action;
+
+/// How many word positions the user must recall before the wallet is saved.
+pub const QUIZ_WORDS: usize = 4;
+
+/// A word the user is asked for: (backup index, word index). The backup index is always 0 for a
+/// plain mnemonic and the share number when the wallet was split into SLIP-39 shares.
+pub type QuizSlot = (usize, usize);
+
+/// Splits the backup the user wrote down into its word lists, one per sheet.
+pub fn backup_words(seed: &str, shares: Option<&[String]>) -> Vec<Vec<String>> {
+    let split = |s: &str| s.split_whitespace().map(|w| w.to_string()).collect::<Vec<String>>();
+    match shares {
+        Some(shares) if !shares.is_empty() => shares.iter().map(|s| split(s)).collect(),
+        _ => vec![split(seed)],
+    }
+}
+
+/// Picks `QUIZ_WORDS` distinct positions of a single mnemonic, in ascending order. With several
+/// shares one word of every share is asked instead, so each written share gets checked.
+pub fn pick_positions(backup: &[Vec<String>]) -> Vec<QuizSlot> {
+    if let [words] = backup {
+        let mut positions = sample(&mut rng(), words.len(), QUIZ_WORDS.min(words.len())).into_vec();
+        positions.sort_unstable();
+        return positions.into_iter().map(|w| (0, w)).collect();
+    }
+    backup.iter().enumerate()
+        .filter(|(_, words)| !words.is_empty())
+        .map(|(s, words)| (s, rng().random_range(0..words.len())))
+        .collect()
+}
+
+/// Field label for a quiz slot.
+pub fn slot_label(backup: &[Vec<String>], (share, word): QuizSlot) -> String {
+    if backup.len() > 1 {
+        format!("S{:02}_W{:02}", share + 1, word + 1)
+    } else {
+        format!("WORD_{:02}", word + 1)
+    }
+}
+
+/// True if every answer matches the backup word at its slot.
+pub fn answers_match(backup: &[Vec<String>], slots: &[QuizSlot], answers: &[String]) -> bool {
+    slots.len() == answers.len()
+        && slots.iter().zip(answers).all(|(&(share, word), answer)| {
+            backup.get(share)
+                .and_then(|words| words.get(word))
+                .map(|w| w.eq_ignore_ascii_case(answer.trim()))
+                .unwrap_or(false)
+        })
+}
+
+#[component]
+pub fn SeedQuizForm(
+    network_label: String,
+    labels: Vec<String>,
+    answers: Signal<Vec<String>>,
+    current_error: Option<String>,
+    on_verify: EventHandler<MouseEvent>,
+) -> Element {
+    rsx! {
+        style { {r#"
+            .quiz-step-container {
+                display: flex;
+                flex-direction: column;
+                width: 100%;
+                max-width: 800px;
+                margin: 0 auto;
+                font-family: 'JetBrains Mono', monospace;
+                padding: 2rem;
+            }
+
+            .step-header {
+                border-bottom: 1px solid var(--border);
+                padding-bottom: 1rem;
+                margin-bottom: 1.5rem;
+            }
+
+            .step-title {
+                font-size: 0.7rem;
+                color: var(--text-secondary);
+                letter-spacing: 2px;
+            }
+
+            .quiz-hint {
+                font-size: 0.7rem;
+                color: var(--text-secondary);
+                margin-bottom: 1.5rem;
+                line-height: 1.5;
+            }
+
+            .quiz-grid {
+                display: grid;
+                grid-template-columns: repeat(2, 1fr);
+                gap: 0.75rem;
+            }
+
+            .quiz-cell {
+                display: flex;
+                align-items: center;
+                background: var(--input-bg);
+                border: 1px solid var(--border);
+                padding: 0.5rem 0.75rem;
+            }
+
+            .quiz-cell:focus-within { border-color: var(--accent); }
+
+            .cell-index {
+                font-size: 0.6rem;
+                color: var(--accent);
+                font-weight: bold;
+                width: 3.5rem;
+            }
+
+            .quiz-input {
+                flex: 1; background: transparent; border: none; outline: none;
+                color: var(--text); font-family: inherit; font-size: 0.85rem;
+            }
+
+            .error-box { background: rgba(239, 68, 68, 0.1); border-left: 3px solid var(--status-warn); padding: 0.75rem 1rem; margin-top: 1rem; font-size: 0.75rem; color: var(--status-warn); }
+
+            .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-start; align-items: center; gap: 2rem; }
+        "#} }
+
+        div { class: "quiz-step-container",
+            div { class: "step-header",
+                div { class: "step-title", "WALLET_CREATION // STEP_02 // VERIFY_BACKUP // {network_label}" }
+            }
+
+            div { class: "quiz-hint",
+                "Enter the requested words from your written backup. The wallet is only saved once they match."
+            }
+
+            div { class: "quiz-grid",
+                for (i, label) in labels.iter().enumerate() {
+                    div { key: "{label}", class: "quiz-cell",
+                        span { class: "cell-index", "{label}" }
+                        input {
+                            class: "quiz-input",
+                            autocomplete: "off",
+                            value: "{answers.read().get(i).cloned().unwrap_or_default()}",
+                            oninput: move |e| {
+                                let val = e.value();
+                                answers.with_mut(|a| if let Some(slot) = a.get_mut(i) { *slot = val; });
+                            },
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = current_error {
+                div { class: "error-box", "SIGNAL_INTERRUPT: {err}" }
+            }
+
+            div { class: "footer-nav",
+                {terminal_action("VERIFY", true, move |e| on_verify.call(e))}
+            }
+        }
+    }
+}
@@ -1,11 +1,17 @@
 use dioxus_native::prelude::*;
 use crate::utils::styles::terminal_action;
+use crate::utils::signing_session_layout::{use_open_session, SessionNotice, SessionPicker};
+use crate::clipboard::AddressChain;
+use crate::context::GlobalContext;
+use crate::signer::session::SessionLimits;
 
 #[component]
 pub fn SendAuthForm(
     step_title: String, // Added parameter
     network_label: String,
+    chain: AddressChain,
     input_mode: Signal<String>,
+    session_limits: Signal<Option<SessionLimits>>,
     passphrase_val: Signal<String>,
     seed_words: Signal<Vec<String>>,
     bip39_val: Signal<String>,
@@ -13,6 +19,17 @@ pub fn SendAuthForm(
     on_submit: EventHandler<MouseEvent>,
     on_clear_error: EventHandler<()>,
 ) -> Element {
+    let open_session = use_open_session(chain);
+    let global = use_context::<GlobalContext>();
+
+    // The session can expire while this form is open; fall back to the passphrase
+    use_effect(move || {
+        let still_open = global.signing_sessions.read().iter().any(|s| s.chain == chain);
+        if input_mode() == "session" && !still_open {
+            input_mode.set("passphrase".to_string());
+        }
+    });
+
     rsx! {
         style { {r#"
             .send-step-container { display: flex; flex-direction: column; width: 100%; max-width: 800px; margin: 0 auto; font-family: 'JetBrains Mono', monospace; padding: 2rem; }
@@ -43,6 +60,16 @@ pub fn SendAuthForm(
             }
 
             div { class: "auth-tabs",
+                if open_session.is_some() {
+                    button {
+                        class: if input_mode() == "session" { "auth-tab auth-tab-active" } else { "auth-tab" },
+                        onclick: move |_| {
+                            input_mode.set("session".to_string());
+                            on_clear_error.call(());
+                        },
+                        "SIGNING_SESSION"
+                    }
+                }
                 button { 
                     class: if input_mode() == "passphrase" { "auth-tab auth-tab-active" } else { "auth-tab" },
                     onclick: move |_| {
@@ -61,7 +88,11 @@ pub fn SendAuthForm(
                 }
             }
 
-            if input_mode() == "passphrase" {
+            if input_mode() == "session" {
+                if let Some(session) = open_session {
+                    SessionNotice { session }
+                }
+            } else if input_mode() == "passphrase" {
                 div { class: "input-section",
                     div { class: "input-label-row", div { class: "input-label", "ENCRYPTION_KEY" } }
                     div { class: "terminal-input-wrapper",
@@ -111,20 +142,24 @@ pub fn SendAuthForm(
                 }
             }
 
-            div { class: "input-section",
-                div { class: "input-label-row", 
-                    div { class: "input-label", "BIP39_PASSPHRASE" } 
-                    div { class: "input-hint", "[OPTIONAL]" }
-                }
-                div { class: "terminal-input-wrapper",
-                    span { class: "bracket", "[" }
-                    input {
-                        class: "inner-input",
-                        value: "{bip39_val()}",
-                        oninput: move |e| bip39_val.set(e.value())
+            if input_mode() != "session" {
+                div { class: "input-section",
+                    div { class: "input-label-row", 
+                        div { class: "input-label", "BIP39_PASSPHRASE" } 
+                        div { class: "input-hint", "[OPTIONAL]" }
+                    }
+                    div { class: "terminal-input-wrapper",
+                        span { class: "bracket", "[" }
+                        input {
+                            class: "inner-input",
+                            value: "{bip39_val()}",
+                            oninput: move |e| bip39_val.set(e.value())
+                        }
+                        span { class: "bracket", "]" }
                     }
-                    span { class: "bracket", "]" }
                 }
+
+                SessionPicker { session_limits }
             }
 
             if let Some(err) = current_error {
@@ -7,6 +7,7 @@ pub fn SendAddressForm(
     address_buffer: Signal<String>,
     placeholder: String,
     current_error: Option<String>,
+    paste_warning: Option<String>,
     on_input: EventHandler<FormEvent>,
     on_next_click: EventHandler<MouseEvent>,
 ) -> Element {
@@ -22,6 +23,8 @@ pub fn SendAddressForm(
             .bracket { color: var(--text-secondary); opacity: 0.4; font-weight: bold; }
             .inner-input { flex: 1; background: transparent; border: none; outline: none; color: var(--text); font-family: inherit; font-size: 1rem; padding: 0 1rem; }
             .error-box { background: rgba(239, 68, 68, 0.1); border-left: 3px solid var(--status-warn); padding: 0.75rem 1rem; margin-top: 1rem; font-size: 0.75rem; color: var(--status-warn); }
+            .hijack-box { border: 2px solid var(--status-warn); background: rgba(239, 68, 68, 0.15); padding: 1rem; margin-top: 1rem; font-size: 0.75rem; color: var(--status-warn); line-height: 1.5; word-break: break-all; }
+            .hijack-title { font-weight: bold; letter-spacing: 2px; margin-bottom: 0.5rem; }
             .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-end; align-items: center; gap: 2rem; }
         "#} }
 
@@ -48,10 +51,17 @@ pub fn SendAddressForm(
                 if let Some(err) = current_error {
                     div { class: "error-box", "SIGNAL_INTERRUPT: {err}" }
                 }
+
+                if let Some(warning) = paste_warning.clone() {
+                    div { class: "hijack-box",
+                        div { class: "hijack-title", "!! POSSIBLE_CLIPBOARD_HIJACK !!" }
+                        "{warning}"
+                    }
+                }
             }
 
             div { class: "footer-nav",
-                {terminal_action("CONTINUE", true, move |e| on_next_click.call(e))}
+                {terminal_action(if paste_warning.is_some() { "CONTINUE_ANYWAY" } else { "CONTINUE" }, true, move |e| on_next_click.call(e))}
             }
         }
     }
@@ -0,0 +1,103 @@
+// src/utils/signing_session_layout.rs
+// Signing session pieces shared by SendAuthForm, render_token_enable and the top bar.
+
+use std::time::{Duration, Instant};
+use dioxus_native::prelude::*;
+use crate::channel::SigningSession;
+use crate::clipboard::AddressChain;
+use crate::context::GlobalContext;
+use crate::signer::session::{SessionLimits, MINUTE_CHOICES, TRANSACTION_CHOICES};
+use crate::utils::styles::terminal_action;
+
+/// The open session for `chain`, if any.
+pub fn use_open_session(chain: AddressChain) -> Option<SigningSession> {
+    let global = use_context::<GlobalContext>();
+    let session = global.signing_sessions.read().iter().find(|s| s.chain == chain).copied();
+    session
+}
+
+fn countdown(expires: Instant) -> String {
+    let left = expires.saturating_duration_since(Instant::now()).as_secs();
+    format!("{:02}:{:02}", left / 60, left % 60)
+}
+
+fn budget(remaining: Option<u32>) -> String {
+    match remaining {
+        Some(n) => format!("{} TX_LEFT", n),
+        None => "NO_TX_LIMIT".to_string(),
+    }
+}
+
+/// "XRP 12:34 // 3 TX_LEFT", re-rendered every second.
+#[component]
+pub fn SessionCountdown(session: SigningSession) -> Element {
+    let mut tick = use_signal(|| 0u64);
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            tick += 1;
+        }
+    });
+    let _ = tick();
+
+    let text = format!("{} {} // {}", session.chain.label(), countdown(session.expires), budget(session.remaining));
+    rsx! { span { "{text}" } }
+}
+
+/// Shown in place of the credential inputs while signing from an open session.
+#[component]
+pub fn SessionNotice(session: SigningSession) -> Element {
+    rsx! {
+        div { class: "input-section",
+            div { class: "input-label-row",
+                div { class: "input-label", "SIGNING_SESSION_OPEN" }
+                div { class: "input-hint", "NO_PASSPHRASE_REQUIRED" }
+            }
+            div { class: "terminal-input-wrapper", style: "font-size: 0.8rem; color: var(--text);",
+                SessionCountdown { session }
+            }
+        }
+    }
+}
+
+/// Opt-in choice to keep the key unlocked after this signature.
+#[component]
+pub fn SessionPicker(session_limits: Signal<Option<SessionLimits>>) -> Element {
+    let current = session_limits();
+
+    let minute_buttons: Vec<Element> = MINUTE_CHOICES.iter().map(|&minutes| {
+        let active = current.map(|l| l.minutes) == Some(minutes);
+        terminal_action(&format!("{}_MIN", minutes), active, move |_| {
+            let transactions = session_limits.peek().and_then(|l| l.transactions);
+            session_limits.set(Some(SessionLimits { minutes, transactions }));
+        })
+    }).collect();
+
+    let transaction_buttons: Vec<Element> = match current {
+        Some(limits) => TRANSACTION_CHOICES.iter().map(|&transactions| {
+            let label = transactions.map(|n| format!("{}_TX", n)).unwrap_or_else(|| "NO_LIMIT".to_string());
+            terminal_action(&label, limits.transactions == transactions, move |_| {
+                session_limits.set(Some(SessionLimits { minutes: limits.minutes, transactions }));
+            })
+        }).collect(),
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "input-section",
+            div { class: "input-label-row",
+                div { class: "input-label", "KEEP_UNLOCKED" }
+                div { class: "input-hint", "[OPTIONAL] // ENDS_ON_LOCK_OR_EXIT" }
+            }
+            div { style: "display: flex; flex-wrap: wrap; gap: 0.25rem;",
+                {terminal_action("OFF", current.is_none(), move |_| session_limits.set(None))}
+                {minute_buttons.into_iter()}
+            }
+            if !transaction_buttons.is_empty() {
+                div { style: "display: flex; flex-wrap: wrap; gap: 0.25rem; margin-top: 0.25rem;",
+                    {transaction_buttons.into_iter()}
+                }
+            }
+        }
+    }
+}
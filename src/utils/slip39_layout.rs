@@ -0,0 +1,178 @@
+//src/utils/slip39_layout.rs
+use dioxus_native::prelude::*;
+use crate::slip39;
+use crate::utils::styles::terminal_action;
+
+/// Short label for a share, e.g. "GROUP_2 // SHARE_1 // 2_NEEDED".
+pub fn share_label(mnemonic: &str) -> String {
+    match slip39::decode_share(mnemonic) {
+        Ok(share) => format!(
+            "GROUP_{} // SHARE_{} // {}_NEEDED",
+            share.group_index + 1, share.member_index + 1, share.member_threshold
+        ),
+        Err(_) => "INVALID_SHARE".to_string(),
+    }
+}
+
+const SHARED_STYLE: &str = r#"
+    .slip-step-container {
+        display: flex;
+        flex-direction: column;
+        width: 100%;
+        max-width: 800px;
+        margin: 0 auto;
+        font-family: 'JetBrains Mono', monospace;
+        padding: 2rem;
+    }
+    .step-header { border-bottom: 1px solid var(--border); padding-bottom: 1rem; margin-bottom: 1.5rem; }
+    .step-title { font-size: 0.7rem; color: var(--text-secondary); letter-spacing: 2px; }
+    .config-row { display: flex; gap: 1rem; margin-bottom: 1rem; }
+    .config-field { display: flex; flex-direction: column; gap: 0.4rem; }
+    .input-label { font-size: 0.65rem; color: var(--accent); border-left: 2px solid var(--accent); padding-left: 8px; }
+    .terminal-input-wrapper { display: flex; align-items: center; background: var(--input-bg); border: 1px solid var(--border); padding: 0.5rem 0.75rem; }
+    .inner-input { flex: 1; background: transparent; border: none; outline: none; color: var(--text); font-family: inherit; font-size: 0.8rem; }
+    .config-hint { font-size: 0.6rem; color: var(--text-secondary); opacity: 0.6; margin-bottom: 1rem; }
+    .share-list { display: flex; flex-direction: column; gap: 0.75rem; max-height: 50vh; overflow-y: auto; }
+    .share-card { border: 1px solid var(--border); background: var(--bg-grid); padding: 0.75rem; }
+    .share-label { font-size: 0.6rem; color: var(--accent); letter-spacing: 1px; margin-bottom: 0.4rem; }
+    .share-words { font-size: 0.75rem; color: var(--text); line-height: 1.6; word-spacing: 0.3rem; }
+    .error-banner {
+        background: rgba(var(--status-warn-rgb), 0.1);
+        color: var(--status-warn);
+        border-left: 3px solid var(--status-warn);
+        padding: 0.75rem 1rem;
+        margin-top: 1rem;
+        font-size: 0.7rem;
+        letter-spacing: 1px;
+    }
+    .footer-nav { margin-top: 2rem; display: flex; justify-content: flex-start; align-items: center; gap: 1rem; }
+"#;
+
+#[component]
+pub fn Slip39SplitForm(
+    network_label: String,
+    group_threshold: Signal<String>,
+    group_spec: Signal<String>,
+    shares: Vec<String>,
+    current_error: Option<String>,
+    on_generate: EventHandler<MouseEvent>,
+    on_use_bip39: EventHandler<MouseEvent>,
+    on_continue: EventHandler<MouseEvent>,
+) -> Element {
+    let has_shares = !shares.is_empty();
+
+    rsx! {
+        style { {SHARED_STYLE} }
+
+        div { class: "slip-step-container",
+            div { class: "step-header",
+                div { class: "step-title", "WALLET_CREATION // STEP_01 // SLIP39_SHARES // {network_label}" }
+            }
+
+            div { class: "config-row",
+                div { class: "config-field",
+                    div { class: "input-label", "GROUPS_REQUIRED" }
+                    div { class: "terminal-input-wrapper",
+                        input {
+                            class: "inner-input",
+                            value: "{group_threshold}",
+                            oninput: move |e| group_threshold.set(e.value()),
+                        }
+                    }
+                }
+                div { class: "config-field", style: "flex: 1;",
+                    div { class: "input-label", "GROUPS // THRESHOLD/COUNT" }
+                    div { class: "terminal-input-wrapper",
+                        input {
+                            class: "inner-input",
+                            value: "{group_spec}",
+                            placeholder: "1/1, 2/3",
+                            oninput: move |e| group_spec.set(e.value()),
+                        }
+                    }
+                }
+            }
+            div { class: "config-hint",
+                "e.g. GROUPS_REQUIRED 1 with \"2/3\" = any 2 of 3 custodians. Each share must be written down separately."
+            }
+
+            if has_shares {
+                div { class: "share-list",
+                    for (i, share) in shares.iter().enumerate() {
+                        div { key: "{i}", class: "share-card",
+                            div { class: "share-label", "{share_label(share)}" }
+                            div { class: "share-words", "{share}" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = current_error {
+                div { class: "error-banner", ">> {err}" }
+            }
+
+            div { class: "footer-nav",
+                {terminal_action("<<_BIP39", false, move |e| on_use_bip39.call(e))}
+                {terminal_action(if has_shares { "REGENERATE" } else { "GENERATE_SHARES" }, !has_shares, move |e| on_generate.call(e))}
+                if has_shares {
+                    {terminal_action("CONTINUE", true, move |e| on_continue.call(e))}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Slip39ImportForm(
+    network_label: String,
+    mut share_inputs: Signal<Vec<String>>,
+    mut error_msg: Signal<Option<String>>,
+    on_use_bip39: EventHandler<MouseEvent>,
+    on_continue: EventHandler<MouseEvent>,
+) -> Element {
+    let count = share_inputs.read().len();
+    let labels: Vec<String> = share_inputs.read().iter()
+        .map(|s| if s.trim().is_empty() { "EMPTY".to_string() } else { share_label(s) })
+        .collect();
+
+    rsx! {
+        style { {SHARED_STYLE} }
+
+        div { class: "slip-step-container",
+            div { class: "step-header",
+                div { class: "step-title", "WALLET_IMPORT // STEP_01 // SLIP39_SHARES // {network_label}" }
+            }
+
+            div { class: "share-list",
+                for i in 0..count {
+                    div { key: "{i}", class: "config-field",
+                        div { class: "input-label", "SHARE_{i + 1:02} // {labels[i]}" }
+                        div { class: "terminal-input-wrapper",
+                            input {
+                                class: "inner-input",
+                                spellcheck: false,
+                                autocomplete: "off",
+                                value: "{share_inputs.read()[i]}",
+                                oninput: move |evt| {
+                                    let val = evt.value().replace(['\n', '\r'], " ");
+                                    error_msg.set(None);
+                                    share_inputs.with_mut(|s| s[i] = val.to_lowercase());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error_msg() {
+                div { class: "error-banner", ">> {err}" }
+            }
+
+            div { class: "footer-nav",
+                {terminal_action("<<_BIP39", false, move |e| on_use_bip39.call(e))}
+                {terminal_action("ADD_SHARE", false, move |_| share_inputs.with_mut(|s| s.push(String::new())))}
+                {terminal_action("RECOVER", true, move |e| on_continue.call(e))}
+            }
+        }
+    }
+}
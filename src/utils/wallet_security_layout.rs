@@ -7,12 +7,14 @@ use crate::utils::styles::terminal_action;
 #[component]
 pub fn WalletSecurityForm(
     flow_label: String,
+    step: u8,
     network_label: String,
     action_label: String,
     mut bip39_buffer: Signal<String>,
     mut encryption_buffer: Signal<String>,
     current_error: Option<String>,
     on_action_click: EventHandler<MouseEvent>,
+    extra: Option<Element>, // flow-specific choices shown above the passphrases
 ) -> Element {
     rsx! {
         style { {r#"
@@ -43,9 +45,11 @@ pub fn WalletSecurityForm(
         // This class now correctly maps to the CSS above
         div { class: "terminal-step-container",
             div { class: "step-header",
-                div { class: "step-title", "WALLET_{flow_label} // STEP_02 // {network_label} // ENCRYPTION_PROTOCOL // AES-256-GCM" }
+                div { class: "step-title", "WALLET_{flow_label} // STEP_{step:02} // {network_label} // ENCRYPTION_PROTOCOL // AES-256-GCM" }
             }
 
+            {extra}
+
             // 1. BIP39 Section
             div { class: "input-section",
                 div { class: "input-label-row",
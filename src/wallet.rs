@@ -1,8 +1,140 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
 use serde_json::{self, Value};
-use crate::channel::{CHANNEL, WSCommand};
+use crate::chain::esplora::{self, HistoryTx, TxPart};
+use crate::channel::{CHANNEL, WSCommand, TransactionState, BTCTransactionState, BTCAccountState,
+    BTCTransactionData, BTCOutput, BitcoinTransactionStatus};
 use crate::utils::json_storage;
 use tokio::sync::mpsc;
 
+/// Clears every balance and history channel, e.g. before loading a different profile's wallets.
+pub fn reset_wallet_channels() {
+    let _ = CHANNEL.wallet_balance_tx.send((0.0, None, false));
+    let _ = CHANNEL.rlusd_tx.send((0.0, false, None));
+    let _ = CHANNEL.euro_tx.send((0.0, false, None));
+    let _ = CHANNEL.sgd_tx.send((0.0, false, None));
+    let _ = CHANNEL.transactions_tx.send(TransactionState { transactions: HashMap::new() });
+    let _ = CHANNEL.bitcoin_wallet_tx.send((0.0, None, false));
+    let _ = CHANNEL.btc_transactions_tx.send(BTCTransactionState { transactions: HashMap::new() });
+    let _ = CHANNEL.btc_account_tx.send(BTCAccountState::default());
+    let _ = CHANNEL.xrp_backup_verified_tx.send(true);
+    let _ = CHANNEL.btc_backup_verified_tx.send(true);
+}
+
+/// Records in `meta_file` (xrp.json / btc.json) that the user proved they hold the mnemonic.
+/// Creates the file if the backend hasn't written it yet; other fields are left untouched.
+pub fn set_backup_verified(meta_file: &str, address: &str) -> io::Result<()> {
+    json_storage::update_json(meta_file, |data: &mut Value| {
+        if !data.is_object() {
+            *data = Value::Object(serde_json::Map::new());
+        }
+        if let Some(obj) = data.as_object_mut() {
+            obj.entry("address").or_insert_with(|| Value::String(address.to_string()));
+            obj.insert("backup_verified".to_string(), Value::Bool(true));
+        }
+    })
+}
+
+// `tx` as the wallet sees it: a send counts what left the account, a receive what arrived in it
+fn history_entry(tx: HistoryTx, owned: &HashSet<String>) -> BTCTransactionData {
+    let is_owned = |part: &TxPart| part.address.as_ref().is_some_and(|a| owned.contains(a));
+    let outgoing = tx.inputs.iter().any(is_owned);
+    let amount: u64 = tx.outputs.iter()
+        .filter(|o| is_owned(o) != outgoing)
+        .map(|o| o.value.to_sat())
+        .sum();
+    let mut senders: Vec<String> = Vec::new();
+    for address in tx.inputs.iter().filter_map(|i| i.address.clone()) {
+        if !senders.contains(&address) {
+            senders.push(address);
+        }
+    }
+    let outputs: Vec<BTCOutput> = tx.outputs.iter()
+        .filter_map(|o| o.address.clone().map(|address| BTCOutput { address, value: o.value.to_sat() }))
+        .collect();
+
+    BTCTransactionData {
+        txid: tx.txid.to_string(),
+        status: if tx.confirmed { BitcoinTransactionStatus::Success } else { BitcoinTransactionStatus::Pending },
+        amount: amount.to_string(),
+        fees: tx.fee.to_sat().to_string(),
+        receiver_addresses: outputs.iter().map(|o| o.address.clone()).collect(),
+        sender_addresses: senders,
+        // Unconfirmed ones sort as the newest
+        timestamp: tx.block_time.unwrap_or_else(|| chrono::Utc::now().timestamp() as u64).to_string(),
+        outputs,
+    }
+}
+
+// History of every used account address, one entry per transaction
+async fn bitcoin_history(wallet: &str) -> Result<HashMap<String, BTCTransactionData>, String> {
+    let account = crate::btcaccount::load();
+    let owned = crate::btcaccount::owned_addresses(wallet);
+    let mut used: Vec<String> = account.receive.iter()
+        .chain(&account.change)
+        .filter(|t| t.used)
+        .map(|t| t.address.clone())
+        .collect();
+    if !used.iter().any(|a| a == wallet) {
+        used.push(wallet.to_string());
+    }
+
+    let mut transactions = HashMap::new();
+    for address in used {
+        for tx in esplora::address_txs(&address).await? {
+            let txid = tx.txid.to_string();
+            if !transactions.contains_key(&txid) {
+                transactions.insert(txid, history_entry(tx, &owned));
+            }
+        }
+    }
+    Ok(transactions)
+}
+
+/// Discovers the BTC account's used addresses, then publishes the balance over every unspent
+/// output they hold and their history with per-output values. The backend only reports on the
+/// wallet address, which misses receive addresses past index 0 and all change.
+pub async fn refresh_bitcoin(wallet: String) -> Result<(), String> {
+    let balance = async {
+        crate::btcaccount::discover().await?;
+        let coins = crate::signer::btc_utxos(wallet.clone()).await?;
+        Ok::<_, String>(coins.iter().map(|coin| coin.utxo.value).sum::<bitcoin::Amount>())
+    }.await;
+    let history = match balance {
+        Ok(_) => bitcoin_history(&wallet).await.map(Some),
+        Err(_) => Ok(None),
+    };
+
+    CHANNEL.btc_account_tx.send_modify(|state| {
+        // A refresh for another wallet does not carry over to this one
+        if state.wallet.as_deref() != Some(wallet.as_str()) {
+            *state = BTCAccountState { wallet: Some(wallet.clone()), ..Default::default() };
+        }
+        if let Ok(total) = &balance {
+            state.balance = Some(total.to_btc());
+        }
+        if let Ok(Some(transactions)) = &history {
+            state.transactions = transactions.clone();
+        }
+        state.error = balance.as_ref().err().or(history.as_ref().err()).cloned();
+    });
+    balance?;
+    history.map(|_| ())
+}
+
+/// Runs `refresh_bitcoin` in the background, e.g. on wallet load or after a broadcast. Its
+/// failures are published in `BTCAccountState::error` for the balance view.
+pub fn spawn_bitcoin_refresh(wallet: String) {
+    tokio::spawn(async move {
+        let _ = refresh_bitcoin(wallet).await;
+    });
+}
+
+// Wallets written before the flag existed never went through the quiz
+fn backup_verified(json: &Value) -> bool {
+    json.get("backup_verified").and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
 pub fn load_wallets(commands_tx: mpsc::Sender<WSCommand>) {
     // Load XRP wallet from xrp.json
     if json_storage::get_config_path("xrp.json")
@@ -17,6 +149,7 @@ pub fn load_wallets(commands_tx: mpsc::Sender<WSCommand>) {
                 // Update XRP wallet channel with initial data
                 if !address.is_empty() {
                     let _ = CHANNEL.wallet_balance_tx.send((0.0, Some(address.clone()), private_key_deleted));
+                    let _ = CHANNEL.xrp_backup_verified_tx.send(backup_verified(&json));
 
                     // Send get_cached_balance command
                     let command = WSCommand {
@@ -24,16 +157,15 @@ pub fn load_wallets(commands_tx: mpsc::Sender<WSCommand>) {
                         wallet: Some(address.clone()),
                         recipient: None,
                         amount: None,
-                        passphrase: None,
                         trustline_limit: None,
                         fee: None,
                         tx_type: None,
                         taker_pays: None,
                         taker_gets: None,
-                        seed: None,
                         flags: None,
                         wallet_type: None,
-                        bip39: None,
+                        signed_tx: None,
+                        tx_hash: None,
                     };
                     let _ = commands_tx.try_send(command);
                 }
@@ -55,6 +187,8 @@ pub fn load_wallets(commands_tx: mpsc::Sender<WSCommand>) {
                 // Update BTC wallet channel with initial data
                 if !address.is_empty() {
                     let _ = CHANNEL.bitcoin_wallet_tx.send((0.0, Some(address.clone()), private_key_deleted));
+                    let _ = CHANNEL.btc_backup_verified_tx.send(backup_verified(&json));
+                    spawn_bitcoin_refresh(address.clone());
 
                     // Send get_bitcoin_cached_balance command
                     let command = WSCommand {
@@ -62,16 +196,15 @@ pub fn load_wallets(commands_tx: mpsc::Sender<WSCommand>) {
                         wallet: Some(address.clone()),
                         recipient: None,
                         amount: None,
-                        passphrase: None,
                         trustline_limit: None,
                         fee: None,
                         tx_type: None,
                         taker_pays: None,
                         taker_gets: None,
-                        seed: None,
                         flags: None,
                         wallet_type: None,
-                        bip39: None,
+                        signed_tx: None,
+                        tx_hash: None,
                     };
                     let _ = commands_tx.try_send(command);
                 }